@@ -10,8 +10,8 @@ async fn main() -> Result<(), MeteostatError> {
     // Period for which we want hourly data:
     let period = NaiveDate::from_ymd_opt(2023, 9, 1).unwrap();
 
-    // --- Hourly Data: Collect a Range into Vec<Hourly> ---
-    println!("\n--- Hourly Data (Range to Vec<Hourly>) ---");
+    // --- Hourly Data: Collect a Range into HourlyRecords ---
+    println!("\n--- Hourly Data (Range to HourlyRecords) ---");
     let hourly_vec = client
         .hourly()
         .location(LatLon(52.0836403, 5.1257283))
@@ -0,0 +1,290 @@
+//! Defines the `CacheBackend` trait for storing cached station lists and weather data, with
+//! implementations for a local directory and for memory.
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::future::Future;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Mutex;
+use tempfile::NamedTempFile;
+
+/// Entries of a [`MemoryCache`]: the stored bytes and when they were written.
+type MemoryEntries = HashMap<String, (Vec<u8>, DateTime<Utc>)>;
+
+/// The future returned by [`CacheBackend`] methods.
+pub type CacheFuture<'a, T> = Pin<Box<dyn Future<Output = io::Result<T>> + Send + 'a>>;
+
+/// Storage for the client's cache: the station list and one Parquet file per station and
+/// frequency.
+///
+/// Entries are addressed by keys that are plain file names, such as `daily-10637.parquet`
+/// or `stations_lite.rkyv`. The client uses [`FileSystemCache`] unless another backend is
+/// passed to the builder with `.cache_backend(..)`, e.g. [`MemoryCache`] for ephemeral
+/// environments, or an implementation backed by an object store such as S3.
+///
+/// Methods return boxed futures so the trait can be used as `Arc<dyn CacheBackend>`. An
+/// implementation typically wraps an `async` block in `Box::pin`.
+///
+/// # Example
+///
+/// ```no_run
+/// use meteostat::{Meteostat, MemoryCache, MeteostatError};
+/// use std::sync::Arc;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), MeteostatError> {
+/// let client = Meteostat::builder()
+///     .cache_backend(Arc::new(MemoryCache::new()))
+///     .build()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait CacheBackend: Debug + Send + Sync {
+    /// Reads the entry stored under `key`, or `None` if there is none.
+    fn read<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<Vec<u8>>>;
+
+    /// Stores `bytes` under `key`, replacing any existing entry. Readers should see either
+    /// the old or the new entry, never a partially written one.
+    fn write<'a>(&'a self, key: &'a str, bytes: Vec<u8>) -> CacheFuture<'a, ()>;
+
+    /// Removes the entry stored under `key`. Removing a missing entry is not an error.
+    fn delete<'a>(&'a self, key: &'a str) -> CacheFuture<'a, ()>;
+
+    /// Lists the keys of all entries.
+    fn list(&self) -> CacheFuture<'_, Vec<String>>;
+
+    /// When the entry stored under `key` was last written, or `None` if there is none.
+    /// Used to decide whether cached weather data is recent enough.
+    fn modified<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<DateTime<Utc>>>;
+
+    /// The size of the entry stored under `key` in bytes, or `None` if there is none or the
+    /// backend can't tell without reading it. Used to report the space freed by purging the
+    /// cache. Defaults to `None`.
+    fn size<'a>(&'a self, _key: &'a str) -> CacheFuture<'a, Option<u64>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// The path of the entry on the local filesystem, if the backend stores entries as
+    /// local files. This lets weather data be scanned lazily from disk instead of being
+    /// read into memory, and enables cache file locking. Defaults to `None`.
+    fn local_path(&self, _key: &str) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// The path used to identify an entry in errors: its local path if it has one, otherwise
+/// the key itself.
+pub(crate) fn entry_path(cache: &dyn CacheBackend, key: &str) -> PathBuf {
+    cache.local_path(key).unwrap_or_else(|| PathBuf::from(key))
+}
+
+/// Keeps cache entries as files in a local directory. This is the default backend.
+#[derive(Debug, Clone)]
+pub struct FileSystemCache {
+    dir: PathBuf,
+}
+
+impl FileSystemCache {
+    /// Stores entries in `dir`, which is created on the first write if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory entries are stored in.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Writes `bytes` to a temporary file in the cache directory and moves it into place,
+    /// so a crash mid-write never leaves a truncated entry behind.
+    fn write_atomic(dir: &Path, path: &Path, bytes: &[u8]) -> io::Result<()> {
+        std::fs::create_dir_all(dir)?;
+        let mut temp_file = NamedTempFile::new_in(dir)?;
+        temp_file.write_all(bytes)?;
+        temp_file.flush()?;
+        temp_file.persist(path).map_err(|e| e.error)?;
+        Ok(())
+    }
+}
+
+impl CacheBackend for FileSystemCache {
+    fn read<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<Vec<u8>>> {
+        Box::pin(async move {
+            match tokio::fs::read(self.dir.join(key)).await {
+                Ok(bytes) => Ok(Some(bytes)),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e),
+            }
+        })
+    }
+
+    fn write<'a>(&'a self, key: &'a str, bytes: Vec<u8>) -> CacheFuture<'a, ()> {
+        let dir = self.dir.clone();
+        let path = self.dir.join(key);
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || Self::write_atomic(&dir, &path, &bytes)).await?
+        })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> CacheFuture<'a, ()> {
+        Box::pin(async move {
+            match tokio::fs::remove_file(self.dir.join(key)).await {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    fn list(&self) -> CacheFuture<'_, Vec<String>> {
+        Box::pin(async move {
+            let mut entries = match tokio::fs::read_dir(&self.dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+                Err(e) => return Err(e),
+            };
+            let mut keys = Vec::new();
+            while let Some(entry) = entries.next_entry().await? {
+                if entry.file_type().await?.is_file() {
+                    if let Some(name) = entry.file_name().to_str() {
+                        keys.push(name.to_string());
+                    }
+                }
+            }
+            Ok(keys)
+        })
+    }
+
+    fn modified<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<DateTime<Utc>>> {
+        Box::pin(async move {
+            match tokio::fs::metadata(self.dir.join(key)).await {
+                Ok(metadata) => Ok(Some(DateTime::from(metadata.modified()?))),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e),
+            }
+        })
+    }
+
+    fn size<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<u64>> {
+        Box::pin(async move {
+            match tokio::fs::metadata(self.dir.join(key)).await {
+                Ok(metadata) => Ok(Some(metadata.len())),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e),
+            }
+        })
+    }
+
+    fn local_path(&self, key: &str) -> Option<PathBuf> {
+        Some(self.dir.join(key))
+    }
+}
+
+/// Keeps cache entries in memory, for environments without a persistent filesystem. The
+/// cache lives as long as the backend, so share one `Arc<MemoryCache>` between clients
+/// (or keep the client alive) to reuse it.
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: Mutex<MemoryEntries>,
+}
+
+impl MemoryCache {
+    /// Creates an empty in-memory cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `f` on the entries. A panic while the lock was held leaves the map itself
+    /// intact, so a poisoned lock is recovered.
+    fn with_entries<T>(&self, f: impl FnOnce(&mut MemoryEntries) -> T) -> T {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        f(&mut entries)
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    fn read<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<Vec<u8>>> {
+        let bytes = self.with_entries(|entries| entries.get(key).map(|(bytes, _)| bytes.clone()));
+        Box::pin(async move { Ok(bytes) })
+    }
+
+    fn write<'a>(&'a self, key: &'a str, bytes: Vec<u8>) -> CacheFuture<'a, ()> {
+        self.with_entries(|entries| entries.insert(key.to_string(), (bytes, Utc::now())));
+        Box::pin(async { Ok(()) })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> CacheFuture<'a, ()> {
+        self.with_entries(|entries| entries.remove(key));
+        Box::pin(async { Ok(()) })
+    }
+
+    fn list(&self) -> CacheFuture<'_, Vec<String>> {
+        let keys = self.with_entries(|entries| entries.keys().cloned().collect());
+        Box::pin(async move { Ok(keys) })
+    }
+
+    fn modified<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<DateTime<Utc>>> {
+        let modified = self.with_entries(|entries| entries.get(key).map(|(_, at)| *at));
+        Box::pin(async move { Ok(modified) })
+    }
+
+    fn size<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<u64>> {
+        let size =
+            self.with_entries(|entries| entries.get(key).map(|(bytes, _)| bytes.len() as u64));
+        Box::pin(async move { Ok(size) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn round_trip(cache: &dyn CacheBackend) -> io::Result<()> {
+        assert_eq!(cache.read("daily-10637.parquet").await?, None);
+        assert_eq!(cache.modified("daily-10637.parquet").await?, None);
+        assert_eq!(cache.size("daily-10637.parquet").await?, None);
+
+        cache
+            .write("daily-10637.parquet", b"first".to_vec())
+            .await?;
+        cache
+            .write("daily-10637.parquet", b"second".to_vec())
+            .await?;
+        assert_eq!(
+            cache.read("daily-10637.parquet").await?,
+            Some(b"second".to_vec())
+        );
+        assert!(cache.modified("daily-10637.parquet").await?.is_some());
+        assert_eq!(cache.size("daily-10637.parquet").await?, Some(6));
+        assert_eq!(cache.list().await?, vec!["daily-10637.parquet".to_string()]);
+
+        cache.delete("daily-10637.parquet").await?;
+        cache.delete("daily-10637.parquet").await?;
+        assert!(cache.list().await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_backends_round_trip() -> io::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let disk = FileSystemCache::new(temp_dir.path().join("nested"));
+        round_trip(&disk).await?;
+        assert_eq!(
+            disk.local_path("a.parquet"),
+            Some(temp_dir.path().join("nested").join("a.parquet"))
+        );
+
+        let memory = MemoryCache::new();
+        round_trip(&memory).await?;
+        assert_eq!(memory.local_path("a.parquet"), None);
+        Ok(())
+    }
+}
@@ -4,8 +4,13 @@
 //! allowing the user to specify the data source (station ID or location) before
 //! executing the request to fetch climate data.
 
-use crate::{ClimateLazyFrame, Frequency, LatLon, Meteostat, MeteostatError, RequiredData};
+use crate::clients::collect_eagerly;
+use crate::{
+    CancellationToken, ClimateLazyFrame, Frequency, LatLon, Meteostat, MeteostatError,
+    RequiredData, WithWarnings,
+};
 use bon::bon;
+use polars::prelude::DataFrame;
 
 /// A client builder specifically for fetching climate normals data.
 ///
@@ -15,6 +20,8 @@ use bon::bon;
 ///
 /// Calling `.station()` or `.location().call()` executes the request and returns a
 /// [`Result<ClimateLazyFrame, MeteostatError>`].
+/// Finish with `.eager().await` instead of `.call().await` to get the data as a collected
+/// `DataFrame` right away.
 pub struct ClimateClient<'a> {
     /// A reference to the main Meteostat client instance.
     client: &'a Meteostat,
@@ -55,6 +62,18 @@ impl<'a> ClimateClient<'a> {
     ///   or return an error depending on the internal implementation. Defaults to `None`
     ///   (no specific inventory requirement beyond needing climate data).
     ///
+    /// * `.skip_validation(bool)`: When `true`, skips checking that the station ID exists in
+    ///   the loaded station list before downloading. Useful if the station list lags behind
+    ///   newly added stations. Defaults to `false`.
+    ///
+    /// * `.cancellation_token(CancellationToken)`: Aborts the download/parse once the token is
+    ///   cancelled, e.g. because the requesting client disconnected. Defaults to `None`.
+    ///
+    /// * `.fallback_location(LatLon)`: If the station has no climate data, or its data file
+    ///   is missing or fails to download, returns data from the nearest other suitable
+    ///   station to this location instead, as [`ClimateClient::location`] would. Defaults to
+    ///   `None` (no fallback).
+    ///
     /// # Returns
     ///
     /// After calling `.call().await`, returns a `Result` containing a [`ClimateLazyFrame`]
@@ -66,6 +85,13 @@ impl<'a> ClimateClient<'a> {
     ///
     /// Can return [`MeteostatError::WeatherData`] if fetching or parsing the underlying
     /// data file fails, potentially influenced by the `required_data` filter if set.
+    /// Returns [`MeteostatError::UnknownStation`] if the station ID is not in the loaded
+    /// station list (unless `skip_validation` is set).
+    /// Returns [`MeteostatError::Cancelled`] if the `cancellation_token` is cancelled first.
+    /// If `fallback_location` is set and the station has no data, returns the errors of
+    /// [`ClimateClient::location`] instead, wrapped in [`MeteostatError::FallbackFailed`] if
+    /// fetching the station failed as well. An unknown station ID is not a reason to fall
+    /// back, unless `skip_validation` is set.
     ///
     /// # Example
     ///
@@ -96,14 +122,21 @@ impl<'a> ClimateClient<'a> {
         &self,
         #[builder(start_fn)] station: &str,
         required_data: Option<RequiredData>,
+        skip_validation: Option<bool>,
+        cancellation_token: Option<CancellationToken>,
+        fallback_location: Option<LatLon>,
     ) -> Result<ClimateLazyFrame, MeteostatError> {
         // Internal call to the main client's data fetching logic for a specific station
         let frame = self
             .client
-            .data_from_station()
+            .data_from_station_or_location()
             .station(station)
             .maybe_required_data(required_data)
+            .maybe_skip_validation(skip_validation)
+            .maybe_cancellation_token(cancellation_token)
+            .maybe_fallback_location(fallback_location)
             .frequency(Frequency::Climate) // Specify we want climate data
+            .in_range(Ok)
             .call() // Execute the internal builder
             .await?;
         // Wrap the resulting LazyFrame in the specific ClimateLazyFrame type
@@ -114,9 +147,12 @@ impl<'a> ClimateClient<'a> {
     ///
     /// This method starts a builder pattern. You must provide the location.
     /// You can optionally specify:
-    /// *   `.max_distance_km(f64)`: Maximum search radius (default: 50.0 km).
+    /// *   `.max_distance_km(f64)`: Maximum search radius (default: the client's
+    ///     [`crate::Meteostat::default_radius`] for climate data, 50.0 km unless configured).
     /// *   `.station_limit(usize)`: Max number of candidate stations to *consider* (default: 1). Note: It will still only return data for the *first* successful one found.
     /// *   `.required_data(RequiredData)`: Filter candidate stations based on their reported data inventory (e.g., `RequiredData::Any`). By default, no inventory filter is applied specifically for climate data location searches beyond the implicit check during data fetching.
+    /// *   `.cancellation_token(CancellationToken)`: Abort the search and download once cancelled; no further candidates are tried.
+    /// *   `.prefer_recent_normals(bool)`: Try candidates with the most recent normals period first (by the end year in their inventory), falling back to distance among stations with the same period. Only reorders the `station_limit` nearest candidates, so raise that limit as well. Defaults to `false` (nearest first).
     ///
     /// Finally, call `.call().await` on the builder to execute the search and data fetch.
     ///
@@ -137,6 +173,7 @@ impl<'a> ClimateClient<'a> {
     /// *   [`MeteostatError::NoDataFoundForNearbyStations`]: If candidate stations were found, but fetching climate data failed for all attempted stations.
     /// *   [`MeteostatError::LocateStation`]: If the underlying station search mechanism fails.
     /// *   [`MeteostatError::WeatherData`]: Encapsulated within `NoDataFoundForNearbyStations` if fetching fails for a candidate.
+    /// *   [`MeteostatError::Cancelled`]: If the `cancellation_token` is cancelled.
     ///
     /// # Example
     ///
@@ -172,18 +209,101 @@ impl<'a> ClimateClient<'a> {
         max_distance_km: Option<f64>,
         station_limit: Option<usize>,
         required_data: Option<RequiredData>,
+        cancellation_token: Option<CancellationToken>,
+        prefer_recent_normals: Option<bool>,
     ) -> Result<ClimateLazyFrame, MeteostatError> {
-        let frame = self
+        self.build_try_location(
+            coordinate,
+            max_distance_km,
+            station_limit,
+            required_data,
+            cancellation_token,
+            prefer_recent_normals,
+        )
+        .await
+        .map(WithWarnings::into_inner)
+    }
+
+    /// Like [`ClimateClient::location`], but also reports the problems that didn't stop the
+    /// request, for batch jobs that would rather log them than fail.
+    ///
+    /// Takes the same builder methods as [`ClimateClient::location`]. The warnings list each
+    /// candidate station that failed before one succeeded
+    /// ([`crate::FetchWarning::StationFailed`]).
+    ///
+    /// # Returns
+    ///
+    /// After calling `.call().await`, returns a `Result` containing the [`ClimateLazyFrame`]
+    /// together with the warnings.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`ClimateClient::location`]: the request still fails if no candidate
+    /// station could be fetched.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meteostat::{LatLon, Meteostat, MeteostatError};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let result = client
+    ///     .climate()
+    ///     .try_location(LatLon(52.52, 13.40))
+    ///     .station_limit(3)
+    ///     .call()
+    ///     .await?;
+    /// for warning in &result.warnings {
+    ///     eprintln!("warning: {warning}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[builder(start_fn = try_location)]
+    #[doc(hidden)]
+    pub async fn build_try_location(
+        &self,
+        #[builder(start_fn)] coordinate: LatLon,
+        max_distance_km: Option<f64>,
+        station_limit: Option<usize>,
+        required_data: Option<RequiredData>,
+        cancellation_token: Option<CancellationToken>,
+        prefer_recent_normals: Option<bool>,
+    ) -> Result<WithWarnings<ClimateLazyFrame>, MeteostatError> {
+        let result = self
             .client
             .data_from_location()
             .location(coordinate)
             .maybe_max_distance_km(max_distance_km)
             .maybe_station_limit(station_limit)
             .maybe_required_data(required_data)
+            .maybe_cancellation_token(cancellation_token)
+            .maybe_prefer_recent_normals(prefer_recent_normals)
             .frequency(Frequency::Climate)
             .call()
             .await?;
-        Ok(ClimateLazyFrame::new(frame))
+        Ok(result.map(ClimateLazyFrame::new))
+    }
+}
+
+impl<S: climate_client_build_station_builder::IsComplete>
+    ClimateClientBuildStationBuilder<'_, '_, '_, S>
+{
+    /// Executes the request like `.call()`, then collects the result into a `DataFrame`.
+    pub async fn eager(self) -> Result<DataFrame, MeteostatError> {
+        collect_eagerly(self.call()).await
+    }
+}
+
+impl<S: climate_client_build_location_builder::IsComplete>
+    ClimateClientBuildLocationBuilder<'_, '_, S>
+{
+    /// Executes the request like `.call()`, then collects the result into a `DataFrame`.
+    pub async fn eager(self) -> Result<DataFrame, MeteostatError> {
+        collect_eagerly(self.call()).await
     }
 }
 
@@ -197,6 +317,43 @@ mod tests {
     }
 
     // CLIMATE
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_climate_from_location_prefers_recent_normals() -> Result<(), MeteostatError> {
+        let client = Meteostat::builder().tag_station_id(true).build().await?;
+        let picked = client
+            .climate()
+            .location(berlin_location())
+            .max_distance_km(100.0)
+            .station_limit(10)
+            .prefer_recent_normals(true)
+            .call()
+            .await?
+            .frame
+            .limit(1)
+            .collect()?
+            .column(crate::STATION_ID_COLUMN)?
+            .str()?
+            .get(0)
+            .and_then(|id| client.station(id))
+            .expect("Expected a station ID in the climate frame");
+
+        let latest_end = client
+            .find_stations()
+            .location(berlin_location())
+            .max_distance_km(100.0)
+            .station_limit(10)
+            .inventory_request(crate::InventoryRequest::new(
+                Frequency::Climate,
+                RequiredData::Any,
+            ))
+            .call()
+            .iter()
+            .filter_map(|candidate| candidate.station.inventory.normals.end)
+            .max();
+        assert_eq!(picked.inventory.normals.end, latest_end);
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_climate_from_station() -> Result<(), MeteostatError> {
         let client = Meteostat::new().await?;
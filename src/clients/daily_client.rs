@@ -4,8 +4,17 @@
 //! allowing the user to specify the data source (station ID or location) before
 //! executing the request to fetch daily aggregated data.
 
-use crate::{DailyLazyFrame, Frequency, LatLon, Meteostat, MeteostatError, RequiredData};
+use crate::clients::collect_eagerly;
+use crate::types::frequency_frames::period_filter::with_resolved_periods;
+use crate::types::traits::period::date_period::DatePeriod;
+use crate::types::traits::types::StartEndDate;
+use crate::{
+    CancellationToken, DailyLazyFrame, Frequency, LatLon, Meteostat, MeteostatError, RequiredData,
+    WithWarnings,
+};
 use bon::bon;
+use chrono::TimeZone;
+use polars::prelude::DataFrame;
 
 /// A client builder specifically for fetching daily weather data.
 ///
@@ -15,6 +24,8 @@ use bon::bon;
 ///
 /// Calling `.station()` or `.location().call()` executes the request and returns a
 /// [`Result<DailyLazyFrame, MeteostatError>`].
+/// Finish with `.eager().await` instead of `.call().await` to get the data as a collected
+/// `DataFrame` right away.
 pub struct DailyClient<'a> {
     /// A reference to the main Meteostat client instance.
     client: &'a Meteostat,
@@ -57,6 +68,25 @@ impl<'a> DailyClient<'a> {
     ///   indicates daily data for the full year 2023. If the filter isn't met, the fetch
     ///   might fail early or return an error. Defaults to `None` (no inventory pre-filtering).
     ///
+    /// * `.skip_validation(bool)`: When `true`, skips checking that the station ID exists in
+    ///   the loaded station list before downloading. Useful if the station list lags behind
+    ///   newly added stations. Defaults to `false`.
+    ///
+    /// * `.cancellation_token(CancellationToken)`: Aborts the download/parse once the token is
+    ///   cancelled, e.g. because the requesting client disconnected. Defaults to `None`.
+    ///
+    /// * `.for_period(period)`: Filters the returned frame to a period (e.g. `Year(2023)`),
+    ///   equivalent to calling `.get_for_period(period)` on the result. Defaults to no filter.
+    ///
+    /// * `.for_local_period(period, &tz)`: Filters the returned frame to the days that overlap
+    ///   the calendar days of a period in timezone `tz`. Daily records are UTC days, so e.g.
+    ///   January 1st in UTC+10 also keeps December 31st. Defaults to no filter.
+    ///
+    /// * `.fallback_location(LatLon)`: If the station has no daily data in the `for_period` /
+    ///   `for_local_period` range (if given), or its data file is missing or fails to download, returns data from the nearest other suitable
+    ///   station to this location instead, as [`DailyClient::location`] would. Defaults to `None`
+    ///   (no fallback).
+    ///
     /// # Returns
     ///
     /// After calling `.call().await`, returns a `Result` containing a [`DailyLazyFrame`]
@@ -68,10 +98,18 @@ impl<'a> DailyClient<'a> {
     /// # Errors
     ///
     /// Can return:
+    /// *   [`MeteostatError::UnknownStation`]: If the station ID is not in the loaded station list
+    ///     (unless `skip_validation` is set).
+    /// *   [`MeteostatError::Cancelled`]: If the `cancellation_token` is cancelled before the data is ready.
+    /// *   [`MeteostatError::DateParsingError`]: If the `for_period` or `for_local_period` period cannot be resolved.
     /// *   [`MeteostatError::WeatherData`]: If fetching or parsing the underlying data file fails
     ///     (e.g., network error, file not found, CSV parse error).
     /// *   Could also potentially return an error related to unmet `required_data` criteria
     ///     if the inventory check fails before attempting the fetch (depends on internal logic).
+    /// *   If `fallback_location` is set and the station has no data, the errors of
+    ///     [`DailyClient::location`] instead, wrapped in [`MeteostatError::FallbackFailed`] if
+    ///     fetching the station failed as well. An unknown station ID is not a reason to fall
+    ///     back, unless `skip_validation` is set.
     ///
     /// # Example
     ///
@@ -82,16 +120,16 @@ impl<'a> DailyClient<'a> {
     /// let client = Meteostat::new().await?;
     /// let station_id = "06240"; // Amsterdam Schiphol
     ///
-    /// // Fetch daily data for the specified station, requiring inventory to show data for 2023
+    /// // Fetch daily data for 2023 for the specified station, requiring inventory to show data for 2023
     /// let daily_lazy = client
     ///     .daily()
     ///     .station(station_id)             // Required: Start builder with station ID
     ///     .required_data(RequiredData::FullYear(2023)) // Optional: Filter by inventory
+    ///     .for_period(Year(2023))          // Optional: Filter the returned frame
     ///     .call()                          // Required: Execute the fetch
     ///     .await?;                         // -> Result<DailyLazyFrame, MeteostatError>
     ///
-    /// // Filter the result for a specific year (even if already filtered by inventory) and collect
-    /// let daily_2023_df = daily_lazy.get_for_period(Year(2023))?.frame.collect()?;
+    /// let daily_2023_df = daily_lazy.frame.collect()?;
     /// println!("Daily data for station {} in 2023:\n{}", station_id, daily_2023_df.head(Some(5)));
     /// # Ok(())
     /// # }
@@ -102,14 +140,30 @@ impl<'a> DailyClient<'a> {
         &self,
         #[builder(start_fn)] station: &str,
         required_data: Option<RequiredData>,
+        skip_validation: Option<bool>,
+        cancellation_token: Option<CancellationToken>,
+        #[builder(with = |period: impl DatePeriod| period.get_date_period().ok_or(MeteostatError::DateParsingError))]
+        for_period: Option<Result<StartEndDate, MeteostatError>>,
+        #[builder(with = |period: impl DatePeriod, tz: &impl TimeZone| period.get_date_period().and_then(|dates| dates.utc_days_in_timezone(tz)).ok_or(MeteostatError::DateParsingError))]
+        for_local_period: Option<Result<StartEndDate, MeteostatError>>,
+        fallback_location: Option<LatLon>,
     ) -> Result<DailyLazyFrame, MeteostatError> {
+        let period = for_period.transpose()?;
+        let local_period = for_local_period.transpose()?;
         // Internal call to the main client's data fetching logic for a specific station
         let frame = self
             .client
-            .data_from_station()
+            .data_from_station_or_location()
             .station(station)
             .maybe_required_data(required_data)
+            .maybe_skip_validation(skip_validation)
+            .maybe_cancellation_token(cancellation_token)
+            .maybe_fallback_location(fallback_location)
             .frequency(Frequency::Daily)
+            .in_range(|frame| {
+                let periods = period.into_iter().chain(local_period);
+                Ok(with_resolved_periods(DailyLazyFrame::new(frame), periods)?.frame)
+            })
             .call()
             .await?;
         // Wrap the resulting LazyFrame in the specific DailyLazyFrame type
@@ -120,9 +174,13 @@ impl<'a> DailyClient<'a> {
     ///
     /// This method starts a builder pattern. You must provide the location.
     /// You can optionally specify:
-    /// *   `.max_distance_km(f64)`: Maximum search radius (default: 50.0 km).
+    /// *   `.max_distance_km(f64)`: Maximum search radius (default: the client's
+    ///     [`crate::Meteostat::default_radius`] for daily data, 50.0 km unless configured).
     /// *   `.station_limit(usize)`: Max number of candidate stations to *consider* (default: 1). It will return data for the *first* successful one found.
     /// *   `.required_data(RequiredData)`: Filter candidate stations based on their reported data inventory (e.g., `RequiredData::FullYear(2023)`).
+    /// *   `.cancellation_token(CancellationToken)`: Abort the search and download once cancelled; no further candidates are tried.
+    /// *   `.for_period(period)`: Filter the returned frame to a period (e.g. `Year(2023)`), like calling `.get_for_period(period)` on the result.
+    /// *   `.for_local_period(period, &tz)`: Filter the returned frame to the days that overlap the calendar days of a period in timezone `tz`.
     ///
     /// Finally, call `.call().await` on the builder to execute the search and data fetch.
     ///
@@ -143,6 +201,8 @@ impl<'a> DailyClient<'a> {
     /// *   [`MeteostatError::NoDataFoundForNearbyStations`]: If candidate stations were found, but fetching daily data failed for all attempted stations.
     /// *   [`MeteostatError::LocateStation`]: If the underlying station search mechanism fails.
     /// *   [`MeteostatError::WeatherData`]: Encapsulated within `NoDataFoundForNearbyStations` if fetching fails for a candidate.
+    /// *   [`MeteostatError::Cancelled`]: If the `cancellation_token` is cancelled.
+    /// *   [`MeteostatError::DateParsingError`]: If the `for_period` or `for_local_period` period cannot be resolved.
     ///
     /// # Example
     ///
@@ -177,20 +237,128 @@ impl<'a> DailyClient<'a> {
         max_distance_km: Option<f64>,            // Optional builder arg
         station_limit: Option<usize>,            // Optional builder arg
         required_data: Option<RequiredData>,     // Optional builder arg
+        cancellation_token: Option<CancellationToken>, // Optional builder arg
+        #[builder(with = |period: impl DatePeriod| period.get_date_period().ok_or(MeteostatError::DateParsingError))]
+        for_period: Option<Result<StartEndDate, MeteostatError>>,
+        #[builder(with = |period: impl DatePeriod, tz: &impl TimeZone| period.get_date_period().and_then(|dates| dates.utc_days_in_timezone(tz)).ok_or(MeteostatError::DateParsingError))]
+        for_local_period: Option<Result<StartEndDate, MeteostatError>>,
     ) -> Result<DailyLazyFrame, MeteostatError> {
+        self.build_try_location(
+            coordinate,
+            max_distance_km,
+            station_limit,
+            required_data,
+            cancellation_token,
+            for_period,
+            for_local_period,
+        )
+        .await
+        .map(WithWarnings::into_inner)
+    }
+
+    /// Like [`DailyClient::location`], but also reports the problems that didn't stop the
+    /// request, for batch jobs that would rather log them than fail.
+    ///
+    /// Takes the same builder methods as [`DailyClient::location`]. The warnings list each
+    /// candidate station that failed before one succeeded ([`crate::FetchWarning::StationFailed`]),
+    /// and, with `.for_period(..)` or `.for_local_period(..)`, whether the inventory of the station used doesn't cover
+    /// the whole period ([`crate::FetchWarning::PeriodNotCovered`]).
+    ///
+    /// # Returns
+    ///
+    /// After calling `.call().await`, returns a `Result` containing the [`DailyLazyFrame`]
+    /// together with the warnings.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`DailyClient::location`]: the request still fails if no candidate
+    /// station could be fetched.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meteostat::{LatLon, Meteostat, MeteostatError, Year};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let result = client
+    ///     .daily()
+    ///     .try_location(LatLon(52.52, 13.40))
+    ///     .station_limit(3)
+    ///     .for_period(Year(2023))
+    ///     .call()
+    ///     .await?;
+    /// for warning in &result.warnings {
+    ///     eprintln!("warning: {warning}");
+    /// }
+    /// println!("{} days", result.value.row_count()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[builder(start_fn = try_location)]
+    #[doc(hidden)]
+    pub async fn build_try_location(
+        &self,
+        #[builder(start_fn)] coordinate: LatLon,
+        max_distance_km: Option<f64>,
+        station_limit: Option<usize>,
+        required_data: Option<RequiredData>,
+        cancellation_token: Option<CancellationToken>,
+        #[builder(with = |period: impl DatePeriod| period.get_date_period().ok_or(MeteostatError::DateParsingError))]
+        for_period: Option<Result<StartEndDate, MeteostatError>>,
+        #[builder(with = |period: impl DatePeriod, tz: &impl TimeZone| period.get_date_period().and_then(|dates| dates.utc_days_in_timezone(tz)).ok_or(MeteostatError::DateParsingError))]
+        for_local_period: Option<Result<StartEndDate, MeteostatError>>,
+    ) -> Result<WithWarnings<DailyLazyFrame>, MeteostatError> {
+        let period = for_period.transpose()?;
+        let local_period = for_local_period.transpose()?;
+        // With both periods, the frame is filtered to their overlap
+        let requested = match (period, local_period) {
+            (Some(a), Some(b)) => Some(StartEndDate {
+                start: a.start.max(b.start),
+                end: a.end.min(b.end),
+            }),
+            (a, b) => a.or(b),
+        };
         // Internal call to the main client's data fetching logic for a location
-        let frame = self
+        let result = self
             .client
             .data_from_location()
             .location(coordinate) // Pass the location
             .maybe_max_distance_km(max_distance_km) // Pass optional distance
             .maybe_station_limit(station_limit) // Pass optional station limit
             .maybe_required_data(required_data) // Pass optional inventory requirement
+            .maybe_cancellation_token(cancellation_token) // Pass optional cancellation token
+            .maybe_check_coverage(requested.map(|period| RequiredData::DateRange {
+                start: period.start,
+                end: period.end,
+            }))
             .frequency(Frequency::Daily) // Specify we want daily data
             .call() // Execute the internal builder
             .await?;
         // Wrap the resulting LazyFrame
-        Ok(DailyLazyFrame::new(frame))
+        let periods = period.into_iter().chain(local_period);
+        let frame = with_resolved_periods(DailyLazyFrame::new(result.value), periods)?;
+        Ok(WithWarnings::new(frame, result.warnings))
+    }
+}
+
+impl<S: daily_client_build_station_builder::IsComplete>
+    DailyClientBuildStationBuilder<'_, '_, '_, S>
+{
+    /// Executes the request like `.call()`, then collects the result into a `DataFrame`.
+    pub async fn eager(self) -> Result<DataFrame, MeteostatError> {
+        collect_eagerly(self.call()).await
+    }
+}
+
+impl<S: daily_client_build_location_builder::IsComplete>
+    DailyClientBuildLocationBuilder<'_, '_, S>
+{
+    /// Executes the request like `.call()`, then collects the result into a `DataFrame`.
+    pub async fn eager(self) -> Result<DataFrame, MeteostatError> {
+        collect_eagerly(self.call()).await
     }
 }
 
@@ -220,6 +388,30 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_daily_from_station_builder_for_period() -> Result<(), MeteostatError> {
+        let client = Meteostat::new().await?;
+        let one_shot = client
+            .daily()
+            .station("06240") // Schiphol
+            .for_period(Year(2023))
+            .call()
+            .await?
+            .frame
+            .collect()?;
+        let two_step = client
+            .daily()
+            .station("06240")
+            .call()
+            .await?
+            .get_for_period(Year(2023))?
+            .frame
+            .collect()?;
+        assert!(one_shot.height() > 0);
+        assert!(one_shot.equals_missing(&two_step));
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_daily_from_station_with_filter() -> Result<(), MeteostatError> {
         let client = Meteostat::new().await?;
@@ -271,4 +463,29 @@ mod tests {
         );
         Ok(())
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_daily_cancelled_request() -> Result<(), MeteostatError> {
+        let client = Meteostat::new().await?;
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let by_station = client
+            .daily()
+            .station("10637")
+            .cancellation_token(token.clone())
+            .call()
+            .await;
+        assert!(matches!(by_station, Err(MeteostatError::Cancelled)));
+
+        let by_location = client
+            .daily()
+            .location(berlin_location())
+            .station_limit(3)
+            .cancellation_token(token)
+            .call()
+            .await;
+        assert!(matches!(by_location, Err(MeteostatError::Cancelled)));
+        Ok(())
+    }
 }
@@ -4,8 +4,18 @@
 //! allowing the user to specify the data source (station ID or location) before
 //! executing the request to fetch hour-by-hour weather observations.
 
-use crate::{Frequency, HourlyLazyFrame, LatLon, Meteostat, MeteostatError, RequiredData};
+use crate::clients::collect_eagerly;
+use crate::types::frequency_frames::period_filter::with_resolved_periods;
+use crate::types::traits::period::date_period::DatePeriod;
+use crate::types::traits::period::datetime_period::DateTimePeriod;
+use crate::types::traits::types::StartEndDateTime;
+use crate::{
+    CancellationToken, Frequency, HourlyLazyFrame, LatLon, Meteostat, MeteostatError, RequiredData,
+    WithWarnings,
+};
 use bon::bon;
+use chrono::TimeZone;
+use polars::prelude::DataFrame;
 
 /// A client builder specifically for fetching hourly weather data.
 ///
@@ -15,6 +25,8 @@ use bon::bon;
 ///
 /// Calling `.station()` or `.location().call()` executes the request and returns a
 /// [`Result<HourlyLazyFrame, MeteostatError>`].
+/// Finish with `.eager().await` instead of `.call().await` to get the data as a collected
+/// `DataFrame` right away.
 pub struct HourlyClient<'a> {
     client: &'a Meteostat,
 }
@@ -57,6 +69,25 @@ impl<'a> HourlyClient<'a> {
     ///   isn't met, the fetch might fail early or return an error. Defaults to `None`
     ///   (no inventory pre-filtering).
     ///
+    /// * `.skip_validation(bool)`: When `true`, skips checking that the station ID exists in
+    ///   the loaded station list before downloading. Useful if the station list lags behind
+    ///   newly added stations. Defaults to `false`.
+    ///
+    /// * `.cancellation_token(CancellationToken)`: Aborts the download/parse once the token is
+    ///   cancelled, e.g. because the requesting client disconnected. Defaults to `None`.
+    ///
+    /// * `.for_period(period)`: Filters the returned frame to a period (e.g. `Year(2023)`),
+    ///   equivalent to calling `.get_for_period(period)` on the result. Defaults to no filter.
+    ///
+    /// * `.for_local_period(period, &tz)`: Filters the returned frame to the calendar days of
+    ///   a period in timezone `tz`, equivalent to calling `.get_for_local_period(period, &tz)`
+    ///   on the result. Defaults to no filter.
+    ///
+    /// * `.fallback_location(LatLon)`: If the station has no hourly data in the `for_period` /
+    ///   `for_local_period` range (if given), or its data file is missing or fails to download, returns data from the
+    ///   nearest other suitable station to this location instead, as
+    ///   [`HourlyClient::location`] would. Defaults to `None` (no fallback).
+    ///
     /// # Returns
     ///
     /// After calling `.call().await`, returns a `Result` containing an [`HourlyLazyFrame`]
@@ -68,10 +99,18 @@ impl<'a> HourlyClient<'a> {
     /// # Errors
     ///
     /// Can return:
+    /// *   [`MeteostatError::UnknownStation`]: If the station ID is not in the loaded station list
+    ///     (unless `skip_validation` is set).
+    /// *   [`MeteostatError::Cancelled`]: If the `cancellation_token` is cancelled before the data is ready.
+    /// *   [`MeteostatError::DateParsingError`]: If the `for_period` or `for_local_period` period cannot be resolved.
     /// *   [`MeteostatError::WeatherData`]: If fetching or parsing the underlying data file fails
     ///     (e.g., network error, file not found, CSV parse error).
     /// *   Could also potentially return an error related to unmet `required_data` criteria
     ///     if the inventory check fails before attempting the fetch (depends on internal logic).
+    /// *   If `fallback_location` is set and the station has no data, the errors of
+    ///     [`HourlyClient::location`] instead, wrapped in [`MeteostatError::FallbackFailed`] if
+    ///     fetching the station failed as well. An unknown station ID is not a reason to fall
+    ///     back, unless `skip_validation` is set.
     ///
     /// # Example
     ///
@@ -107,13 +146,29 @@ impl<'a> HourlyClient<'a> {
         &self,
         #[builder(start_fn)] station: &str,
         required_data: Option<RequiredData>,
+        skip_validation: Option<bool>,
+        cancellation_token: Option<CancellationToken>,
+        #[builder(with = |period: impl DateTimePeriod| period.get_datetime_period().ok_or(MeteostatError::DateParsingError))]
+        for_period: Option<Result<StartEndDateTime, MeteostatError>>,
+        #[builder(with = |period: impl DatePeriod, tz: &impl TimeZone| period.get_date_period().and_then(|dates| dates.in_timezone(tz)).ok_or(MeteostatError::DateParsingError))]
+        for_local_period: Option<Result<StartEndDateTime, MeteostatError>>,
+        fallback_location: Option<LatLon>,
     ) -> Result<HourlyLazyFrame, MeteostatError> {
+        let period = for_period.transpose()?;
+        let local_period = for_local_period.transpose()?;
         let frame = self
             .client
-            .data_from_station()
+            .data_from_station_or_location()
             .station(station)
             .maybe_required_data(required_data)
+            .maybe_skip_validation(skip_validation)
+            .maybe_cancellation_token(cancellation_token)
+            .maybe_fallback_location(fallback_location)
             .frequency(Frequency::Hourly)
+            .in_range(|frame| {
+                let periods = period.into_iter().chain(local_period);
+                Ok(with_resolved_periods(HourlyLazyFrame::new(frame), periods)?.frame)
+            })
             .call()
             .await?;
         Ok(HourlyLazyFrame::new(frame))
@@ -123,9 +178,13 @@ impl<'a> HourlyClient<'a> {
     ///
     /// This method starts a builder pattern. You must provide the location.
     /// You can optionally specify:
-    /// *   `.max_distance_km(f64)`: Maximum search radius (default: 50.0 km).
+    /// *   `.max_distance_km(f64)`: Maximum search radius (default: the client's
+    ///     [`crate::Meteostat::default_radius`] for hourly data, 50.0 km unless configured).
     /// *   `.station_limit(usize)`: Max number of candidate stations to *consider* (default: 1). It will return data for the *first* successful one found.
     /// *   `.required_data(RequiredData)`: Filter candidate stations based on their reported data inventory (e.g., `RequiredData::FullYear(2023)`).
+    /// *   `.cancellation_token(CancellationToken)`: Abort the search and download once cancelled; no further candidates are tried.
+    /// *   `.for_period(period)`: Filter the returned frame to a period (e.g. `Year(2023)`), like calling `.get_for_period(period)` on the result.
+    /// *   `.for_local_period(period, &tz)`: Filter the returned frame to the calendar days of a period in timezone `tz`, like calling `.get_for_local_period(period, &tz)` on the result.
     ///
     /// Finally, call `.call().await` on the builder to execute the search and data fetch.
     ///
@@ -146,6 +205,8 @@ impl<'a> HourlyClient<'a> {
     /// *   [`MeteostatError::NoDataFoundForNearbyStations`]: If candidate stations were found, but fetching hourly data failed for all attempted stations.
     /// *   [`MeteostatError::LocateStation`]: If the underlying station search mechanism fails.
     /// *   [`MeteostatError::WeatherData`]: Encapsulated within `NoDataFoundForNearbyStations` if fetching fails for a candidate.
+    /// *   [`MeteostatError::Cancelled`]: If the `cancellation_token` is cancelled.
+    /// *   [`MeteostatError::DateParsingError`]: If the `for_period` or `for_local_period` period cannot be resolved.
     ///
     /// # Example
     ///
@@ -183,19 +244,127 @@ impl<'a> HourlyClient<'a> {
         max_distance_km: Option<f64>,
         station_limit: Option<usize>,
         required_data: Option<RequiredData>,
+        cancellation_token: Option<CancellationToken>,
+        #[builder(with = |period: impl DateTimePeriod| period.get_datetime_period().ok_or(MeteostatError::DateParsingError))]
+        for_period: Option<Result<StartEndDateTime, MeteostatError>>,
+        #[builder(with = |period: impl DatePeriod, tz: &impl TimeZone| period.get_date_period().and_then(|dates| dates.in_timezone(tz)).ok_or(MeteostatError::DateParsingError))]
+        for_local_period: Option<Result<StartEndDateTime, MeteostatError>>,
     ) -> Result<HourlyLazyFrame, MeteostatError> {
-        let frame = self
+        self.build_try_location(
+            coordinate,
+            max_distance_km,
+            station_limit,
+            required_data,
+            cancellation_token,
+            for_period,
+            for_local_period,
+        )
+        .await
+        .map(WithWarnings::into_inner)
+    }
+
+    /// Like [`HourlyClient::location`], but also reports the problems that didn't stop the
+    /// request, for batch jobs that would rather log them than fail.
+    ///
+    /// Takes the same builder methods as [`HourlyClient::location`]. The warnings list each
+    /// candidate station that failed before one succeeded
+    /// ([`crate::FetchWarning::StationFailed`]) and,
+    /// with `.for_period(..)` or `.for_local_period(..)`, whether the inventory of the station
+    /// used doesn't cover the whole period ([`crate::FetchWarning::PeriodNotCovered`]).
+    ///
+    /// # Returns
+    ///
+    /// After calling `.call().await`, returns a `Result` containing the [`HourlyLazyFrame`]
+    /// together with the warnings.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`HourlyClient::location`]: the request still fails if no candidate
+    /// station could be fetched.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meteostat::{LatLon, Meteostat, MeteostatError, Year};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let result = client
+    ///     .hourly()
+    ///     .try_location(LatLon(52.52, 13.40))
+    ///     .station_limit(3)
+    ///     .for_period(Year(2023))
+    ///     .call()
+    ///     .await?;
+    /// for warning in &result.warnings {
+    ///     eprintln!("warning: {warning}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[builder(start_fn = try_location)]
+    #[doc(hidden)]
+    pub async fn build_try_location(
+        &self,
+        #[builder(start_fn)] coordinate: LatLon,
+        max_distance_km: Option<f64>,
+        station_limit: Option<usize>,
+        required_data: Option<RequiredData>,
+        cancellation_token: Option<CancellationToken>,
+        #[builder(with = |period: impl DateTimePeriod| period.get_datetime_period().ok_or(MeteostatError::DateParsingError))]
+        for_period: Option<Result<StartEndDateTime, MeteostatError>>,
+        #[builder(with = |period: impl DatePeriod, tz: &impl TimeZone| period.get_date_period().and_then(|dates| dates.in_timezone(tz)).ok_or(MeteostatError::DateParsingError))]
+        for_local_period: Option<Result<StartEndDateTime, MeteostatError>>,
+    ) -> Result<WithWarnings<HourlyLazyFrame>, MeteostatError> {
+        let period = for_period.transpose()?;
+        let local_period = for_local_period.transpose()?;
+        // With both periods, the frame is filtered to their overlap
+        let requested = match (period, local_period) {
+            (Some(a), Some(b)) => Some(StartEndDateTime {
+                start: a.start.max(b.start),
+                end: a.end.min(b.end),
+            }),
+            (a, b) => a.or(b),
+        };
+        let result = self
             .client
             .data_from_location()
             .location(coordinate)
             .maybe_max_distance_km(max_distance_km)
             .maybe_station_limit(station_limit)
             .maybe_required_data(required_data)
+            .maybe_cancellation_token(cancellation_token)
+            .maybe_check_coverage(requested.map(|period| RequiredData::DateRange {
+                start: period.start.date_naive(),
+                end: period.end.date_naive(),
+            }))
             .frequency(Frequency::Hourly)
             .call()
             .await?;
 
-        Ok(HourlyLazyFrame::new(frame))
+        let periods = period.into_iter().chain(local_period);
+        let frame = with_resolved_periods(HourlyLazyFrame::new(result.value), periods)?;
+        Ok(WithWarnings::new(frame, result.warnings))
+    }
+}
+
+impl<S: hourly_client_build_station_builder::IsComplete>
+    HourlyClientBuildStationBuilder<'_, '_, '_, S>
+{
+    /// Executes the request like `.call()`, then collects the result into a `DataFrame`.
+    pub async fn eager(self) -> Result<DataFrame, MeteostatError> {
+        collect_eagerly(self.call()).await
+    }
+}
+
+impl<S: hourly_client_build_location_builder::IsComplete>
+    HourlyClientBuildLocationBuilder<'_, '_, S>
+{
+    /// Executes the request like `.call()`, then collects the result into a `DataFrame`.
+    pub async fn eager(self) -> Result<DataFrame, MeteostatError> {
+        collect_eagerly(self.call()).await
     }
 }
 
@@ -246,6 +415,27 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_hourly_from_station_builder_for_local_period() -> Result<(), MeteostatError> {
+        let client = Meteostat::new().await?;
+        let cest = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        let local_july = client
+            .hourly()
+            .station("06240") // Schiphol
+            .for_local_period(Month(2023, 7), &cest)
+            .call()
+            .await?
+            .collect_hourly()?;
+        let first = local_july
+            .first()
+            .expect("Expected hourly data for July 2023");
+        assert_eq!(
+            first.datetime,
+            chrono::Utc.with_ymd_and_hms(2023, 6, 30, 22, 0, 0).unwrap()
+        );
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_hourly_from_station_at_specific_datetime() -> Result<(), MeteostatError> {
         let client = Meteostat::new().await?;
@@ -1,4 +1,21 @@
+use crate::{MeteostatError, MeteostatFrame};
+use polars::prelude::DataFrame;
+use std::future::Future;
+
 pub mod climate_client;
 pub mod daily_client;
 pub mod hourly_client;
 pub mod monthly_client;
+
+/// Awaits `request` and collects the frame it returns, for the `eager()` method of the
+/// request builders. For one-shot scripts that don't need lazy filtering: equivalent to
+/// `.call().await?.collect_frame()`.
+///
+/// # Errors
+///
+/// Returns the errors of `request`, or [`MeteostatError::PolarsError`] if collecting fails.
+pub(crate) async fn collect_eagerly<F: MeteostatFrame>(
+    request: impl Future<Output = Result<F, MeteostatError>>,
+) -> Result<DataFrame, MeteostatError> {
+    request.await?.collect_frame()
+}
@@ -4,8 +4,17 @@
 //! allowing the user to specify the data source (station ID or location) before
 //! executing the request to fetch monthly aggregated data.
 
-use crate::{Frequency, LatLon, Meteostat, MeteostatError, MonthlyLazyFrame, RequiredData};
+use crate::clients::collect_eagerly;
+use crate::types::frequency::month_bounds;
+use crate::types::frequency_frames::period_filter::with_resolved_periods;
+use crate::types::traits::period::month_period::MonthPeriod;
+use crate::types::traits::types::StartEndMonth;
+use crate::{
+    CancellationToken, Frequency, LatLon, Meteostat, MeteostatError, MonthlyLazyFrame,
+    RequiredData, WithWarnings,
+};
 use bon::bon;
+use polars::prelude::DataFrame;
 
 /// A client builder specifically for fetching monthly weather data.
 ///
@@ -15,6 +24,8 @@ use bon::bon;
 ///
 /// Calling `.station()` or `.location().call()` executes the request and returns a
 /// [`Result<MonthlyLazyFrame, MeteostatError>`].
+/// Finish with `.eager().await` instead of `.call().await` to get the data as a collected
+/// `DataFrame` right away.
 pub struct MonthlyClient<'a> {
     client: &'a Meteostat,
 }
@@ -57,6 +68,21 @@ impl<'a> MonthlyClient<'a> {
     ///   isn't met, the fetch might fail early or return an error. Defaults to `None`
     ///   (no inventory pre-filtering).
     ///
+    /// * `.skip_validation(bool)`: When `true`, skips checking that the station ID exists in
+    ///   the loaded station list before downloading. Useful if the station list lags behind
+    ///   newly added stations. Defaults to `false`.
+    ///
+    /// * `.cancellation_token(CancellationToken)`: Aborts the download/parse once the token is
+    ///   cancelled, e.g. because the requesting client disconnected. Defaults to `None`.
+    ///
+    /// * `.for_period(period)`: Filters the returned frame to a period (e.g. `Year(2023)`),
+    ///   equivalent to calling `.get_for_period(period)` on the result. Defaults to no filter.
+    ///
+    /// * `.fallback_location(LatLon)`: If the station has no monthly data in the `for_period`
+    ///   range (if given), or its data file is missing or fails to download, returns data from the nearest other suitable
+    ///   station to this location instead, as [`MonthlyClient::location`] would. Defaults to `None`
+    ///   (no fallback).
+    ///
     /// # Returns
     ///
     /// After calling `.call().await`, returns a `Result` containing a [`MonthlyLazyFrame`]
@@ -68,10 +94,18 @@ impl<'a> MonthlyClient<'a> {
     /// # Errors
     ///
     /// Can return:
+    /// *   [`MeteostatError::UnknownStation`]: If the station ID is not in the loaded station list
+    ///     (unless `skip_validation` is set).
+    /// *   [`MeteostatError::Cancelled`]: If the `cancellation_token` is cancelled before the data is ready.
+    /// *   [`MeteostatError::DateParsingError`]: If the `for_period` period cannot be resolved.
     /// *   [`MeteostatError::WeatherData`]: If fetching or parsing the underlying data file fails
     ///     (e.g., network error, file not found, CSV parse error).
     /// *   Could also potentially return an error related to unmet `required_data` criteria
     ///     if the inventory check fails before attempting the fetch (depends on internal logic).
+    /// *   If `fallback_location` is set and the station has no data, the errors of
+    ///     [`MonthlyClient::location`] instead, wrapped in [`MeteostatError::FallbackFailed`] if
+    ///     fetching the station failed as well. An unknown station ID is not a reason to fall
+    ///     back, unless `skip_validation` is set.
     ///
     /// # Example
     ///
@@ -102,13 +136,25 @@ impl<'a> MonthlyClient<'a> {
         &self,
         #[builder(start_fn)] station: &str,
         required_data: Option<RequiredData>,
+        skip_validation: Option<bool>,
+        cancellation_token: Option<CancellationToken>,
+        #[builder(with = |period: impl MonthPeriod| period.get_month_period().ok_or(MeteostatError::DateParsingError))]
+        for_period: Option<Result<StartEndMonth, MeteostatError>>,
+        fallback_location: Option<LatLon>,
     ) -> Result<MonthlyLazyFrame, MeteostatError> {
+        let period = for_period.transpose()?;
         let frame = self
             .client
-            .data_from_station()
+            .data_from_station_or_location()
             .station(station)
             .maybe_required_data(required_data)
+            .maybe_skip_validation(skip_validation)
+            .maybe_cancellation_token(cancellation_token)
+            .maybe_fallback_location(fallback_location)
             .frequency(Frequency::Monthly)
+            .in_range(
+                |frame| Ok(with_resolved_periods(MonthlyLazyFrame::new(frame), period)?.frame),
+            )
             .call()
             .await?;
         Ok(MonthlyLazyFrame::new(frame))
@@ -118,9 +164,12 @@ impl<'a> MonthlyClient<'a> {
     ///
     /// This method starts a builder pattern. You must provide the location.
     /// You can optionally specify:
-    /// *   `.max_distance_km(f64)`: Maximum search radius (default: 50.0 km).
+    /// *   `.max_distance_km(f64)`: Maximum search radius (default: the client's
+    ///     [`crate::Meteostat::default_radius`] for monthly data, 50.0 km unless configured).
     /// *   `.station_limit(usize)`: Max number of candidate stations to *consider* (default: 1). It will return data for the *first* successful one found.
     /// *   `.required_data(RequiredData)`: Filter candidate stations based on their reported data inventory (e.g., `RequiredData::FullYear(2023)`).
+    /// *   `.cancellation_token(CancellationToken)`: Abort the search and download once cancelled; no further candidates are tried.
+    /// *   `.for_period(period)`: Filter the returned frame to a period (e.g. `Year(2023)`), like calling `.get_for_period(period)` on the result.
     ///
     /// Finally, call `.call().await` on the builder to execute the search and data fetch.
     ///
@@ -141,6 +190,8 @@ impl<'a> MonthlyClient<'a> {
     /// *   [`MeteostatError::NoDataFoundForNearbyStations`]: If candidate stations were found, but fetching monthly data failed for all attempted stations.
     /// *   [`MeteostatError::LocateStation`]: If the underlying station search mechanism fails.
     /// *   [`MeteostatError::WeatherData`]: Encapsulated within `NoDataFoundForNearbyStations` if fetching fails for a candidate.
+    /// *   [`MeteostatError::Cancelled`]: If the `cancellation_token` is cancelled.
+    /// *   [`MeteostatError::DateParsingError`]: If the `for_period` period cannot be resolved.
     ///
     /// # Example
     ///
@@ -175,18 +226,117 @@ impl<'a> MonthlyClient<'a> {
         max_distance_km: Option<f64>,
         station_limit: Option<usize>,
         required_data: Option<RequiredData>,
+        cancellation_token: Option<CancellationToken>,
+        #[builder(with = |period: impl MonthPeriod| period.get_month_period().ok_or(MeteostatError::DateParsingError))]
+        for_period: Option<Result<StartEndMonth, MeteostatError>>,
     ) -> Result<MonthlyLazyFrame, MeteostatError> {
-        let frame = self
+        self.build_try_location(
+            coordinate,
+            max_distance_km,
+            station_limit,
+            required_data,
+            cancellation_token,
+            for_period,
+        )
+        .await
+        .map(WithWarnings::into_inner)
+    }
+
+    /// Like [`MonthlyClient::location`], but also reports the problems that didn't stop the
+    /// request, for batch jobs that would rather log them than fail.
+    ///
+    /// Takes the same builder methods as [`MonthlyClient::location`]. The warnings list each
+    /// candidate station that failed before one succeeded
+    /// ([`crate::FetchWarning::StationFailed`]) and,
+    /// with `.for_period(..)`, whether the inventory of the station used doesn't cover the
+    /// whole period ([`crate::FetchWarning::PeriodNotCovered`]).
+    ///
+    /// # Returns
+    ///
+    /// After calling `.call().await`, returns a `Result` containing the [`MonthlyLazyFrame`]
+    /// together with the warnings.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`MonthlyClient::location`]: the request still fails if no candidate
+    /// station could be fetched.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meteostat::{LatLon, Meteostat, MeteostatError, Year};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let result = client
+    ///     .monthly()
+    ///     .try_location(LatLon(52.52, 13.40))
+    ///     .station_limit(3)
+    ///     .for_period(Year(2023))
+    ///     .call()
+    ///     .await?;
+    /// for warning in &result.warnings {
+    ///     eprintln!("warning: {warning}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[builder(start_fn = try_location)]
+    #[doc(hidden)]
+    pub async fn build_try_location(
+        &self,
+        #[builder(start_fn)] coordinate: LatLon,
+        max_distance_km: Option<f64>,
+        station_limit: Option<usize>,
+        required_data: Option<RequiredData>,
+        cancellation_token: Option<CancellationToken>,
+        #[builder(with = |period: impl MonthPeriod| period.get_month_period().ok_or(MeteostatError::DateParsingError))]
+        for_period: Option<Result<StartEndMonth, MeteostatError>>,
+    ) -> Result<WithWarnings<MonthlyLazyFrame>, MeteostatError> {
+        let period = for_period.transpose()?;
+        let requested = period
+            .map(|period| {
+                let (start, _) = month_bounds(period.start.year(), period.start.month())
+                    .ok_or(MeteostatError::DateParsingError)?;
+                let (_, end) = month_bounds(period.end.year(), period.end.month())
+                    .ok_or(MeteostatError::DateParsingError)?;
+                Ok::<_, MeteostatError>(RequiredData::DateRange { start, end })
+            })
+            .transpose()?;
+        let result = self
             .client
             .data_from_location()
             .location(coordinate)
             .maybe_max_distance_km(max_distance_km)
             .maybe_station_limit(station_limit)
             .maybe_required_data(required_data)
+            .maybe_cancellation_token(cancellation_token)
+            .maybe_check_coverage(requested)
             .frequency(Frequency::Monthly)
             .call()
             .await?;
-        Ok(MonthlyLazyFrame::new(frame))
+        let frame = with_resolved_periods(MonthlyLazyFrame::new(result.value), period)?;
+        Ok(WithWarnings::new(frame, result.warnings))
+    }
+}
+
+impl<S: monthly_client_build_station_builder::IsComplete>
+    MonthlyClientBuildStationBuilder<'_, '_, '_, S>
+{
+    /// Executes the request like `.call()`, then collects the result into a `DataFrame`.
+    pub async fn eager(self) -> Result<DataFrame, MeteostatError> {
+        collect_eagerly(self.call()).await
+    }
+}
+
+impl<S: monthly_client_build_location_builder::IsComplete>
+    MonthlyClientBuildLocationBuilder<'_, '_, S>
+{
+    /// Executes the request like `.call()`, then collects the result into a `DataFrame`.
+    pub async fn eager(self) -> Result<DataFrame, MeteostatError> {
+        collect_eagerly(self.call()).await
     }
 }
 
@@ -25,6 +25,9 @@ pub enum MeteostatError {
     #[error("Failed to delete cache '{0}'")]
     CacheDeletionError(PathBuf, #[source] std::io::Error),
 
+    #[error("Invalid client configuration: {0}.")]
+    InvalidConfig(String),
+
     #[error("No {granularity} data found for datetime: {datetime} and position: {latitude}, {longitude}.")]
     NoDataFound {
         datetime: String,
@@ -33,6 +36,25 @@ pub enum MeteostatError {
         granularity: Frequency,
     },
 
+    #[error("The request was cancelled.")]
+    Cancelled,
+
+    #[error("Background task failed to complete")]
+    TaskJoin(#[from] tokio::task::JoinError),
+
+    #[error("Unknown station ID '{id}': not found in the station list.")]
+    UnknownStation { id: String },
+
+    #[error("Station '{station}' has no data available, and the fallback location failed too: {fallback}")]
+    FallbackFailed {
+        station: String,
+        /// Why the preferred station's data couldn't be fetched.
+        #[source]
+        preferred: Box<Self>,
+        /// Why the fallback location's data couldn't be fetched.
+        fallback: Box<Self>,
+    },
+
     #[error("No station within radius: {radius} km, at position {lat}, {lon}")]
     NoStationWithinRadius { radius: f64, lat: f64, lon: f64 },
 
@@ -56,4 +78,42 @@ pub enum MeteostatError {
 
     #[error("Expected a single row DataFrame, but found {actual} rows.")]
     ExpectedSingleRow { actual: usize },
+
+    #[error("Cannot aggregate daily records into a monthly record: records are empty or span multiple months.")]
+    InvalidDailyAggregation,
+
+    #[error("Percentile {0} is outside the range 0 to 100.")]
+    InvalidPercentile(f64),
+
+    #[error("Quantile {0} is outside the range 0 to 1.")]
+    InvalidQuantile(f64),
+
+    #[error("Invalid precipitation phase thresholds: snow below {snow_below_c} °C must not exceed rain above {rain_above_c} °C.")]
+    InvalidPrecipPhaseThresholds {
+        snow_below_c: f64,
+        rain_above_c: f64,
+    },
+
+    #[error("Cannot fit a linear correction from {0} overlapping days; at least 2 with differing values are needed.")]
+    InsufficientOverlap(usize),
+
+    #[error("Cannot concatenate an empty list of frames.")]
+    EmptyConcat,
+
+    #[error("Cannot concatenate frames: in frame {index}, {reason}.")]
+    SchemaMismatch { index: usize, reason: String },
+
+    #[error("Station '{id}' has no data available to export.")]
+    NothingToExport { id: String },
+
+    #[error("Failed to write export file '{0}'")]
+    ExportIo(PathBuf, #[source] std::io::Error),
+
+    #[cfg(feature = "export")]
+    #[error("Failed to write zip archive '{0}'")]
+    ExportArchive(PathBuf, #[source] zip::result::ZipError),
+
+    #[cfg(feature = "xlsx")]
+    #[error("Failed to write spreadsheet '{0}'")]
+    ExportSpreadsheet(PathBuf, #[source] rust_xlsxwriter::XlsxError),
 }
@@ -42,14 +42,14 @@
 //!     // Period for which we want hourly data:
 //!     let period = NaiveDate::from_ymd_opt(2023, 9, 1).unwrap();
 //!
-//!     // --- Example 1: Collect 24 hourly data points into `Vec<Hourly>` ---
+//!     // --- Example 1: Collect 24 hourly data points into `HourlyRecords` ---
 //!     let hourly_vec = client
 //!         .hourly()
 //!         .location(LatLon(52.0836403, 5.1257283))
 //!         .call()
 //!         .await? // `HourlyLazyFrame`
 //!         .get_for_period(period)? // `HourlyLazyFrame` with filter plan
-//!         .collect_hourly()?; // `Vec<Hourly>`
+//!         .collect_hourly()?; // `HourlyRecords`
 //!
 //!     // Do something with the hourly data...
 //!
@@ -76,7 +76,7 @@
 //! *   **Source Specification:** Use `.station("ID")` or `.location(LatLon)` on the frequency client builders.
 //! *   **`LazyFrame` Wrappers:** Fetching data returns structs like [`HourlyLazyFrame`], [`DailyLazyFrame`], [`MonthlyLazyFrame`], [`ClimateLazyFrame`] which contain a Polars `LazyFrame` and provide convenience filtering and collection methods.
 //! *   **Filtering:** Use methods like `get_range`, `get_at`, `get_for_period` on the frame wrappers, or access `.frame` for advanced Polars operations.
-//! *   **Collecting:** Call `.frame.collect()?` on the frame wrappers to execute the query and get a `DataFrame`, OR use specific methods like `.collect_daily()`, `.collect_single_hourly()`, etc., to get results directly as Rust structs (e.g., `Vec<Daily>`, `Hourly`).
+//! *   **Collecting:** Call `.frame.collect()?` on the frame wrappers to execute the query and get a `DataFrame`, OR use specific methods like `.collect_daily()`, `.collect_single_hourly()`, etc., to get results directly as Rust structs (e.g., [`DailyRecords`], `Hourly`).
 //! *   **Finding Stations:** Use [`Meteostat::find_stations`] to search for [`Station`] objects near a [`LatLon`], optionally filtering by [`InventoryRequest`] criteria.
 //!
 //! # Data Source and Attribution
@@ -85,9 +85,12 @@
 //! *   This crate uses Meteostat's **free bulk data interface**. No API key is required. Please consider supporting them if you find their service useful.
 
 // Module structure
+mod cache_backend;
 mod clients;
 mod error;
+pub mod meteo_calc;
 mod meteostat;
+mod parse;
 mod stations;
 mod types;
 mod utils;
@@ -95,12 +98,33 @@ mod weather_data;
 
 // --- Core Exports ---
 pub use error::MeteostatError;
-pub use meteostat::{InventoryRequest, LatLon, Meteostat};
+pub use meteostat::{
+    AvailabilityResult, HealthReport, InventoryRequest, LatLon, Meteostat, PurgeReport,
+    UpdateStatus, VersionInfo, DEFAULT_MAX_DISTANCE_KM, STATION_ID_COLUMN,
+};
+pub use stations::locate_station::STATION_CACHE_FORMAT_VERSION;
+
+// --- Cache Storage ---
+pub use cache_backend::{CacheBackend, CacheFuture, FileSystemCache, MemoryCache};
 
 // --- Data Types & Enums ---
-pub use types::frequency::{Frequency, RequiredData};
-pub use types::station::Station;
+pub use types::csv_options::CsvOptions;
+pub use types::data_format::{DataFormat, DataSource};
+pub use types::distance_metric::{distance_km, DistanceMetric};
+#[cfg(feature = "export")]
+pub use types::export_format::ExportFormat;
+pub use types::frequency::{
+    Frequency, RequiredData, CLIMATE_COLUMNS, DAILY_COLUMNS, HOURLY_COLUMNS, MONTHLY_COLUMNS,
+};
+pub use types::precip_phase::{PrecipPhase, PrecipPhaseRules, PRECIP_PHASE_COLUMN};
+pub use types::records::{ClimateRecords, DailyRecords, HourlyRecords, MonthlyRecords, Records};
+pub use types::station::{
+    Coverage, DateRange, Station, StationInventory, StationWithDistance, YearRange,
+};
+pub use types::unit_system::UnitSystem;
 pub use types::weather_condition::WeatherCondition;
+pub use types::wind_rose::{WindRose, CALM_WIND_SPEED_KMH};
+pub use types::with_warnings::{FetchWarning, WithWarnings};
 
 // --- Time/Date Trait Exports (for filtering convenience) ---
 pub use types::traits::any::any_date::AnyDate;
@@ -111,6 +135,7 @@ pub use types::traits::period::datetime_period::DateTimePeriod;
 pub use types::traits::period::month_period::MonthPeriod;
 // Concrete time period types
 pub use types::traits::types::{Month, Year};
+pub use types::traits::weather_comparison::WeatherComparison;
 
 // --- Clients ---
 pub use clients::climate_client::ClimateClient;
@@ -118,11 +143,16 @@ pub use clients::daily_client::DailyClient;
 pub use clients::hourly_client::HourlyClient;
 pub use clients::monthly_client::MonthlyClient;
 
+// --- Parsing CSV files obtained elsewhere ---
+pub use parse::{parse_climate_csv, parse_daily_csv, parse_hourly_csv, parse_monthly_csv};
+
 // --- Result Struct Exports (Needed for Vec<Struct> collection) ---
 // These are the structs returned by collect_daily(), collect_hourly(), etc.
 pub use types::frequency_frames::climate_frame::Climate;
-pub use types::frequency_frames::daily_frame::Daily;
-pub use types::frequency_frames::hourly_frame::Hourly;
+pub use types::frequency_frames::daily_frame::{
+    Daily, DailyExtreme, DailyExtremes, DailyField, Exceedance, MissingSnow, SnowDays,
+};
+pub use types::frequency_frames::hourly_frame::{Hourly, HourlyField, TimeAnomaly};
 pub use types::frequency_frames::monthly_frame::Monthly;
 
 // --- LazyFrame Wrapper Exports ---
@@ -130,8 +160,13 @@ pub use types::frequency_frames::monthly_frame::Monthly;
 pub use types::frequency_frames::climate_frame::ClimateLazyFrame;
 pub use types::frequency_frames::daily_frame::DailyLazyFrame;
 pub use types::frequency_frames::hourly_frame::HourlyLazyFrame;
+pub use types::frequency_frames::meteostat_frame::MeteostatFrame;
 pub use types::frequency_frames::monthly_frame::MonthlyLazyFrame;
+pub use types::frequency_frames::period_filter::PeriodFilter;
 
 // --- Sub-Error Type Exports (useful for specific error matching) ---
 pub use stations::error::LocateStationError;
 pub use weather_data::error::WeatherDataError;
+
+// --- Cancellation (re-exported so callers don't need a direct tokio-util dependency) ---
+pub use tokio_util::sync::CancellationToken;
@@ -0,0 +1,165 @@
+//! Standalone meteorological conversions.
+//!
+//! Meteostat only provides dew point and relative humidity in hourly data. These functions
+//! convert between the two (given the air temperature) using the Magnus formula, so they can
+//! be derived for daily data or for any other inputs. [`circular_mean_degrees`] averages
+//! wind directions.
+//!
+//! The coefficients are those recommended by Alduchov & Eskridge (1996), which are accurate
+//! to within about 0.4% for temperatures between -40 °C and 50 °C.
+
+/// Magnus coefficient `b` (dimensionless).
+const MAGNUS_B: f64 = 17.625;
+/// Magnus coefficient `c` in °C.
+const MAGNUS_C: f64 = 243.04;
+/// Saturation vapour pressure at 0 °C in hPa.
+const MAGNUS_A: f64 = 6.1094;
+
+/// `ln(e_s(T) / A)`, the term shared by all Magnus conversions.
+fn magnus_gamma(temperature_c: f64) -> f64 {
+    MAGNUS_B * temperature_c / (MAGNUS_C + temperature_c)
+}
+
+/// Computes the saturation vapour pressure over water.
+///
+/// # Arguments
+///
+/// * `temperature_c` - Air temperature in °C.
+///
+/// # Returns
+///
+/// The saturation vapour pressure in hPa.
+#[must_use]
+pub fn saturation_vapour_pressure(temperature_c: f64) -> f64 {
+    MAGNUS_A * magnus_gamma(temperature_c).exp()
+}
+
+/// Computes the dew point from air temperature and relative humidity.
+///
+/// # Arguments
+///
+/// * `temperature_c` - Air temperature in °C.
+/// * `relative_humidity` - Relative humidity in percent (0-100], as in Meteostat's `rhum` column.
+///
+/// # Returns
+///
+/// The dew point in °C. A relative humidity of 0 yields negative infinity.
+///
+/// # Example
+///
+/// ```
+/// use meteostat::meteo_calc::dewpoint_from_relative_humidity;
+///
+/// let dewpoint = dewpoint_from_relative_humidity(20.0, 50.0);
+/// assert!((dewpoint - 9.26).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn dewpoint_from_relative_humidity(temperature_c: f64, relative_humidity: f64) -> f64 {
+    let gamma = (relative_humidity / 100.0).ln() + magnus_gamma(temperature_c);
+    MAGNUS_C * gamma / (MAGNUS_B - gamma)
+}
+
+/// Computes the relative humidity from air temperature and dew point.
+///
+/// # Arguments
+///
+/// * `temperature_c` - Air temperature in °C.
+/// * `dewpoint_c` - Dew point in °C.
+///
+/// # Returns
+///
+/// The relative humidity in percent. Values above 100 indicate a dew point above the air
+/// temperature (supersaturation), which usually points at inconsistent inputs.
+///
+/// # Example
+///
+/// ```
+/// use meteostat::meteo_calc::relative_humidity_from_dewpoint;
+///
+/// let humidity = relative_humidity_from_dewpoint(20.0, 20.0);
+/// assert!((humidity - 100.0).abs() < 1e-9);
+/// ```
+#[must_use]
+pub fn relative_humidity_from_dewpoint(temperature_c: f64, dewpoint_c: f64) -> f64 {
+    100.0 * (magnus_gamma(dewpoint_c) - magnus_gamma(temperature_c)).exp()
+}
+
+/// Below this length of the mean unit vector, directions are considered to cancel out.
+const MIN_RESULTANT_LENGTH: f64 = 1e-9;
+
+/// Averages directions in degrees as unit vectors, so that e.g. 350° and 10° average to 0°
+/// rather than the 180° of an arithmetic mean.
+///
+/// Returns the mean direction in degrees within `[0, 360)`, or `None` if there are no
+/// directions or they cancel out (such as 90° and 270°).
+///
+/// # Example
+///
+/// ```
+/// use meteostat::meteo_calc::circular_mean_degrees;
+///
+/// let mean = circular_mean_degrees([350.0, 10.0]).unwrap_or_default();
+/// assert!(mean.min(360.0 - mean) < 1e-9);
+/// ```
+#[must_use]
+pub fn circular_mean_degrees(directions: impl IntoIterator<Item = f64>) -> Option<f64> {
+    let (sin_sum, cos_sum, count) =
+        directions
+            .into_iter()
+            .fold((0.0, 0.0, 0_u32), |(sin_sum, cos_sum, count), direction| {
+                let radians = direction.to_radians();
+                (sin_sum + radians.sin(), cos_sum + radians.cos(), count + 1)
+            });
+    if count == 0 || sin_sum.hypot(cos_sum) / f64::from(count) < MIN_RESULTANT_LENGTH {
+        return None;
+    }
+    let mean = sin_sum.atan2(cos_sum).to_degrees().rem_euclid(360.0);
+    // `rem_euclid` rounds tiny negative angles up to exactly 360
+    Some(if mean >= 360.0 { 0.0 } else { mean })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saturation_vapour_pressure() {
+        assert!((saturation_vapour_pressure(0.0) - MAGNUS_A).abs() < 1e-9);
+        // Reference value at 20 °C is about 23.4 hPa
+        assert!((saturation_vapour_pressure(20.0) - 23.37).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_dewpoint_humidity_round_trip() {
+        for (temperature, humidity) in [(-10.0, 80.0), (0.0, 100.0), (15.0, 65.0), (35.0, 20.0)] {
+            let dewpoint = dewpoint_from_relative_humidity(temperature, humidity);
+            assert!(dewpoint <= temperature + 1e-9);
+            let back = relative_humidity_from_dewpoint(temperature, dewpoint);
+            assert!(
+                (back - humidity).abs() < 1e-9,
+                "round trip for {temperature} °C / {humidity}% gave {back}%"
+            );
+        }
+    }
+
+    #[test]
+    fn test_saturated_air_dewpoint_equals_temperature() {
+        assert!((dewpoint_from_relative_humidity(12.5, 100.0) - 12.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_circular_mean_degrees() {
+        // Compares angles, so that 359.999... is close to 0
+        let close = |a: Option<f64>, b: f64| {
+            a.is_some_and(|a| {
+                (0.0..360.0).contains(&a)
+                    && ((a - b + 180.0).rem_euclid(360.0) - 180.0).abs() < 1e-9
+            })
+        };
+        assert!(close(circular_mean_degrees([350.0, 10.0]), 0.0));
+        assert!(close(circular_mean_degrees([80.0, 100.0]), 90.0));
+        assert!(close(circular_mean_degrees([270.0, 0.0]), 315.0));
+        assert_eq!(circular_mean_degrees([90.0, 270.0]), None);
+        assert_eq!(circular_mean_degrees([]), None);
+    }
+}
@@ -4,20 +4,105 @@
 //! different types of weather data (hourly, daily, monthly, climate normals)
 //! either by station ID or by geographical location.
 
-use crate::stations::locate_station::{StationLocator, RKYV_CACHE_FILE_NAME};
+use crate::cache_backend::{entry_path, CacheBackend, FileSystemCache};
+use crate::stations::locate_station::{
+    StationLocator, DEFAULT_STATION_DATA_URL, RKYV_CACHE_FILE_NAME, STATION_CACHE_FORMAT_VERSION,
+    VALIDATORS_FILE_NAME,
+};
+#[cfg(feature = "export")]
+use crate::types::export_format::write_export;
 use crate::types::station::StationWithDistance;
 use crate::utils::{ensure_cache_dir_exists, get_cache_dir};
+use crate::weather_data::data_loader::{DEFAULT_BULK_DATA_BASE_URL, DEFAULT_CACHE_COMPRESSION};
 use crate::weather_data::frame_fetcher::FrameFetcher;
 use crate::RequiredData::Any;
 use crate::{
-    ClimateClient, DailyClient, Frequency, HourlyClient, MeteostatError, MonthlyClient,
-    RequiredData,
+    ClimateClient, Coverage, CsvOptions, DailyClient, DailyLazyFrame, DataFormat, DataSource,
+    DistanceMetric, FetchWarning, Frequency, HourlyClient, MeteostatError, MonthlyClient,
+    RequiredData, Station, WithWarnings,
 };
-use bon::bon;
-use polars::prelude::LazyFrame;
+use bon::{bon, Builder};
+use chrono::{DateTime, Utc};
+use futures_util::future::join_all;
+use polars::prelude::{col, len, lit, Expr, LazyFrame, ParquetCompression};
 use serde::{Deserialize, Serialize};
-use std::io;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+
+/// How long [`Meteostat::health_check`] waits for the server before reporting it unreachable.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+/// Cache key written and removed again by [`Meteostat::health_check`].
+const HEALTH_CHECK_CACHE_KEY: &str = ".health_check";
+
+/// Number of downloads that may run at the same time unless configured otherwise.
+pub const DEFAULT_MAX_CONCURRENT_DOWNLOADS: usize = 6;
+
+/// Search radius for location requests and station searches unless configured otherwise.
+pub const DEFAULT_MAX_DISTANCE_KM: f64 = 50.0;
+
+/// Name of the column holding the station ID, added to frames when the client is built with
+/// `.tag_station_id(true)`.
+pub const STATION_ID_COLUMN: &str = "station_id";
+
+/// Replaces nulls with 0 in those of `columns` that exist for the given frequency.
+fn fill_null_as_zero(frame: LazyFrame, frequency: Frequency, columns: &[&str]) -> LazyFrame {
+    let schema = frequency.schema();
+    let fills: Vec<Expr> = columns
+        .iter()
+        .filter(|column| schema.contains(column))
+        .map(|column| col(*column).fill_null(lit(0)))
+        .collect();
+    if fills.is_empty() {
+        frame
+    } else {
+        frame.with_columns(fills)
+    }
+}
+
+/// Rejects builder options that contradict each other, so configuration mistakes surface
+/// when the client is built rather than as surprising behavior later.
+fn validate_options<const N: usize>(
+    cache_folder: Option<&PathBuf>,
+    cache_backend: Option<&dyn CacheBackend>,
+    cache_lock_timeout: Option<Duration>,
+    max_concurrent_downloads: Option<usize>,
+    urls: [(&str, Option<&str>); N],
+) -> Result<(), MeteostatError> {
+    let invalid = |reason: String| Err(MeteostatError::InvalidConfig(reason));
+    if max_concurrent_downloads == Some(0) {
+        return invalid(
+            "max concurrent downloads is 0, so nothing could be downloaded".to_string(),
+        );
+    }
+    if let Some(backend) = cache_backend {
+        if let Some(folder) = cache_folder {
+            return invalid(format!(
+                "cache folder '{}' is given together with a cache backend, which doesn't use it",
+                folder.display()
+            ));
+        }
+        if cache_lock_timeout.is_some() && backend.local_path("").is_none() {
+            return invalid(
+                "cache lock timeout is set, but the cache backend has no local files to lock"
+                    .to_string(),
+            );
+        }
+    }
+    for (option, url) in urls {
+        let Some(url) = url else { continue };
+        let scheme = reqwest::Url::parse(url).map(|url| url.scheme().to_string());
+        if !matches!(scheme.as_deref(), Ok("http" | "https")) {
+            return invalid(format!("{option} '{url}' is not an http(s) URL"));
+        }
+    }
+    Ok(())
+}
 
 /// Represents a geographical coordinate using Latitude and Longitude.
 ///
@@ -45,11 +130,28 @@ impl LatLon {
 ///
 /// Used in conjunction with [`Meteostat::find_stations`] to find stations that
 /// report having data for a specific frequency and meeting certain data coverage requirements.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Besides [`InventoryRequest::new`], common requests can be made with shorthands like
+/// [`InventoryRequest::daily_any`] and [`InventoryRequest::hourly_year`], or with the builder:
+///
+/// ```
+/// # use meteostat::{Frequency, InventoryRequest, RequiredData};
+/// let request = InventoryRequest::builder().frequency(Frequency::Hourly).build();
+/// assert_eq!(request, InventoryRequest::hourly_any());
+///
+/// let request = InventoryRequest::builder()
+///     .frequency(Frequency::Daily)
+///     .required_data(RequiredData::FullYear(2023))
+///     .build();
+/// assert_eq!(request, InventoryRequest::daily_year(2023));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Builder)]
 pub struct InventoryRequest {
     /// The required data frequency (e.g., Hourly, Daily).
     frequency: Frequency,
     /// The specific data coverage requirement (e.g., Any, FullYear(2023)).
+    /// Defaults to [`RequiredData::Any`].
+    #[builder(default = RequiredData::Any)]
     required_data: RequiredData,
 }
 
@@ -67,6 +169,150 @@ impl InventoryRequest {
             required_data,
         }
     }
+
+    /// Requests stations that report any hourly data.
+    #[must_use]
+    pub const fn hourly_any() -> Self {
+        Self::new(Frequency::Hourly, RequiredData::Any)
+    }
+
+    /// Requests stations that report any daily data.
+    #[must_use]
+    pub const fn daily_any() -> Self {
+        Self::new(Frequency::Daily, RequiredData::Any)
+    }
+
+    /// Requests stations that report any monthly data.
+    #[must_use]
+    pub const fn monthly_any() -> Self {
+        Self::new(Frequency::Monthly, RequiredData::Any)
+    }
+
+    /// Requests stations that report climate normals.
+    #[must_use]
+    pub const fn climate_any() -> Self {
+        Self::new(Frequency::Climate, RequiredData::Any)
+    }
+
+    /// Requests stations whose hourly data covers the full calendar `year`.
+    #[must_use]
+    pub const fn hourly_year(year: i32) -> Self {
+        Self::new(Frequency::Hourly, RequiredData::FullYear(year))
+    }
+
+    /// Requests stations whose daily data covers the full calendar `year`.
+    #[must_use]
+    pub const fn daily_year(year: i32) -> Self {
+        Self::new(Frequency::Daily, RequiredData::FullYear(year))
+    }
+
+    /// Requests stations whose monthly data covers the calendar `year`.
+    #[must_use]
+    pub const fn monthly_year(year: i32) -> Self {
+        Self::new(Frequency::Monthly, RequiredData::FullYear(year))
+    }
+}
+
+/// The outcome of [`Meteostat::health_check`].
+///
+/// Every check reports `false` on failure instead of returning an error, so the report
+/// can be used directly for readiness probes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// Whether the Meteostat bulk server answered a lightweight request.
+    pub server_reachable: bool,
+    /// Whether the station list cache file exists and holds valid data.
+    pub station_cache_valid: bool,
+    /// The number of stations in the in-memory station list.
+    pub stations_loaded: usize,
+    /// Whether new entries can be written to the cache (a file in the cache directory, for
+    /// the default [`crate::FileSystemCache`]).
+    pub cache_dir_writable: bool,
+}
+
+impl HealthReport {
+    /// Returns `true` if all checks passed and at least one station is loaded.
+    #[must_use]
+    pub const fn is_healthy(&self) -> bool {
+        self.server_reachable
+            && self.station_cache_valid
+            && self.stations_loaded > 0
+            && self.cache_dir_writable
+    }
+}
+
+/// The versions and data sources of a client, returned by [`Meteostat::version_info`].
+///
+/// Useful to log next to results, or to attach to a bug report about unexpected data. Its
+/// `Display` output is a single line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionInfo {
+    /// The version of this crate.
+    pub crate_version: String,
+    /// The URL the station list is downloaded from.
+    pub station_data_url: String,
+    /// The base URL weather data files are downloaded from.
+    pub bulk_data_base_url: String,
+    /// The format version of the station list cache, see [`STATION_CACHE_FORMAT_VERSION`].
+    pub station_cache_format_version: u32,
+}
+
+impl fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "meteostat {} (stations: {}, data: {}, station cache format v{})",
+            self.crate_version,
+            self.station_data_url,
+            self.bulk_data_base_url,
+            self.station_cache_format_version
+        )
+    }
+}
+
+/// The outcome of [`Meteostat::check_updates`] for one station.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateStatus {
+    /// The station ID.
+    pub station: String,
+    /// When the station's cache file was last written, or `None` if it isn't cached.
+    pub cached_at: Option<DateTime<Utc>>,
+    /// The `Last-Modified` time of the remote file, or `None` if the server doesn't report
+    /// one or the request failed (e.g. because the station has no data at this frequency).
+    pub remote_modified: Option<DateTime<Utc>>,
+}
+
+impl UpdateStatus {
+    /// Returns `Some(true)` if the remote file changed after the cache file was written,
+    /// `Some(false)` if the cache is up to date, and `None` if the station isn't cached or
+    /// the remote modification time is unknown.
+    #[must_use]
+    pub fn is_stale(&self) -> Option<bool> {
+        Some(self.remote_modified? > self.cached_at?)
+    }
+}
+
+/// The outcome of [`Meteostat::purge_cache_older_than`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PurgeReport {
+    /// The number of weather data cache entries removed.
+    pub files_removed: usize,
+    /// The total size of the removed entries, in bytes. Entries whose size the cache backend
+    /// doesn't report (see [`crate::CacheBackend::size`]) are not counted.
+    pub bytes_freed: u64,
+}
+
+/// The outcome of [`Meteostat::check_availability`] for one station.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvailabilityResult {
+    /// The station ID.
+    pub station: String,
+    /// How much of the requested period the station's inventory covers.
+    /// [`Coverage::None`] if the station isn't in the station list.
+    pub coverage: Coverage,
+    /// The first and last year the inventory reports for the frequency, or `None` if it
+    /// reports no range or the station isn't in the station list.
+    pub reported_years: Option<(i32, i32)>,
 }
 
 /// The main client struct for accessing Meteostat data.
@@ -74,11 +320,19 @@ impl InventoryRequest {
 /// Provides methods to fetch weather data (hourly, daily, monthly, climate)
 /// and find weather stations. Handles data caching internally.
 ///
-/// Create instances using [`Meteostat::new`] or [`Meteostat::with_cache_folder`].
+/// Create instances using [`Meteostat::new`], [`Meteostat::with_cache_folder`] or
+/// [`Meteostat::builder`] for full control over the cache location and download URLs.
 pub struct Meteostat {
     fetcher: FrameFetcher,
     station_locator: StationLocator,
-    cache_folder: PathBuf,
+    /// Where the station list and weather data are cached.
+    cache: Arc<dyn CacheBackend>,
+    /// Columns whose nulls are replaced by 0 in every returned frame, if they exist there.
+    null_as_zero_columns: Vec<&'static str>,
+    /// Whether returned frames get a [`STATION_ID_COLUMN`] with the station they came from.
+    tag_station_id: bool,
+    /// Search radii that replace [`DEFAULT_MAX_DISTANCE_KM`] for specific frequencies.
+    default_radius_km: HashMap<Frequency, f64>,
 }
 
 #[bon]
@@ -91,7 +345,7 @@ impl Meteostat {
     /// # Arguments
     ///
     /// * `cache_folder` - A `PathBuf` representing the directory to use for caching
-    ///   station metadata and downloaded weather data.
+    ///   station metadata and downloaded weather data. Overrides `METEOSTAT_CACHE_DIR`.
     ///
     /// # Returns
     ///
@@ -128,24 +382,362 @@ impl Meteostat {
     /// # }
     /// ```
     pub async fn with_cache_folder(cache_folder: PathBuf) -> Result<Self, MeteostatError> {
-        // Ensure the directory exists
-        ensure_cache_dir_exists(&cache_folder)
+        Self::builder().cache_folder(cache_folder).build().await
+    }
+
+    /// Creates a new `Meteostat` client that uses the given stations instead of the
+    /// station list served by Meteostat, with a specific cache folder.
+    ///
+    /// The station R-tree and lookup indexes are built from `stations` directly: the station
+    /// list isn't downloaded or read from the cache, so every search and lookup works on
+    /// exactly these stations. This makes the station set deterministic, for tests and for
+    /// offline or pinned deployments (e.g. with a snapshot loaded by [`Station::from_json`]).
+    /// Weather data is fetched and cached in `cache_folder` the same way as for
+    /// [`Meteostat::with_cache_folder`]. Use [`Meteostat::builder`] with `.stations(..)` to
+    /// combine this with other options.
+    ///
+    /// Note that [`Meteostat::rebuild_station_list_cache`] replaces the stations with the
+    /// live list.
+    ///
+    /// # Arguments
+    ///
+    /// * `stations` - The stations the client searches and looks up.
+    /// * `cache_folder` - The directory to use for caching weather data.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the initialized `Meteostat` client or a `MeteostatError`
+    /// if initialization fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::CacheDirCreation`] if the cache directory cannot be created.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meteostat::{Meteostat, Station};
+    /// use std::path::PathBuf;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let json = std::fs::read_to_string("stations-2024-01.json")?;
+    /// let client =
+    ///     Meteostat::with_stations(Station::from_json(&json)?, PathBuf::from("weather_cache"))
+    ///         .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_stations(
+        stations: Vec<Station>,
+        cache_folder: PathBuf,
+    ) -> Result<Self, MeteostatError> {
+        Self::builder()
+            .stations(stations)
+            .cache_folder(cache_folder)
+            .build()
             .await
-            .map_err(|e| MeteostatError::CacheDirCreation(cache_folder.clone(), e))?;
+    }
+
+    /// Sets the search radius used for `frequency` when none is given explicitly.
+    ///
+    /// Hourly stations are sparser than daily ones, so a location request that easily finds
+    /// daily data within the default 50 km may need a wider search for hourly data or climate
+    /// normals. The radius applies to `.location(..)` requests of that frequency, and to
+    /// [`Meteostat::find_stations`] and [`Meteostat::nearest_station`] when their inventory
+    /// request is for that frequency. An explicit `.max_distance_km(..)` still takes precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `frequency` - The data [`Frequency`] to set the radius for.
+    /// * `radius_km` - The default search radius in kilometers.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Frequency, LatLon, Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new()
+    ///     .await?
+    ///     .with_default_radius(Frequency::Hourly, 100.0)
+    ///     .with_default_radius(Frequency::Climate, 150.0);
+    ///
+    /// // Searches up to 100 km for a station with hourly data
+    /// let hourly = client.hourly().location(LatLon(64.14, -21.94)).call().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_default_radius(mut self, frequency: Frequency, radius_km: f64) -> Self {
+        self.default_radius_km.insert(frequency, radius_km);
+        self
+    }
+
+    /// Downloads weather data files in `data_format` instead of the bulk CSV files.
+    ///
+    /// The files are parsed into the same frame schema either way, and data that is already
+    /// cached is used regardless of the format it was downloaded in. See [`DataFormat`] for
+    /// where the files are expected on the server; the Meteostat bulk server only publishes
+    /// CSV, so [`DataFormat::Json`] needs a mirror set with `.bulk_data_base_url(..)`. This
+    /// is shorthand for
+    /// `.with_source_fallback(&[DataSource::Cache, data_format.into()])`, and replaces a
+    /// fallback order set before.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{DataFormat, Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::builder()
+    ///     .bulk_data_base_url("http://localhost:8080")
+    ///     .build()
+    ///     .await?
+    ///     .with_data_format(DataFormat::Json);
+    ///
+    /// // Downloads http://localhost:8080/daily/10637.json.gz
+    /// let daily = client.daily().station("10637").call().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_data_format(self, data_format: DataFormat) -> Self {
+        self.with_source_fallback(&[DataSource::Cache, data_format.into()])
+    }
+
+    /// Sets where weather data is loaded from: each source is tried in order until one
+    /// succeeds, so data can still be loaded when one of them has an outage or a station's
+    /// file in one format is broken.
+    ///
+    /// Defaults to `[DataSource::Cache, DataSource::Csv]`, which an empty slice restores.
+    /// Downloaded data is always written to the cache; leaving out [`DataSource::Cache`]
+    /// only means it isn't read from there, so every request downloads fresh data. Putting
+    /// it last uses the cache only when the downloads fail, e.g. while offline.
+    ///
+    /// If every source fails, the error of a single failed source is returned as is, and
+    /// several as [`crate::WeatherDataError::AllSourcesFailed`] listing each attempt. When only
+    /// cache sources are configured and the data isn't cached, the error is
+    /// [`crate::WeatherDataError::NotCached`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{DataSource, Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// // Try the mirror's CSV files, then its JSON files, then whatever is cached
+    /// let client = Meteostat::builder()
+    ///     .bulk_data_base_url("https://weather-mirror.example.com")
+    ///     .build()
+    ///     .await?
+    ///     .with_source_fallback(&[DataSource::Csv, DataSource::Json, DataSource::Cache]);
+    /// let daily = client.daily().station("10637").call().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_source_fallback(mut self, sources: &[DataSource]) -> Self {
+        self.fetcher = self.fetcher.with_sources(sources);
+        self
+    }
+
+    /// The search radius used for `frequency` when none is given explicitly: the radius set
+    /// with [`Meteostat::with_default_radius`], or [`DEFAULT_MAX_DISTANCE_KM`] (50 km).
+    #[must_use]
+    pub fn default_radius(&self, frequency: Frequency) -> f64 {
+        self.default_radius_km
+            .get(&frequency)
+            .copied()
+            .unwrap_or(DEFAULT_MAX_DISTANCE_KM)
+    }
+
+    /// Starts a builder for a `Meteostat` client with custom configuration.
+    ///
+    /// Besides the cache folder, this allows overriding the URLs data is downloaded from,
+    /// for example to point the client at a local mirror or at a mock server in tests.
+    ///
+    /// # Optional Builder Methods
+    ///
+    /// * `.cache_folder(PathBuf)`: Directory used for caching station metadata and weather
+    ///   data. Takes precedence over `METEOSTAT_CACHE_DIR`. Defaults to the same location as
+    ///   [`Meteostat::new`].
+    /// * `.station_data_url(impl Into<String>)`: URL of the gzipped JSON station list.
+    ///   Defaults to `https://bulk.meteostat.net/v2/stations/lite.json.gz`.
+    /// * `.bulk_data_base_url(impl Into<String>)`: Base URL for weather data files; the file
+    ///   path `{frequency}/{station}.csv.gz` is appended to it.
+    ///   Defaults to `https://bulk.meteostat.net/v2`.
+    /// * `.cache_compression(ParquetCompression)`: Compression used when writing weather data
+    ///   cache files. Defaults to `ParquetCompression::Zstd(None)`, which keeps large caches
+    ///   small at a modest CPU cost. Existing cache files are read regardless of how they
+    ///   were compressed.
+    /// * `.max_concurrent_downloads(usize)`: Maximum number of simultaneous downloads across
+    ///   all requests made through this client, covering both the station list and weather
+    ///   data files. Further downloads wait for a free slot, so spawning many tasks does not
+    ///   open more connections. Defaults to 6; a value of 0 is rejected.
+    /// * `.cache_lock_timeout(Duration)`: Lock weather data cache files with an advisory
+    ///   file lock while downloading and writing them, so several processes sharing a
+    ///   cache folder (e.g. on a shared volume) don't download the same file at once. A
+    ///   process waits at most this long for another one; after that the holder is assumed
+    ///   to be stuck and the lock is taken over. Locks are released as soon as the file is
+    ///   written, and by the operating system if a process crashes. Defaults to no locking.
+    /// * `.distance_metric(DistanceMetric)`: How distances between a location and stations
+    ///   are computed when searching nearby stations. This affects both the reported
+    ///   distances and the ranking. Defaults to [`DistanceMetric::Haversine`] for speed; use
+    ///   [`DistanceMetric::Vincenty`] for ellipsoidal precision near the poles or over long
+    ///   distances.
+    /// * `.treat_precip_null_as_zero(bool)`: Meteostat leaves precipitation (`prcp`) empty
+    ///   both when it wasn't measured and, at some stations, when no precipitation fell. When
+    ///   `true`, every frame this client returns has those nulls replaced by 0, so totals and
+    ///   day counts don't depend on a station's reporting convention, at the cost of counting
+    ///   unmeasured periods as dry. Defaults to `false`, which keeps the data as published.
+    /// * `.treat_snow_null_as_zero(bool)`: The same for snow depth (`snow`), which most
+    ///   stations only report while there is snow on the ground. Defaults to `false`.
+    /// * `.tag_station_id(bool)`: When `true`, every frame this client returns gets a
+    ///   [`STATION_ID_COLUMN`] (`station_id`) column with the ID of the station the data came
+    ///   from, which is also the station picked for location requests. This keeps rows
+    ///   traceable after concatenating frames of several stations. The column is a literal,
+    ///   so it costs next to nothing. Defaults to `false`.
+    /// * `.csv_options(CsvOptions)`: Escape hatches for downloaded CSV files that deviate
+    ///   from Meteostat's format, such as a stray extra column, a header row or custom null
+    ///   values. See [`CsvOptions`]. Defaults to the strict format.
+    /// * `.cache_backend(Arc<dyn CacheBackend>)`: Store the station list and weather data in
+    ///   this [`CacheBackend`] instead of a local directory, e.g. [`crate::MemoryCache`] or your
+    ///   own implementation backed by an object store when the filesystem is ephemeral.
+    ///   Cannot be combined with `.cache_folder(..)`. Defaults to a [`FileSystemCache`] in
+    ///   the cache folder.
+    /// * `.stations(Vec<Station>)`: Use these stations instead of the station list from
+    ///   `station_data_url`, which is then neither downloaded nor read from the cache. See
+    ///   [`Meteostat::with_stations`].
+    ///
+    /// # Returns
+    ///
+    /// After calling `.build().await`, a `Result` containing the initialized `Meteostat` client.
+    ///
+    /// # Errors
+    ///
+    /// This function can return errors if:
+    /// - The options contradict each other or a URL isn't an `http(s)` URL
+    ///   ([`MeteostatError::InvalidConfig`]): a cache folder together with a cache backend,
+    ///   a cache lock timeout with a backend that doesn't store entries as local files,
+    ///   so there is nothing to lock, or a maximum of 0 concurrent downloads.
+    /// - No cache folder is given and the default one cannot be determined
+    ///   ([`MeteostatError::CacheDirResolution`]).
+    /// - The cache directory cannot be created ([`MeteostatError::CacheDirCreation`]).
+    /// - Loading or initializing station data fails (propagated from `StationLocator::new`,
+    ///   resulting in [`MeteostatError::LocateStation`]).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meteostat::{Meteostat, MeteostatError};
+    /// use polars::prelude::ParquetCompression;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::builder()
+    ///     .cache_compression(ParquetCompression::Snappy)
+    ///     .max_concurrent_downloads(4)
+    ///     .station_data_url("http://localhost:8080/stations/lite.json.gz")
+    ///     .bulk_data_base_url("http://localhost:8080")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[builder(start_fn = builder, finish_fn = build)]
+    #[doc(hidden)]
+    pub async fn build_with_options(
+        cache_folder: Option<PathBuf>,
+        #[builder(into)] station_data_url: Option<String>,
+        #[builder(into)] bulk_data_base_url: Option<String>,
+        cache_compression: Option<ParquetCompression>,
+        max_concurrent_downloads: Option<usize>,
+        cache_lock_timeout: Option<Duration>,
+        distance_metric: Option<DistanceMetric>,
+        treat_precip_null_as_zero: Option<bool>,
+        treat_snow_null_as_zero: Option<bool>,
+        tag_station_id: Option<bool>,
+        csv_options: Option<CsvOptions>,
+        cache_backend: Option<Arc<dyn CacheBackend>>,
+        stations: Option<Vec<Station>>,
+    ) -> Result<Self, MeteostatError> {
+        validate_options(
+            cache_folder.as_ref(),
+            cache_backend.as_deref(),
+            cache_lock_timeout,
+            max_concurrent_downloads,
+            [
+                ("station_data_url", station_data_url.as_deref()),
+                ("bulk_data_base_url", bulk_data_base_url.as_deref()),
+            ],
+        )?;
+        let cache = match cache_backend {
+            Some(cache) => cache,
+            None => {
+                let cache_folder = match cache_folder {
+                    Some(folder) => folder,
+                    None => Self::default_cache_dir()?,
+                };
+
+                // Ensure the directory exists
+                ensure_cache_dir_exists(&cache_folder)
+                    .await
+                    .map_err(|e| MeteostatError::CacheDirCreation(cache_folder.clone(), e))?;
+                Arc::new(FileSystemCache::new(cache_folder))
+            }
+        };
+
+        let station_data_url = station_data_url
+            .as_deref()
+            .unwrap_or(DEFAULT_STATION_DATA_URL);
+        let bulk_data_base_url = bulk_data_base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BULK_DATA_BASE_URL);
+        // One semaphore for all download paths, so the limit holds across them
+        let download_permits = Arc::new(Semaphore::new(
+            max_concurrent_downloads.unwrap_or(DEFAULT_MAX_CONCURRENT_DOWNLOADS),
+        ));
 
         // Initialize components
         Ok(Self {
-            station_locator: StationLocator::new(&cache_folder)
-                .await
-                .map_err(MeteostatError::from)?, // Converts LocateStationError
-            fetcher: FrameFetcher::new(&cache_folder),
-            cache_folder,
+            station_locator: match stations {
+                Some(stations) => StationLocator::from_stations(
+                    stations,
+                    station_data_url.to_string(),
+                    download_permits.clone(),
+                ),
+                None => StationLocator::new(&*cache, station_data_url, download_permits.clone())
+                    .await
+                    .map_err(MeteostatError::from)?, // Converts LocateStationError
+            }
+            .with_distance_metric(distance_metric.unwrap_or_default()),
+            fetcher: FrameFetcher::new(
+                cache.clone(),
+                bulk_data_base_url,
+                cache_compression.unwrap_or(DEFAULT_CACHE_COMPRESSION),
+                download_permits,
+            )
+            .with_cache_lock_timeout(cache_lock_timeout)
+            .with_csv_options(csv_options.unwrap_or_default()),
+            cache,
+            null_as_zero_columns: [
+                (treat_precip_null_as_zero, "prcp"),
+                (treat_snow_null_as_zero, "snow"),
+            ]
+            .into_iter()
+            .filter(|(enabled, _)| enabled.unwrap_or(false))
+            .map(|(_, column)| column)
+            .collect(),
+            tag_station_id: tag_station_id.unwrap_or(false),
+            default_radius_km: HashMap::new(),
         })
     }
 
     /// Creates a new `Meteostat` client using the default cache folder location.
     ///
-    /// The default location is platform-dependent (e.g., `~/.cache/meteostat-rs` on Linux).
+    /// The default location is the `METEOSTAT_CACHE_DIR` environment variable if it is set,
+    /// otherwise a platform-dependent folder (e.g., `~/.cache/meteostat_rs_cache` on Linux).
     /// Initializes the station locator and frame fetcher, ensuring the default
     /// cache directory exists.
     ///
@@ -178,8 +770,36 @@ impl Meteostat {
     /// # }
     /// ```
     pub async fn new() -> Result<Self, MeteostatError> {
-        let cache_folder = get_cache_dir().map_err(MeteostatError::CacheDirResolution)?;
-        Self::with_cache_folder(cache_folder).await
+        Self::builder().build().await
+    }
+
+    /// Returns the cache folder a client uses when none is given explicitly.
+    ///
+    /// This is the `METEOSTAT_CACHE_DIR` environment variable if it is set, otherwise a
+    /// platform-dependent folder (e.g., `~/.cache/meteostat_rs_cache` on Linux). The folder
+    /// is not created by this function.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the path of the default cache folder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::CacheDirResolution`] if the platform cache directory cannot
+    /// be determined and the environment variable is not set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meteostat::{Meteostat, MeteostatError};
+    ///
+    /// # fn main() -> Result<(), MeteostatError> {
+    /// println!("Cache folder: {}", Meteostat::default_cache_dir()?.display());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn default_cache_dir() -> Result<PathBuf, MeteostatError> {
+        get_cache_dir().map_err(MeteostatError::CacheDirResolution)
     }
 
     /// Prepares a request builder for fetching hourly weather data.
@@ -236,6 +856,53 @@ impl Meteostat {
         DailyClient::new(self)
     }
 
+    /// Fetches daily data for many locations concurrently, each from its nearest station
+    /// that has daily data (within the default 50 km).
+    ///
+    /// Points that resolve to the same station share a single download. This is useful for
+    /// sampling a grid of points, e.g. to build gridded climatologies from station data.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The locations to fetch data for.
+    ///
+    /// # Returns
+    ///
+    /// One result per point, in the same order as `points`. Each is the same as what
+    /// `client.daily().location(point).call()` would return.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{LatLon, Meteostat, MeteostatError, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let grid: Vec<LatLon> = (0..5)
+    ///     .flat_map(|i| (0..5).map(move |j| LatLon(51.0 + f64::from(i) * 0.5, 4.0 + f64::from(j) * 0.5)))
+    ///     .collect();
+    ///
+    /// for (point, result) in grid.iter().zip(client.daily_at_points(&grid).await) {
+    ///     match result {
+    ///         Ok(daily) => println!("{point:?}: {} days in 2023", daily.get_for_period(Year(2023))?.row_count()?),
+    ///         Err(e) => println!("{point:?}: {e}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn daily_at_points(
+        &self,
+        points: &[LatLon],
+    ) -> Vec<Result<DailyLazyFrame, MeteostatError>> {
+        join_all(
+            points
+                .iter()
+                .map(|&point| async move { self.daily().location(point).call().await }),
+        )
+        .await
+    }
+
     /// Prepares a request builder for fetching monthly weather data.
     ///
     /// Returns a [`MonthlyClient`] which allows specifying a station ID or location
@@ -298,8 +965,11 @@ impl Meteostat {
     ///
     /// * `.location(LatLon)`: **Required.** The geographical coordinate [`LatLon`] around which to search.
     /// * `.inventory_request(InventoryRequest)`: *Optional.* Filters stations based on reported data availability using an [`InventoryRequest`].
-    /// * `.max_distance_km(f64)`: *Optional.* The maximum search radius in kilometers. Defaults to `50.0`.
+    /// * `.max_distance_km(f64)`: *Optional.* The maximum search radius in kilometers. Defaults to the client's [`Meteostat::default_radius`] for the frequency of the `inventory_request`, or [`DEFAULT_MAX_DISTANCE_KM`] (50 km) without one.
     /// * `.station_limit(usize)`: *Optional.* The maximum number of stations to return, sorted by distance. Defaults to `5`.
+    /// * `.include_unverified(bool)`: *Optional.* When `true`, stations whose inventory for the requested frequency is missing (common for newly added stations) pass the `inventory_request` filter, so a fetch can still be attempted. Defaults to `false`, which excludes them.
+    /// * `.dedupe_within_km(f64)`: *Optional.* Collapses stations closer than this to each other (e.g. the old and new ID of a relocated station) into the one with the best reported inventory: the best coverage of the `inventory_request`, then the most years of data. Ties go to the closest station, so the result is deterministic. The `station_limit` applies after deduplication, and every station in range is measured, as with `exact`.
+    /// * `.exact(bool)`: *Optional.* When `true`, guarantees the true nearest stations under the configured [`DistanceMetric`] by measuring every station within `max_distance_km`. The default (`false`) visits stations in great-circle order and stops once `station_limit` qualifying stations are found. That is exact for the haversine metric, but with the Vincenty metric stations at nearly the same distance can come out in a slightly different order. Exact mode costs time proportional to the number of stations in range: negligible for the default 50 km radius, but noticeably slower for radii of hundreds of kilometers in dense networks, so prefer it for batch analysis over interactive use.
     ///
     /// # Returns
     ///
@@ -315,7 +985,7 @@ impl Meteostat {
     /// # Example
     ///
     /// ```no_run
-    /// use meteostat::{Meteostat, MeteostatError, LatLon, InventoryRequest, Frequency, RequiredData};
+    /// use meteostat::{Meteostat, MeteostatError, LatLon, InventoryRequest};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), MeteostatError> {
@@ -324,7 +994,7 @@ impl Meteostat {
     ///
     /// // Find the 3 closest stations within 100km of NYC
     /// // that have reported *any* Daily data.
-    /// let inventory_req = InventoryRequest::new(Frequency::Daily, RequiredData::Any);
+    /// let inventory_req = InventoryRequest::daily_any();
     ///
     /// let stations = client.find_stations()
     ///     .location(nyc)
@@ -347,24 +1017,47 @@ impl Meteostat {
         inventory_request: Option<InventoryRequest>,
         max_distance_km: Option<f64>,
         station_limit: Option<usize>,
+        include_unverified: Option<bool>,
+        exact: Option<bool>,
+        dedupe_within_km: Option<f64>,
     ) -> Vec<StationWithDistance> {
         // Note: The defaults below are applied *if* the corresponding builder method was not called.
-        let max_distance_km = max_distance_km.unwrap_or(50.0);
+        let max_distance_km = max_distance_km.unwrap_or_else(|| {
+            inventory_request.map_or(DEFAULT_MAX_DISTANCE_KM, |req| {
+                self.default_radius(req.frequency)
+            })
+        });
         let station_limit = station_limit.unwrap_or(5); // Default limit for find_stations
 
         let (freq_option, date_option) = inventory_request.map_or((None, None), |req| {
             (Some(req.frequency), Some(req.required_data))
         });
 
-        // Perform the query using the station locator
-        let stations_with_distance = self.station_locator.query(
+        // Perform the query using the station locator. Deduplication needs every station in
+        // range, since stations beyond the limit can replace collapsed ones.
+        let mut stations_with_distance = self.station_locator.query(
             location.0,
             location.1,
-            station_limit,
+            if dedupe_within_km.is_some() {
+                usize::MAX
+            } else {
+                station_limit
+            },
             max_distance_km,
             freq_option,
             date_option,
+            include_unverified.unwrap_or(false),
+            exact.unwrap_or(false) || dedupe_within_km.is_some(),
         );
+        if let Some(within_km) = dedupe_within_km {
+            stations_with_distance = self.station_locator.dedupe(
+                stations_with_distance,
+                within_km,
+                freq_option,
+                date_option.as_ref(),
+            );
+            stations_with_distance.truncate(station_limit);
+        }
 
         // Extract stations and discard distances
         stations_with_distance
@@ -377,58 +1070,553 @@ impl Meteostat {
             .collect()
     }
 
-    /// **Internal:** Fetches a lazy frame for a specific station and frequency.
+    /// Finds the single closest weather station to a location.
     ///
-    /// Handles cache lookup and potential downloads via `FrameFetcher`.
-    /// This method is intended for internal use by the frequency-specific clients
-    /// (`HourlyClient`, `DailyClient`, etc.).
+    /// Shorthand for [`Meteostat::find_stations`] with a station limit of 1, returning an
+    /// error instead of an empty result when no station matches. This is how the
+    /// `.location(..)` data requests pick their station as well.
     ///
-    /// # Arguments
+    /// # Arguments (Builder Methods)
     ///
-    /// * `station` - The ID of the weather station.
-    /// * `frequency` - The desired data [`Frequency`].
-    /// * `required_data` - (optional) Make sure cache isn't older than requested date.
+    /// * `.location(LatLon)`: **Required.** The geographical coordinate [`LatLon`] to search around.
+    /// * `.inventory_request(InventoryRequest)`: *Optional.* Only consider stations reporting the requested data, see [`InventoryRequest`].
+    /// * `.max_distance_km(f64)`: *Optional.* The maximum search radius in kilometers. Defaults to the client's [`Meteostat::default_radius`] for the frequency of the `inventory_request`, or [`DEFAULT_MAX_DISTANCE_KM`] (50 km) without one.
+    /// * `.include_unverified(bool)`: *Optional.* Let stations without inventory for the requested frequency pass the `inventory_request` filter. Defaults to `false`.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a Polars `LazyFrame` for the requested data,
-    /// or a `MeteostatError` if fetching fails.
+    /// A `Result` containing the closest station with its distance to `location`.
     ///
     /// # Errors
     ///
-    /// Can return [`MeteostatError::WeatherData`] if fetching/parsing the data fails
-    /// (e.g., network error, file not found, CSV parsing error).
+    /// Returns [`MeteostatError::NoStationWithinRadius`] if no station within
+    /// `max_distance_km` matches the criteria.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meteostat::{InventoryRequest, LatLon, Meteostat, MeteostatError};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let nearest = client
+    ///     .nearest_station()
+    ///     .location(LatLon(40.7128, -74.0060))
+    ///     .inventory_request(InventoryRequest::hourly_any())
+    ///     .call()?;
+    /// println!("{} is {:.1} km away", nearest.station.id, nearest.distance_km);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[builder]
-    pub(crate) async fn data_from_station(
+    pub fn nearest_station(
         &self,
-        station: &str,
-        frequency: Frequency,
-        required_data: Option<RequiredData>,
-    ) -> Result<LazyFrame, MeteostatError> {
-        self.fetcher
-            .get_cache_lazyframe(station, frequency, required_data.unwrap_or(Any))
-            .await
-            .map_err(MeteostatError::from) // Converts WeatherDataError
+        location: LatLon,
+        inventory_request: Option<InventoryRequest>,
+        max_distance_km: Option<f64>,
+        include_unverified: Option<bool>,
+    ) -> Result<StationWithDistance, MeteostatError> {
+        let max_distance_km = max_distance_km.unwrap_or_else(|| {
+            inventory_request.map_or(DEFAULT_MAX_DISTANCE_KM, |req| {
+                self.default_radius(req.frequency)
+            })
+        });
+        self.find_stations()
+            .location(location)
+            .maybe_inventory_request(inventory_request)
+            .max_distance_km(max_distance_km)
+            .station_limit(1)
+            .maybe_include_unverified(include_unverified)
+            .call()
+            .into_iter()
+            .next()
+            .ok_or(MeteostatError::NoStationWithinRadius {
+                radius: max_distance_km,
+                lat: location.0,
+                lon: location.1,
+            })
     }
 
-    /// **Internal:** Fetches a lazy frame for the nearest suitable station to a location.
+    /// Looks up the metadata of a single station by its Meteostat ID.
     ///
-    /// Finds nearby stations matching the criteria, then attempts to fetch data
-    /// from them sequentially (closest first) until successful.
-    /// This method is intended for internal use by the frequency-specific clients.
+    /// Unlike [`Meteostat::find_stations`], this does not require coordinates; the station
+    /// is looked up directly in the loaded station list.
     ///
     /// # Arguments
     ///
-    /// * `location` - The target [`LatLon`].
-    /// * `frequency` - The desired data [`Frequency`].
-    /// * `max_distance_km` - *Optional.* Max search radius. Defaults to `50.0`.
-    /// * `station_limit` - *Optional.* Max number of *candidate stations* to query. Defaults to `1`.
-    /// * `required_data` - *Optional.* Filter candidate stations by [`RequiredData`].
+    /// * `id` - The Meteostat station identifier (e.g., "10637").
     ///
     /// # Returns
     ///
-    /// A `Result` containing a Polars `LazyFrame` for the first successful station,
-    /// or a `MeteostatError` if no suitable station is found or data fetching fails for all candidates.
+    /// `Some(Station)` if a station with this ID exists in the station list, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// if let Some(station) = client.station("10637") {
+    ///     println!("Station 10637 is {:?}", station.name.get("en"));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn station(&self, id: &str) -> Option<Station> {
+        self.station_locator.get_station(id).cloned()
+    }
+
+    /// The number of stations in the loaded station list.
+    ///
+    /// Useful to confirm the full station list was loaded (on the order of ten thousand
+    /// stations for the official list), rather than a truncated one.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// println!("{} stations loaded", client.station_count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn station_count(&self) -> usize {
+        self.station_locator.station_count()
+    }
+
+    /// The south-west and north-east corners of the smallest box containing every station in
+    /// the loaded station list, or `None` if it is empty.
+    ///
+    /// Together with [`Meteostat::station_count`], this helps to tell whether spatial queries
+    /// behave oddly because of the station list rather than the query.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// if let Some((south_west, north_east)) = client.station_bounds() {
+    ///     println!("Stations span {south_west:?} to {north_east:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn station_bounds(&self) -> Option<(LatLon, LatLon)> {
+        self.station_locator
+            .station_bounds()
+            .map(|(lower, upper)| (LatLon(lower[0], lower[1]), LatLon(upper[0], upper[1])))
+    }
+
+    /// Looks up a station by its ICAO airport code (e.g. "EGLL" for London Heathrow).
+    ///
+    /// The lookup is case-insensitive. Only stations located at airports have an ICAO code.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The four-letter ICAO code.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Station)` if a station with this ICAO code exists, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// if let Some(station) = client.station_by_icao("EGLL") {
+    ///     println!("EGLL has Meteostat ID {}", station.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn station_by_icao(&self, code: &str) -> Option<Station> {
+        self.station_locator.get_station_by_icao(code).cloned()
+    }
+
+    /// Looks up a station by its World Meteorological Organization (WMO) identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The WMO station identifier (e.g., "03772").
+    ///
+    /// # Returns
+    ///
+    /// `Some(Station)` if a station with this WMO identifier exists, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// if let Some(station) = client.station_by_wmo("03772") {
+    ///     println!("WMO 03772 has Meteostat ID {}", station.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn station_by_wmo(&self, code: &str) -> Option<Station> {
+        self.station_locator.get_station_by_wmo(code).cloned()
+    }
+
+    /// Searches weather stations by name.
+    ///
+    /// Performs a case-insensitive match of `query` against every language variant in
+    /// [`Station::name`]. Exact and prefix matches rank above substring matches, which in turn
+    /// rank above fuzzy matches (all query characters appearing in order). Useful when you
+    /// know a place name but not its coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The (partial) station name to search for, e.g. "Heathrow".
+    /// * `limit` - The maximum number of stations to return.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Station>` sorted by match quality, best match first. Empty if nothing matches.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// for station in client.search_stations_by_name("Heathrow", 5) {
+    ///     println!("{}: {:?}", station.id, station.name.get("en"));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn search_stations_by_name(&self, query: &str, limit: usize) -> Vec<Station> {
+        self.station_locator.search_by_name(query, limit)
+    }
+
+    /// Converts stations into a GeoJSON `FeatureCollection` of `Point` features, ready to be
+    /// shown on a web map (e.g. with Leaflet or Mapbox).
+    ///
+    /// Each station becomes a feature as described in [`Station::to_geojson_feature`].
+    ///
+    /// # Arguments
+    ///
+    /// * `stations` - The stations to include, e.g. from [`Meteostat::find_stations`].
+    ///
+    /// # Returns
+    ///
+    /// The GeoJSON document as a string.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{LatLon, Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let stations: Vec<_> = client
+    ///     .find_stations()
+    ///     .location(LatLon(52.37, 4.90))
+    ///     .station_limit(10)
+    ///     .call()
+    ///     .into_iter()
+    ///     .map(|found| found.station)
+    ///     .collect();
+    ///
+    /// std::fs::write("stations.geojson", Meteostat::stations_to_geojson(&stations))
+    ///     .expect("Failed to write GeoJSON");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn stations_to_geojson(stations: &[Station]) -> String {
+        serde_json::json!({
+            "type": "FeatureCollection",
+            "features": stations.iter().map(Station::to_geojson_feature).collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+
+    /// Computes the distance between two stations in kilometers.
+    ///
+    /// Uses the same [`DistanceMetric`] as [`Meteostat::find_stations`], so the results are
+    /// consistent with the distances it reports.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The Meteostat ID of the first station.
+    /// * `b` - The Meteostat ID of the second station.
+    ///
+    /// # Returns
+    ///
+    /// The distance in kilometers, or `None` if either station ID is not in the station list.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// // De Bilt to Schiphol
+    /// if let Some(km) = client.distance_between("06260", "06240") {
+    ///     println!("The stations are {km:.1} km apart");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn distance_between(&self, a: &str, b: &str) -> Option<f64> {
+        self.station_locator.distance_between(a, b)
+    }
+
+    /// Computes the distance from a station to a point in kilometers.
+    ///
+    /// Uses the same [`DistanceMetric`] as [`Meteostat::find_stations`].
+    ///
+    /// # Arguments
+    ///
+    /// * `station` - The Meteostat ID of the station.
+    /// * `point` - The [`LatLon`] to measure the distance to.
+    ///
+    /// # Returns
+    ///
+    /// The distance in kilometers, or `None` if the station ID is not in the station list.
+    #[must_use]
+    pub fn distance_to(&self, station: &str, point: LatLon) -> Option<f64> {
+        self.station_locator
+            .distance_to(station, point.lat(), point.lon())
+    }
+
+    /// **Internal:** Fetches a lazy frame for a specific station and frequency.
+    ///
+    /// Handles cache lookup and potential downloads via `FrameFetcher`.
+    /// This method is intended for internal use by the frequency-specific clients
+    /// (`HourlyClient`, `DailyClient`, etc.).
+    ///
+    /// # Arguments
+    ///
+    /// * `station` - The ID of the weather station.
+    /// * `frequency` - The desired data [`Frequency`].
+    /// * `required_data` - (optional) Make sure cache isn't older than requested date.
+    /// * `skip_validation` - (optional) Skip checking the station ID against the station list.
+    /// * `cancellation_token` - (optional) Aborts the download/parse when cancelled.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a Polars `LazyFrame` for the requested data,
+    /// or a `MeteostatError` if fetching fails.
+    ///
+    /// # Errors
+    ///
+    /// Can return [`MeteostatError::UnknownStation`] if the station ID is not in the loaded
+    /// station list and validation isn't skipped.
+    /// Can return [`MeteostatError::Cancelled`] if the token is cancelled before the data is ready.
+    /// Can return [`MeteostatError::WeatherData`] if fetching/parsing the data fails
+    /// (e.g., network error, file not found, CSV parsing error).
+    #[builder]
+    pub(crate) async fn data_from_station(
+        &self,
+        station: &str,
+        frequency: Frequency,
+        required_data: Option<RequiredData>,
+        skip_validation: Option<bool>,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<LazyFrame, MeteostatError> {
+        // Avoid a pointless download for IDs that aren't in the station list
+        if !skip_validation.unwrap_or(false) && self.station_locator.get_station(station).is_none()
+        {
+            return Err(MeteostatError::UnknownStation {
+                id: station.to_string(),
+            });
+        }
+
+        self.fetch_frame(
+            station,
+            frequency,
+            required_data,
+            cancellation_token.as_ref(),
+        )
+        .await
+    }
+
+    /// **Internal:** Gets the lazy frame from the fetcher, racing it against the token if given.
+    ///
+    /// Dropping the fetch mid-download is safe: cache files are written to a temporary file
+    /// and only moved into place once complete.
+    async fn fetch_frame(
+        &self,
+        station: &str,
+        frequency: Frequency,
+        required_data: Option<RequiredData>,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> Result<LazyFrame, MeteostatError> {
+        let fetch =
+            self.fetcher
+                .get_cache_lazyframe(station, frequency, required_data.unwrap_or(Any));
+        let result = match cancellation_token {
+            Some(token) => token
+                .run_until_cancelled(fetch)
+                .await
+                .ok_or(MeteostatError::Cancelled)?,
+            None => fetch.await,
+        };
+        let frame = result.map_err(MeteostatError::from)?; // Converts WeatherDataError
+        let frame = fill_null_as_zero(frame, frequency, &self.null_as_zero_columns);
+        Ok(if self.tag_station_id {
+            frame.with_column(lit(station).alias(STATION_ID_COLUMN))
+        } else {
+            frame
+        })
+    }
+
+    /// **Internal:** Fetches a station's data, falling back to the nearest other station to
+    /// a location if the station has none in the requested range.
+    ///
+    /// The preferred station is fetched as in `data_from_station` and narrowed down with
+    /// `in_range`. If its data doesn't exist or can't be downloaded, or leaves no rows, the
+    /// nearest suitable station to `fallback_location` (other than the preferred one) is
+    /// used instead, as in `data_from_location`. Without a `fallback_location`, this is
+    /// `data_from_station` followed by `in_range`.
+    ///
+    /// # Arguments
+    ///
+    /// * `station` - The ID of the preferred weather station.
+    /// * `frequency` - The desired data [`Frequency`].
+    /// * `in_range` - Narrows a fetched frame down to the requested range, e.g. a period filter.
+    /// * `fallback_location` - *Optional.* Where to search for another station.
+    /// * `required_data` - *Optional.* Inventory filter, applied to both the preferred station
+    ///   and the fallback candidates.
+    /// * `skip_validation` - *Optional.* Skip checking the preferred station ID against the
+    ///   station list.
+    /// * `cancellation_token` - *Optional.* Aborts the download/parse when cancelled.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the frame of the preferred station, or of the fallback station
+    /// if the preferred one had no data in range.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `data_from_station` if there is no `fallback_location`, or if
+    /// the preferred station failed for another reason than missing data or a failed
+    /// download, such as [`MeteostatError::UnknownStation`] or a broken cache file. Errors
+    /// from `in_range` (e.g. [`MeteostatError::DateParsingError`]) and
+    /// [`MeteostatError::Cancelled`] are returned right away as well. If the fallback search
+    /// fails, its error is returned, wrapped in [`MeteostatError::FallbackFailed`] with the
+    /// preferred station's error as the source if that station failed too.
+    #[builder]
+    pub(crate) async fn data_from_station_or_location<F>(
+        &self,
+        station: &str,
+        frequency: Frequency,
+        in_range: F,
+        fallback_location: Option<LatLon>,
+        required_data: Option<RequiredData>,
+        skip_validation: Option<bool>,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<LazyFrame, MeteostatError>
+    where
+        F: Fn(LazyFrame) -> Result<LazyFrame, MeteostatError>,
+    {
+        let preferred = self
+            .data_from_station()
+            .station(station)
+            .frequency(frequency)
+            .maybe_required_data(required_data)
+            .maybe_skip_validation(skip_validation)
+            .maybe_cancellation_token(cancellation_token.clone())
+            .call()
+            .await;
+        let Some(fallback_location) = fallback_location else {
+            return in_range(preferred?);
+        };
+
+        let preferred_error = match preferred {
+            Ok(frame) => {
+                let frame = in_range(frame)?;
+                let rows = frame.clone().select([len()]).collect()?;
+                if rows.column("len")?.idx()?.get(0).unwrap_or(0) > 0 {
+                    return Ok(frame);
+                }
+                None
+            }
+            // No data file, or the download failed: another station may do
+            Err(MeteostatError::WeatherData(e)) if e.is_not_found() || e.is_download_failure() => {
+                Some(MeteostatError::WeatherData(e))
+            }
+            Err(e) => return Err(e),
+        };
+
+        let fallback = self
+            .data_from_location()
+            .location(fallback_location)
+            .frequency(frequency)
+            .maybe_required_data(required_data)
+            .maybe_cancellation_token(cancellation_token)
+            .exclude_station(station)
+            .call()
+            .await;
+        match (fallback, preferred_error) {
+            (Ok(frame), _) => in_range(frame.into_inner()),
+            (Err(MeteostatError::Cancelled), _) => Err(MeteostatError::Cancelled),
+            (Err(fallback), Some(preferred)) => Err(MeteostatError::FallbackFailed {
+                station: station.to_string(),
+                preferred: Box::new(preferred),
+                fallback: Box::new(fallback),
+            }),
+            (Err(fallback), None) => Err(fallback),
+        }
+    }
+
+    /// **Internal:** Fetches a lazy frame for the nearest suitable station to a location.
+    ///
+    /// Finds nearby stations matching the criteria, then attempts to fetch data
+    /// from them sequentially (closest first) until successful.
+    /// This method is intended for internal use by the frequency-specific clients.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - The target [`LatLon`].
+    /// * `frequency` - The desired data [`Frequency`].
+    /// * `max_distance_km` - *Optional.* Max search radius. Defaults to the client's
+    ///   [`Meteostat::default_radius`] for `frequency`.
+    /// * `station_limit` - *Optional.* Max number of *candidate stations* to query. Defaults to `1`.
+    /// * `required_data` - *Optional.* Filter candidate stations by [`RequiredData`].
+    /// * `cancellation_token` - *Optional.* Aborts the download/parse when cancelled.
+    /// * `prefer_recent_normals` - *Optional.* Tries candidates with the latest climate normals
+    ///   period first, then by distance. Defaults to `false`.
+    /// * `exclude_station` - *Optional.* A station ID that is never tried, e.g. because it
+    ///   already failed.
+    /// * `check_coverage` - *Optional.* A period the successful station's inventory is
+    ///   checked against, adding a [`FetchWarning::PeriodNotCovered`] if it isn't fully
+    ///   covered.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a Polars `LazyFrame` for the first successful station, with a
+    /// [`FetchWarning::StationFailed`] for each candidate that failed before it, or a
+    /// `MeteostatError` if no suitable station is found or data fetching fails for all candidates.
     ///
     /// # Errors
     ///
@@ -437,6 +1625,7 @@ impl Meteostat {
     /// - [`MeteostatError::NoDataFoundForNearbyStations`]: If candidate stations were found, but fetching data failed for all of them. Includes the last encountered `WeatherData` error.
     /// - [`MeteostatError::LocateStation`]: If the station query itself fails.
     /// - [`MeteostatError::WeatherData`]: Encapsulated within `NoDataFoundForNearbyStations` if fetching fails for a candidate.
+    /// - [`MeteostatError::Cancelled`]: If the token is cancelled; remaining candidates are not tried.
     #[builder]
     pub(crate) async fn data_from_location(
         &self,
@@ -445,21 +1634,32 @@ impl Meteostat {
         max_distance_km: Option<f64>,
         station_limit: Option<usize>,
         required_data: Option<RequiredData>,
-    ) -> Result<LazyFrame, MeteostatError> {
+        cancellation_token: Option<CancellationToken>,
+        prefer_recent_normals: Option<bool>,
+        exclude_station: Option<&str>,
+        check_coverage: Option<RequiredData>,
+    ) -> Result<WithWarnings<LazyFrame>, MeteostatError> {
         // Note: Defaults applied here if builder methods not called.
-        let max_distance_km = max_distance_km.unwrap_or(50.0);
+        let max_distance_km = max_distance_km.unwrap_or_else(|| self.default_radius(frequency));
         // Default limit for *candidate stations to try* in from_location is 1.
         let station_limit = station_limit.unwrap_or(1);
 
         // Query for candidate stations
-        let stations = self.station_locator.query(
+        let mut stations = self.station_locator.query(
             location.0,
             location.1,
-            station_limit, // Limit the number of candidates fetched
+            // Limit the number of candidates fetched, plus one in case it is excluded
+            station_limit + usize::from(exclude_station.is_some()),
             max_distance_km,
             Some(frequency), // Always filter by frequency for from_location
             required_data,   // Apply optional date/inventory filter
+            false,           // Stations must report the frequency in their inventory
+            false,           // Nearest first is exact for the default haversine metric
         );
+        if let Some(excluded) = exclude_station {
+            stations.retain(|(station, _)| station.id != excluded);
+            stations.truncate(station_limit);
+        }
 
         // Handle case where no stations are found matching the criteria
         if stations.is_empty() {
@@ -470,28 +1670,57 @@ impl Meteostat {
             });
         }
 
-        let mut last_error: Option<MeteostatError> = None;
+        if prefer_recent_normals.unwrap_or(false) {
+            // Stable sort, so stations with the same normals period stay in distance order
+            stations.sort_by_key(|(station, _)| Reverse(station.inventory.normals.end));
+        }
+
+        let mut warnings = Vec::new();
 
         // Iterate through the found stations (sorted by distance) and try to fetch data
         for (station, _) in &stations {
             match self
-                .fetcher
-                .get_cache_lazyframe(&station.id, frequency, required_data.unwrap_or(Any))
+                .fetch_frame(
+                    &station.id,
+                    frequency,
+                    required_data,
+                    cancellation_token.as_ref(),
+                )
                 .await
             {
                 Ok(lazy_frame) => {
                     // Successfully fetched data, return it immediately
-                    return Ok(lazy_frame);
+                    if let Some(period) = check_coverage {
+                        let coverage = station.inventory.coverage(frequency, &period);
+                        if coverage != Coverage::Full {
+                            warnings.push(FetchWarning::PeriodNotCovered {
+                                station: station.id.clone(),
+                                coverage,
+                            });
+                        }
+                    }
+                    return Ok(WithWarnings::new(lazy_frame, warnings));
+                }
+                Err(MeteostatError::Cancelled) => {
+                    // Cancellation applies to the whole search, not just this candidate
+                    return Err(MeteostatError::Cancelled);
                 }
-                Err(e) => {
-                    // Convert specific WeatherDataError to the general MeteostatError
-                    let current_error = MeteostatError::from(e);
-                    last_error = Some(current_error);
+                Err(error) => {
+                    warnings.push(FetchWarning::StationFailed {
+                        station: station.id.clone(),
+                        error,
+                    });
                     // Continue to the next station
                 }
             }
         }
 
+        // Every candidate failed, so the warnings are all failures; report the last one
+        let last_error = match warnings.pop() {
+            Some(FetchWarning::StationFailed { error, .. }) => Some(error),
+            _ => None,
+        };
+
         // If the loop finishes without returning, it means all attempts failed.
         Err(MeteostatError::NoDataFoundForNearbyStations {
             radius: max_distance_km,
@@ -502,7 +1731,277 @@ impl Meteostat {
         })
     }
 
-    /// Clears the cached station list file (`stations_lite.bin`).
+    /// Reports the crate version, data-source URLs and cache format version of this client.
+    ///
+    /// The URLs are the ones the client was built with, so they reflect any overrides
+    /// passed to [`Meteostat::builder`].
+    ///
+    /// # Returns
+    ///
+    /// A [`VersionInfo`] describing this client.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// // e.g. "meteostat 0.4.7 (stations: https://bulk.meteostat.net/v2/stations/lite.json.gz, ...)"
+    /// println!("{}", client.version_info());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn version_info(&self) -> VersionInfo {
+        VersionInfo {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            station_data_url: self.station_locator.data_url().to_string(),
+            bulk_data_base_url: self.fetcher.base_url().to_string(),
+            station_cache_format_version: STATION_CACHE_FORMAT_VERSION,
+        }
+    }
+
+    /// Performs a quick health check of the client, e.g. for service readiness probes.
+    ///
+    /// Checks whether the bulk server is reachable (with a `HEAD` request to the station
+    /// list, timing out after a few seconds), whether the station list cache is present
+    /// and valid, and whether the cache directory is writable. No weather data is downloaded.
+    ///
+    /// # Returns
+    ///
+    /// A [`HealthReport`] with the outcome of each check.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let report = client.health_check().await;
+    /// if !report.is_healthy() {
+    ///     eprintln!("Meteostat client not ready: {report:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn health_check(&self) -> HealthReport {
+        let (server_reachable, station_cache_valid, cache_dir_writable) = tokio::join!(
+            self.station_locator
+                .is_server_reachable(HEALTH_CHECK_TIMEOUT),
+            StationLocator::is_cache_valid(&*self.cache),
+            async {
+                self.cache
+                    .write(HEALTH_CHECK_CACHE_KEY, Vec::new())
+                    .await
+                    .is_ok()
+                    && self.cache.delete(HEALTH_CHECK_CACHE_KEY).await.is_ok()
+            },
+        );
+
+        HealthReport {
+            server_reachable,
+            station_cache_valid,
+            stations_loaded: self.station_locator.station_count(),
+            cache_dir_writable,
+        }
+    }
+
+    /// Checks which cached stations have newer data upstream, without downloading any data.
+    ///
+    /// For each station, the modification time of its cache file is compared with the
+    /// `Last-Modified` time the server reports for the remote file (from a `HEAD` request).
+    /// The requests run concurrently, up to the client's download limit. A maintenance job can
+    /// use the result to refresh only the stations that changed, e.g. with
+    /// [`Meteostat::clear_weather_data_cache_per_station`] followed by a new request.
+    ///
+    /// # Arguments
+    ///
+    /// * `stations` - The station IDs to check.
+    /// * `frequency` - The data [`Frequency`] whose cache files to check.
+    ///
+    /// # Returns
+    ///
+    /// An [`UpdateStatus`] per station, in the order of `stations`. Failed requests are
+    /// reported as an unknown remote modification time rather than an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Frequency, Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// for status in client.check_updates(&["06240", "10637"], Frequency::Daily).await {
+    ///     if status.is_stale() == Some(true) {
+    ///         client
+    ///             .clear_weather_data_cache_per_station(&status.station, Frequency::Daily)
+    ///             .await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_updates(
+        &self,
+        stations: &[&str],
+        frequency: Frequency,
+    ) -> Vec<UpdateStatus> {
+        join_all(
+            stations
+                .iter()
+                .map(|station| self.fetcher.update_status(station, frequency)),
+        )
+        .await
+    }
+
+    /// Checks how well the reported inventories of stations cover a period, without
+    /// downloading any weather data.
+    ///
+    /// Only the station list already in memory is consulted, so this is a cheap way to plan a
+    /// multi-station analysis before fetching. Like [`InventoryRequest`], the inventory is
+    /// station metadata: a fully covered period can still have gaps in the actual data.
+    ///
+    /// # Arguments
+    ///
+    /// * `stations` - The station IDs to check.
+    /// * `frequency` - The data [`Frequency`] whose inventory to check.
+    /// * `period` - The period to cover, e.g. [`RequiredData::DateRange`] or
+    ///   [`RequiredData::FullYear`]. See [`crate::StationInventory::coverage`] for how it is
+    ///   compared.
+    ///
+    /// # Returns
+    ///
+    /// An [`AvailabilityResult`] per station, in the order of `stations`. Unknown station IDs
+    /// are reported with [`Coverage::None`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Coverage, Frequency, Meteostat, MeteostatError, RequiredData};
+    /// # use chrono::NaiveDate;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let period = RequiredData::DateRange {
+    ///     start: NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+    ///     end: NaiveDate::from_ymd_opt(2020, 12, 31).unwrap(),
+    /// };
+    /// for result in client.check_availability(&["06240", "10637"], Frequency::Daily, period) {
+    ///     if result.coverage != Coverage::Full {
+    ///         println!("{} only reports {:?}", result.station, result.reported_years);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn check_availability(
+        &self,
+        stations: &[&str],
+        frequency: Frequency,
+        period: RequiredData,
+    ) -> Vec<AvailabilityResult> {
+        stations
+            .iter()
+            .map(|&id| {
+                let inventory = self
+                    .station_locator
+                    .get_station(id)
+                    .map(|station| &station.inventory);
+                AvailabilityResult {
+                    station: id.to_string(),
+                    coverage: inventory.map_or(Coverage::None, |inventory| {
+                        inventory.coverage(frequency, &period)
+                    }),
+                    reported_years: inventory.and_then(|inventory| inventory.years(frequency)),
+                }
+            })
+            .collect()
+    }
+
+    /// Exports all available data of a station to a single file.
+    ///
+    /// Only available with the `export` feature (or the `xlsx` feature, which enables it).
+    ///
+    /// Fetches the hourly, daily, monthly and climate normals data of the station concurrently
+    /// and writes each frequency as a separate entry of one file: a CSV file in a zip archive,
+    /// or a sheet in an Excel workbook (with the `xlsx` feature). Frequencies the station has no
+    /// data for are left out. Client options such as [`Meteostat::builder`]'s
+    /// `tag_station_id` apply to the exported data as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The Meteostat station ID (e.g., `"06240"`).
+    /// * `path` - The file to write. An existing file is overwritten.
+    /// * `format` - The [`ExportFormat`] of the file.
+    ///
+    /// # Returns
+    ///
+    /// The frequencies that were written to the file.
+    ///
+    /// # Errors
+    ///
+    /// - [`MeteostatError::NothingToExport`]: If no data is available for any frequency.
+    /// - [`MeteostatError::WeatherData`]: If fetching a frequency fails for another reason
+    ///   than missing data.
+    /// - [`MeteostatError::ExportIo`], [`MeteostatError::ExportArchive`] or
+    ///   [`MeteostatError::PolarsError`]: If writing the file fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{ExportFormat, Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let exported = client
+    ///     .export_station("06240", "schiphol.zip", ExportFormat::ZipCsv)
+    ///     .await?;
+    /// println!("Exported {exported:?} data.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "export")]
+    pub async fn export_station(
+        &self,
+        id: &str,
+        path: impl AsRef<std::path::Path>,
+        format: crate::ExportFormat,
+    ) -> Result<Vec<Frequency>, MeteostatError> {
+        let results =
+            join_all(Frequency::ALL.map(|frequency| self.fetch_frame(id, frequency, None, None)))
+                .await;
+        let mut frames = Vec::new();
+        for (frequency, result) in Frequency::ALL.into_iter().zip(results) {
+            match result {
+                Ok(frame) => frames.push((frequency, frame.collect()?)),
+                // Meteostat has no file for frequencies the station doesn't record
+                Err(MeteostatError::WeatherData(e)) if e.is_not_found() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if frames.is_empty() {
+            return Err(MeteostatError::NothingToExport { id: id.to_string() });
+        }
+
+        let exported = frames.iter().map(|(frequency, _)| *frequency).collect();
+        let path = path.as_ref().to_path_buf();
+        let task_path = path.clone();
+        tokio::task::spawn_blocking(move || write_export(&mut frames, &task_path, format))
+            .await
+            .map_err(|e| MeteostatError::ExportIo(path, std::io::Error::other(e)))??;
+        Ok(exported)
+    }
+
+    /// Clears the cached station list file (`stations_lite.rkyv`) and its stored
+    /// `ETag`/`Last-Modified` validators.
     ///
     /// This removes the locally stored station metadata. This function doesn't
     /// clear the in-memory tree of stations. To clear that, use [`Meteostat::rebuild_station_list_cache`].
@@ -531,19 +2030,22 @@ impl Meteostat {
     /// # }
     /// ```
     pub async fn clear_station_list_cache(&self) -> Result<(), MeteostatError> {
-        let stations_file = self.cache_folder.join(RKYV_CACHE_FILE_NAME);
-        match tokio::fs::remove_file(&stations_file).await {
-            Ok(()) => Ok(()),
-            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()), // Not an error if already gone
-            Err(e) => Err(MeteostatError::CacheDeletionError(stations_file.clone(), e)),
+        for file_name in [RKYV_CACHE_FILE_NAME, VALIDATORS_FILE_NAME] {
+            self.cache.delete(file_name).await.map_err(|e| {
+                MeteostatError::CacheDeletionError(entry_path(&*self.cache, file_name), e)
+            })?;
         }
+        Ok(())
     }
 
     /// Forces a rebuild of the station list cache.
     ///
-    /// This method will delete the existing station cache file (if any)
-    /// and then immediately download and process the latest station metadata
-    /// from Meteostat, storing it in the cache.
+    /// This method downloads and processes the latest station metadata from Meteostat,
+    /// replacing the existing station cache file (if any).
+    ///
+    /// If the server sent an `ETag` or `Last-Modified` header for the cached list, the
+    /// request is conditional: when the list hasn't changed (`304 Not Modified`), the
+    /// existing cache is kept and nothing is re-downloaded.
     ///
     /// Note: This requires mutable access (`&mut self`) because it modifies the
     /// internal `StationLocator` state.
@@ -576,7 +2078,7 @@ impl Meteostat {
     pub async fn rebuild_station_list_cache(&mut self) -> Result<(), MeteostatError> {
         // Delegate the actual rebuilding (which includes clearing) to the locator
         self.station_locator
-            .rebuild_cache(&self.cache_folder)
+            .rebuild_cache(&*self.cache)
             .await
             .map_err(MeteostatError::from) // Convert LocateStationError
     }
@@ -631,6 +2133,59 @@ impl Meteostat {
             .map_err(MeteostatError::from) // Convert WeatherDataError
     }
 
+    /// Re-downloads the weather data of a station and frequency, replacing its cache.
+    ///
+    /// This is [`Meteostat::clear_weather_data_cache_per_station`] followed by an immediate
+    /// download, rather than one on the next request. Use it to update the data of specific
+    /// stations ahead of time, e.g. from a scheduled task, the way
+    /// [`Meteostat::rebuild_station_list_cache`] updates the station list.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The Meteostat ID of the station.
+    /// * `frequency` - The [`Frequency`] of the data to refresh.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a Polars `LazyFrame` of the freshly downloaded data. Wrap it in
+    /// the wrapper of the frequency to use its methods, e.g. `DailyLazyFrame { frame }`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::UnknownStation`] if the station ID is not in the station
+    /// list, [`MeteostatError::WeatherData`] if clearing the cache or downloading and parsing
+    /// the data fails. The old cache entry is already gone when the download fails, so the
+    /// next request retries it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{DailyLazyFrame, Frequency, Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// for station in ["06240", "10637"] {
+    ///     let frame = client.refresh_station(station, Frequency::Daily).await?;
+    ///     let daily = DailyLazyFrame { frame };
+    ///     println!("{station}: {} days", daily.row_count()?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn refresh_station(
+        &self,
+        id: &str,
+        frequency: Frequency,
+    ) -> Result<LazyFrame, MeteostatError> {
+        if self.station_locator.get_station(id).is_none() {
+            return Err(MeteostatError::UnknownStation { id: id.to_string() });
+        }
+        self.clear_weather_data_cache_per_station(id, frequency)
+            .await?;
+        self.fetch_frame(id, frequency, None, None).await
+    }
+
     /// Clears all cached weather data files (`.parquet` files).
     ///
     /// Iterates through the cache directory and removes all files ending with the
@@ -671,6 +2226,51 @@ impl Meteostat {
         Ok(())
     }
 
+    /// Removes cached weather data files that weren't written for longer than `age`, e.g.
+    /// to delete anything not refreshed in 90 days.
+    ///
+    /// Only weather data (`.parquet` entries) is considered; the station list cache is never
+    /// removed. Removed entries are also dropped from the in-memory cache, so they are
+    /// downloaded again when requested.
+    ///
+    /// # Returns
+    ///
+    /// A [`PurgeReport`] with the number of removed entries and the bytes freed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::WeatherData`] if the cache entries can't be listed,
+    /// inspected or removed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let report = client
+    ///     .purge_cache_older_than(Duration::from_secs(90 * 24 * 60 * 60))
+    ///     .await?;
+    /// println!("Removed {} files, freed {} bytes", report.files_removed, report.bytes_freed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn purge_cache_older_than(
+        &self,
+        age: Duration,
+    ) -> Result<PurgeReport, MeteostatError> {
+        let cutoff = chrono::TimeDelta::from_std(age)
+            .ok()
+            .and_then(|age| Utc::now().checked_sub_signed(age));
+        // An age beyond the representable dates means nothing is old enough
+        let Some(cutoff) = cutoff else {
+            return Ok(PurgeReport::default());
+        };
+        Ok(self.fetcher.purge_older_than(cutoff).await?)
+    }
+
     /// Clears the entire cache directory.
     ///
     /// This removes both the cached station list (`stations_lite.bin`) and all
@@ -755,6 +2355,8 @@ impl Meteostat {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{LocateStationError, Year};
+    use chrono::TimeZone;
     use std::ffi::OsStr;
     use std::fs;
     use std::path::Path;
@@ -765,6 +2367,674 @@ mod tests {
         LatLon(52.520_008, 13.404_954)
     }
 
+    // A pinned station list with only Frankfurt Airport
+    fn frankfurt_station_json() -> &'static str {
+        r#"[{
+        "id": "10637",
+        "country": "DE",
+        "region": null,
+        "timezone": null,
+        "name": {"en": "Frankfurt Airport"},
+        "identifiers": {"national": null, "wmo": "10637", "icao": "EDDF"},
+        "location": {"latitude": 50.05, "longitude": 8.6, "elevation": 111},
+        "inventory": {
+            "daily": {"start": null, "end": null},
+            "hourly": {"start": null, "end": null},
+            "model": {"start": null, "end": null},
+            "monthly": {"start": null, "end": null},
+            "normals": {"start": null, "end": null}
+        }
+    }]"#
+    }
+
+    #[tokio::test]
+    async fn test_nearest_station_errors_when_none_in_range() -> Result<(), MeteostatError> {
+        let cache_dir = tempdir()?;
+        let client = Meteostat::with_stations(
+            Station::from_json(frankfurt_station_json())?,
+            cache_dir.path().to_path_buf(),
+        )
+        .await?;
+
+        let nearest = client
+            .nearest_station()
+            .location(LatLon(50.11, 8.68))
+            .call()?;
+        assert_eq!(nearest.station.id, "10637");
+        assert!(nearest.distance_km < 10.0);
+
+        let far_away = client.nearest_station().location(berlin_location()).call();
+        assert!(matches!(
+            far_away,
+            Err(MeteostatError::NoStationWithinRadius { radius, .. }) if radius == 50.0
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_station_count_and_bounds() -> Result<(), MeteostatError> {
+        let memory = || Arc::new(crate::MemoryCache::new());
+        let client = Meteostat::builder()
+            .cache_backend(memory())
+            .stations(Station::from_json(frankfurt_station_json())?)
+            .build()
+            .await?;
+        assert_eq!(client.station_count(), 1);
+        assert_eq!(
+            client.station_bounds(),
+            Some((LatLon(50.05, 8.6), LatLon(50.05, 8.6)))
+        );
+
+        let empty = Meteostat::builder()
+            .cache_backend(memory())
+            .stations(Vec::new())
+            .build()
+            .await?;
+        assert_eq!(empty.station_count(), 0);
+        assert_eq!(empty.station_bounds(), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_default_radius_per_frequency() -> Result<(), MeteostatError> {
+        let cache_dir = tempdir()?;
+        let client = Meteostat::with_stations(
+            Station::from_json(frankfurt_station_json())?,
+            cache_dir.path().to_path_buf(),
+        )
+        .await?
+        .with_default_radius(Frequency::Hourly, 500.0);
+        assert_eq!(client.default_radius(Frequency::Hourly), 500.0);
+        assert_eq!(
+            client.default_radius(Frequency::Daily),
+            DEFAULT_MAX_DISTANCE_KM
+        );
+
+        // Frankfurt is about 420 km from Berlin: only in range of the hourly radius
+        let search = |request: InventoryRequest| {
+            client
+                .nearest_station()
+                .location(berlin_location())
+                .inventory_request(request)
+                .include_unverified(true)
+                .call()
+        };
+        assert_eq!(search(InventoryRequest::hourly_any())?.station.id, "10637");
+        assert!(search(InventoryRequest::daily_any()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_inventory_request_shorthands() {
+        let any = |frequency| InventoryRequest::new(frequency, RequiredData::Any);
+        let year = |frequency| InventoryRequest::new(frequency, RequiredData::FullYear(2023));
+        assert_eq!(InventoryRequest::hourly_any(), any(Frequency::Hourly));
+        assert_eq!(InventoryRequest::daily_any(), any(Frequency::Daily));
+        assert_eq!(InventoryRequest::monthly_any(), any(Frequency::Monthly));
+        assert_eq!(InventoryRequest::climate_any(), any(Frequency::Climate));
+        assert_eq!(InventoryRequest::hourly_year(2023), year(Frequency::Hourly));
+        assert_eq!(InventoryRequest::daily_year(2023), year(Frequency::Daily));
+        assert_eq!(
+            InventoryRequest::monthly_year(2023),
+            year(Frequency::Monthly)
+        );
+    }
+
+    /// Writes daily CSV rows as the cache file of `station`, so it is read without downloading.
+    fn seed_daily_cache(cache_dir: &Path, station: &str, csv: &str) -> Result<(), MeteostatError> {
+        let mut df = crate::parse_daily_csv(csv.as_bytes())?.frame.collect()?;
+        let file = fs::File::create(cache_dir.join(format!("daily-{station}.parquet")))?;
+        polars::prelude::ParquetWriter::new(file).finish(&mut df)?;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_station_with_fallback_location() -> Result<(), MeteostatError> {
+        let cache_dir = tempdir()?;
+        let mut stations = Station::from_json(frankfurt_station_json())?;
+        stations.extend(Station::from_json(
+            r#"[{
+            "id": "10641",
+            "country": "DE",
+            "region": null,
+            "timezone": null,
+            "name": {"en": "Offenbach-Wetterpark"},
+            "identifiers": {"national": null, "wmo": "10641", "icao": null},
+            "location": {"latitude": 50.09, "longitude": 8.78, "elevation": 119},
+            "inventory": {
+                "daily": {"start": "2023-01-01", "end": "2023-12-31"},
+                "hourly": {"start": null, "end": null},
+                "model": {"start": null, "end": null},
+                "monthly": {"start": null, "end": null},
+                "normals": {"start": null, "end": null}
+            }
+        }]"#,
+        )?);
+        // Frankfurt only has 2022 data, Offenbach only 2023
+        seed_daily_cache(cache_dir.path(), "10637", "2022-06-01,20.0,,,,,,,,,\n")?;
+        seed_daily_cache(cache_dir.path(), "10641", "2023-06-01,21.0,,,,,,,,,\n")?;
+        let client = Meteostat::builder()
+            .cache_folder(cache_dir.path().to_path_buf())
+            // Unreachable, so downloads of uncached stations fail
+            .bulk_data_base_url("http://127.0.0.1:9")
+            .stations(stations)
+            .build()
+            .await?;
+        let frankfurt = LatLon(50.05, 8.6);
+
+        let preferred = client
+            .daily()
+            .station("10637")
+            .for_period(Year(2022))
+            .fallback_location(frankfurt)
+            .call()
+            .await?
+            .collect_single_daily()?;
+        assert_eq!(preferred.average_temperature, Some(20.0));
+
+        let fallback = client
+            .daily()
+            .station("10637")
+            .for_period(Year(2023))
+            .fallback_location(frankfurt)
+            .call()
+            .await?
+            .collect_single_daily()?;
+        assert_eq!(fallback.average_temperature, Some(21.0));
+
+        let without_fallback = client
+            .daily()
+            .station("10637")
+            .for_period(Year(2023))
+            .call()
+            .await?;
+        assert_eq!(without_fallback.row_count()?, 0);
+
+        // An unknown ID is a mistake rather than missing data, so it doesn't fall back
+        let unknown = client
+            .daily()
+            .station("00000")
+            .fallback_location(frankfurt)
+            .call()
+            .await;
+        assert!(matches!(
+            unknown,
+            Err(MeteostatError::UnknownStation { .. })
+        ));
+
+        // Unless validation is skipped, and the download fails instead
+        let unlisted = client
+            .daily()
+            .station("00000")
+            .skip_validation(true)
+            .fallback_location(frankfurt)
+            .call()
+            .await?;
+        assert_eq!(unlisted.row_count()?, 1);
+
+        // If the fallback fails as well, the preferred station's error is kept as the source
+        let both_failed = client
+            .daily()
+            .station("00000")
+            .skip_validation(true)
+            .fallback_location(berlin_location())
+            .call()
+            .await;
+        let Err(MeteostatError::FallbackFailed {
+            preferred,
+            fallback,
+            ..
+        }) = both_failed
+        else {
+            panic!("expected FallbackFailed");
+        };
+        assert!(matches!(*preferred, MeteostatError::WeatherData(_)));
+        assert!(matches!(
+            *fallback,
+            MeteostatError::NoStationWithinRadius { .. }
+        ));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_eager_returns_collected_frame() -> Result<(), MeteostatError> {
+        let cache_dir = tempdir()?;
+        seed_daily_cache(
+            cache_dir.path(),
+            "10637",
+            "2022-06-01,20.0,,,,,,,,,\n2022-06-02,21.0,,,,,,,,,\n",
+        )?;
+        let client = Meteostat::with_stations(
+            Station::from_json(frankfurt_station_json())?,
+            cache_dir.path().to_path_buf(),
+        )
+        .await?;
+
+        let eager = client.daily().station("10637").eager().await?;
+        let lazy = client.daily().station("10637").call().await?;
+        assert!(eager.equals_missing(&lazy.collect_frame()?));
+        assert_eq!(eager.height(), 2);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_daily_for_local_period_keeps_overlapping_utc_days() -> Result<(), MeteostatError>
+    {
+        let cache_dir = tempdir()?;
+        seed_daily_cache(
+            cache_dir.path(),
+            "10637",
+            "2022-12-31,1.0,,,,,,,,,\n2023-01-01,2.0,,,,,,,,,\n2023-01-02,3.0,,,,,,,,,\n",
+        )?;
+        let client = Meteostat::with_stations(
+            Station::from_json(frankfurt_station_json())?,
+            cache_dir.path().to_path_buf(),
+        )
+        .await?;
+        let new_year =
+            chrono::NaiveDate::from_ymd_opt(2023, 1, 1).ok_or(MeteostatError::DateParsingError)?;
+        let temperatures = |days: crate::DailyRecords| {
+            days.iter()
+                .map(|day| day.average_temperature)
+                .collect::<Vec<_>>()
+        };
+
+        // January 1st in UTC+10 starts on December 31st in UTC
+        let east =
+            chrono::FixedOffset::east_opt(10 * 3600).ok_or(MeteostatError::DateParsingError)?;
+        let days = client
+            .daily()
+            .station("10637")
+            .for_local_period(new_year, &east)
+            .call()
+            .await?
+            .collect_daily()?;
+        assert_eq!(temperatures(days), vec![Some(1.0), Some(2.0)]);
+
+        // ... and in UTC-5 it ends on January 2nd in UTC
+        let west =
+            chrono::FixedOffset::west_opt(5 * 3600).ok_or(MeteostatError::DateParsingError)?;
+        let days = client
+            .daily()
+            .station("10637")
+            .for_local_period(new_year, &west)
+            .call()
+            .await?
+            .collect_daily()?;
+        assert_eq!(temperatures(days), vec![Some(2.0), Some(3.0)]);
+
+        let days = client
+            .daily()
+            .station("10637")
+            .for_local_period(new_year, &Utc)
+            .call()
+            .await?
+            .collect_daily()?;
+        assert_eq!(temperatures(days), vec![Some(2.0)]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_availability_reports_per_station() -> Result<(), MeteostatError> {
+        let cache_dir = tempdir()?;
+        let client = Meteostat::with_stations(
+            Station::from_json(frankfurt_station_json())?,
+            cache_dir.path().to_path_buf(),
+        )
+        .await?;
+
+        let results =
+            client.check_availability(&["unknown", "10637"], Frequency::Daily, RequiredData::Any);
+        let stations: Vec<&str> = results.iter().map(|r| r.station.as_str()).collect();
+        assert_eq!(stations, ["unknown", "10637"]);
+        // Neither is covered: one isn't in the list, the fixture reports no inventory
+        assert!(results
+            .iter()
+            .all(|r| r.coverage == Coverage::None && r.reported_years.is_none()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_version_info_reports_configured_urls() -> Result<(), MeteostatError> {
+        let cache_dir = tempdir()?;
+        let client = Meteostat::builder()
+            .stations(Station::from_json(frankfurt_station_json())?)
+            .cache_folder(cache_dir.path().to_path_buf())
+            .bulk_data_base_url("http://localhost:8080/")
+            .build()
+            .await?;
+
+        let info = client.version_info();
+        assert_eq!(info.crate_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.station_data_url, DEFAULT_STATION_DATA_URL);
+        assert_eq!(info.bulk_data_base_url, "http://localhost:8080");
+        assert_eq!(
+            info.station_cache_format_version,
+            STATION_CACHE_FORMAT_VERSION
+        );
+        assert!(info.to_string().contains("data: http://localhost:8080,"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_with_stations_skips_station_list_download() -> Result<(), MeteostatError> {
+        let json = frankfurt_station_json();
+        let cache_dir = tempdir()?;
+        let client = Meteostat::builder()
+            .cache_folder(cache_dir.path().to_path_buf())
+            // Unreachable, so the test fails if the station list is downloaded
+            .station_data_url("http://127.0.0.1:9/stations.json.gz")
+            .stations(Station::from_json(json)?)
+            .build()
+            .await?;
+        let pinned =
+            Meteostat::with_stations(Station::from_json(json)?, cache_dir.path().to_path_buf())
+                .await?;
+        assert_eq!(pinned.station("10637"), client.station("10637"));
+
+        assert_eq!(
+            client.station_by_icao("EDDF").map(|s| s.id),
+            Some("10637".to_string())
+        );
+        assert!(client.station("06240").is_none());
+        assert!(!cache_dir.path().join(RKYV_CACHE_FILE_NAME).exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_custom_cache_backend_replaces_cache_folder() -> Result<(), MeteostatError> {
+        let cache_dir = tempdir()?;
+        let unused_folder = cache_dir.path().join("unused");
+        let cache = Arc::new(crate::MemoryCache::new());
+        let with_folder = Meteostat::builder()
+            .cache_folder(unused_folder.clone())
+            .cache_backend(cache.clone())
+            .stations(Station::from_json(frankfurt_station_json())?)
+            .build()
+            .await;
+        assert!(matches!(with_folder, Err(MeteostatError::InvalidConfig(_))));
+        assert!(!unused_folder.exists());
+
+        let client = Meteostat::builder()
+            .cache_backend(cache.clone())
+            .station_data_url("http://127.0.0.1:9/stations.json.gz")
+            .stations(Station::from_json(frankfurt_station_json())?)
+            .build()
+            .await?;
+
+        let report = client.health_check().await;
+        assert!(report.cache_dir_writable);
+        assert!(!report.station_cache_valid);
+
+        cache
+            .write("daily-10637.parquet", b"cached".to_vec())
+            .await?;
+        cache
+            .write(RKYV_CACHE_FILE_NAME, b"cached".to_vec())
+            .await?;
+        client.clear_weather_data_cache().await?;
+        assert_eq!(cache.list().await?, vec![RKYV_CACHE_FILE_NAME.to_string()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_station_replaces_cache_entry() -> Result<(), MeteostatError> {
+        let cache = Arc::new(crate::MemoryCache::new());
+        let client = Meteostat::builder()
+            .cache_backend(cache.clone())
+            // Unreachable, so the download after clearing the cache fails
+            .bulk_data_base_url("http://127.0.0.1:9")
+            .stations(Station::from_json(frankfurt_station_json())?)
+            .build()
+            .await?;
+        cache
+            .write("daily-10637.parquet", b"stale".to_vec())
+            .await?;
+
+        assert!(matches!(
+            client.refresh_station("06240", Frequency::Daily).await,
+            Err(MeteostatError::UnknownStation { .. })
+        ));
+        assert!(cache.read("daily-10637.parquet").await?.is_some());
+
+        assert!(matches!(
+            client.refresh_station("10637", Frequency::Daily).await,
+            Err(MeteostatError::WeatherData(_))
+        ));
+        assert_eq!(cache.read("daily-10637.parquet").await?, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_builder_rejects_contradictory_options() -> Result<(), MeteostatError> {
+        let invalid = |result: Result<Meteostat, MeteostatError>| {
+            matches!(result, Err(MeteostatError::InvalidConfig(_)))
+        };
+        let stations = || Station::from_json(frankfurt_station_json());
+        let memory = || Arc::new(crate::MemoryCache::new());
+
+        assert!(invalid(
+            Meteostat::builder()
+                .cache_backend(memory())
+                .cache_folder(PathBuf::from("cache"))
+                .stations(stations()?)
+                .build()
+                .await
+        ));
+        assert!(invalid(
+            Meteostat::builder()
+                .cache_backend(memory())
+                .cache_lock_timeout(Duration::from_secs(1))
+                .stations(stations()?)
+                .build()
+                .await
+        ));
+        assert!(invalid(
+            Meteostat::builder()
+                .cache_backend(memory())
+                .bulk_data_base_url("bulk.meteostat.net/v2")
+                .stations(stations()?)
+                .build()
+                .await
+        ));
+        assert!(invalid(
+            Meteostat::builder()
+                .cache_backend(memory())
+                .max_concurrent_downloads(0)
+                .stations(stations()?)
+                .build()
+                .await
+        ));
+
+        let temp_dir = tempdir()?;
+        let local = Meteostat::builder()
+            .cache_backend(Arc::new(FileSystemCache::new(temp_dir.path())))
+            .cache_lock_timeout(Duration::from_secs(1))
+            .bulk_data_base_url("http://localhost:8080")
+            .stations(stations()?)
+            .build()
+            .await;
+        assert!(local.is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_purge_cache_older_than() -> Result<(), MeteostatError> {
+        let cache = Arc::new(crate::MemoryCache::new());
+        let client = Meteostat::builder()
+            .cache_backend(cache.clone())
+            .stations(Station::from_json(frankfurt_station_json())?)
+            .build()
+            .await?;
+        cache
+            .write("daily-10637.parquet", b"daily".to_vec())
+            .await?;
+        cache.write("stations_lite.rkyv", b"list".to_vec()).await?;
+
+        let day = Duration::from_secs(24 * 60 * 60);
+        assert_eq!(client.purge_cache_older_than(day).await?.files_removed, 0);
+        assert_eq!(
+            client.purge_cache_older_than(Duration::MAX).await?,
+            PurgeReport::default()
+        );
+
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let report = client.purge_cache_older_than(Duration::ZERO).await?;
+        assert_eq!(
+            report,
+            PurgeReport {
+                files_removed: 1,
+                bytes_freed: 5,
+            }
+        );
+        assert_eq!(cache.read("daily-10637.parquet").await?, None);
+        assert!(cache.read("stations_lite.rkyv").await?.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_try_location_reports_failed_station() -> Result<(), MeteostatError> {
+        // Candidates for a location must report the frequency in their inventory
+        let station_json = frankfurt_station_json().replace(
+            r#""daily": {"start": null, "end": null}"#,
+            r#""daily": {"start": "2023-01-01", "end": "2023-06-30"}"#,
+        );
+        let client = Meteostat::builder()
+            .cache_backend(Arc::new(crate::MemoryCache::new()))
+            .bulk_data_base_url("http://127.0.0.1:9")
+            .stations(Station::from_json(&station_json)?)
+            .build()
+            .await?;
+
+        // The only candidate fails, so its warning becomes the error's last error
+        let result = client
+            .daily()
+            .try_location(LatLon(50.05, 8.6))
+            .for_period(Year(2023))
+            .call()
+            .await;
+        let Err(MeteostatError::NoDataFoundForNearbyStations {
+            stations_tried,
+            last_error,
+            ..
+        }) = result
+        else {
+            panic!("expected NoDataFoundForNearbyStations");
+        };
+        assert_eq!(stations_tried, 1);
+        assert!(matches!(
+            last_error.as_deref(),
+            Some(MeteostatError::WeatherData(_))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_try_location_warns_about_skipped_station_and_coverage(
+    ) -> Result<(), MeteostatError> {
+        let inventory = |json: &str| {
+            json.replace(
+                r#""daily": {"start": null, "end": null}"#,
+                r#""daily": {"start": "2023-01-01", "end": "2023-06-30"}"#,
+            )
+        };
+        let mut stations = Station::from_json(&inventory(frankfurt_station_json()))?;
+        stations.extend(Station::from_json(&inventory(
+            &frankfurt_station_json().replace("10637", "10641").replace(
+                r#""latitude": 50.05, "longitude": 8.6"#,
+                r#""latitude": 50.09, "longitude": 8.78"#,
+            ),
+        ))?);
+
+        // Only the farther station is cached; the nearer one fails to download
+        let cache = Arc::new(crate::MemoryCache::new());
+        let mut df =
+            crate::parse_daily_csv(b"2023-01-01,5.1,2.0,8.3,1.2,,240,15.0,40.0,1012.3,60\n")?
+                .frame
+                .collect()?;
+        let mut parquet = Vec::new();
+        polars::prelude::ParquetWriter::new(&mut parquet).finish(&mut df)?;
+        cache.write("daily-10641.parquet", parquet).await?;
+        let client = Meteostat::builder()
+            .cache_backend(cache)
+            .bulk_data_base_url("http://127.0.0.1:9")
+            .stations(stations)
+            .build()
+            .await?;
+
+        let result = client
+            .daily()
+            .try_location(LatLon(50.05, 8.6))
+            .station_limit(2)
+            .for_period(Year(2023))
+            .call()
+            .await?;
+        assert!(result.has_warnings());
+        assert!(matches!(
+            result.warnings.as_slice(),
+            [
+                FetchWarning::StationFailed { station: failed, error: MeteostatError::WeatherData(_) },
+                FetchWarning::PeriodNotCovered { station: used, coverage: crate::Coverage::Partial },
+            ] if failed == "10637" && used == "10641"
+        ));
+        assert_eq!(
+            result.warnings[1].to_string(),
+            "Station '10641' reports partial coverage of the requested period"
+        );
+        assert_eq!(result.into_inner().row_count()?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_null_as_zero_only_touches_given_columns() -> Result<(), MeteostatError> {
+        // date,tavg,tmin,tmax,prcp,snow,...
+        let csv = "2023-01-01,1.0,,,,,,,,,\n2023-01-02,,,,2.5,10,,,,,\n";
+        let frame = crate::parse_daily_csv(csv.as_bytes())?.frame;
+
+        let filled = DailyLazyFrame::new(fill_null_as_zero(
+            frame.clone(),
+            Frequency::Daily,
+            &["prcp", "snow"],
+        ))
+        .collect_daily()?;
+        assert_eq!(filled[0].precipitation, Some(0.0));
+        assert_eq!(filled[0].snow, Some(0));
+        assert_eq!(filled[0].minimum_temperature, None);
+        assert_eq!(filled[1].precipitation, Some(2.5));
+
+        let untouched =
+            DailyLazyFrame::new(fill_null_as_zero(frame, Frequency::Daily, &[])).collect_daily()?;
+        assert_eq!(untouched[0].precipitation, None);
+
+        // Monthly data has no snow column, so only precipitation is filled
+        let monthly = crate::parse_monthly_csv(b"2023,1,1.0,,,,,,\n")?.frame;
+        let monthly =
+            fill_null_as_zero(monthly, Frequency::Monthly, &["prcp", "snow"]).collect()?;
+        assert_eq!(monthly.column("prcp")?.f64()?.get(0), Some(0.0));
+        assert!(monthly.column("snow").is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_client_without_cache_folder_uses_default_cache_dir(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let client = Meteostat::builder()
+            .bulk_data_base_url("http://127.0.0.1:9")
+            .stations(Station::from_json(frankfurt_station_json())?)
+            .build()
+            .await?;
+
+        let dir = Meteostat::default_cache_dir()?;
+        assert!(dir.is_dir(), "the default cache folder should be created");
+        let key = crate::weather_data::data_loader::WeatherDataLoader::cache_key(
+            Frequency::Daily,
+            "10637",
+        );
+        assert_eq!(client.cache.local_path(&key), Some(dir.join(&key)));
+        Ok(())
+    }
+
     /// Helper function to check if a cache file exists
     fn cache_file_exists(cache_dir: &Path, station: &str, frequency: Frequency) -> bool {
         let file = cache_dir.join(format!("{}-{}.parquet", frequency.path_segment(), station));
@@ -830,7 +3100,7 @@ mod tests {
         // --- Clear all cache (async operation) ---
         client.clear_weather_data_cache().await?;
 
-        // --- Verify directory is empty except for stations file (sync version) ---
+        // --- Verify directory is empty except for station list files (sync version) ---
         let mut file_count = 0;
         let mut stations_file_found = false;
         let stations_filename = OsStr::new(RKYV_CACHE_FILE_NAME); // Define expected filename
@@ -840,7 +3110,7 @@ mod tests {
             let entry = entry_result?; // Propagate IO errors
             let path = entry.path();
 
-            if path.is_file() {
+            if path.is_file() && entry.file_name() != OsStr::new(VALIDATORS_FILE_NAME) {
                 file_count += 1;
                 if entry.file_name() == stations_filename {
                     stations_file_found = true;
@@ -867,6 +3137,93 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_daily_at_points_keeps_order_and_errors() -> Result<(), MeteostatError> {
+        let client = Meteostat::new().await?;
+        // Two points next to each other share a station; the third is in the middle of the ocean
+        let points = [
+            berlin_location(),
+            LatLon(52.52, 13.41),
+            LatLon(-40.0, -130.0),
+        ];
+
+        let results = client.daily_at_points(&points).await;
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert!(matches!(
+            results[2],
+            Err(MeteostatError::NoStationWithinRadius { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_tag_station_id_adds_station_column() -> Result<(), MeteostatError> {
+        let client = Meteostat::builder().tag_station_id(true).build().await?;
+        let by_station = client.daily().station("10637").call().await?;
+        let ids = by_station.frame.limit(3).collect()?;
+        let ids = ids.column(STATION_ID_COLUMN)?.str()?;
+        assert!((0..ids.len()).all(|i| ids.get(i) == Some("10637")));
+
+        // Location requests are tagged with the station that was picked
+        let by_location = client.daily().location(berlin_location()).call().await?;
+        let picked = by_location
+            .frame
+            .limit(1)
+            .collect()?
+            .column(STATION_ID_COLUMN)?
+            .str()?
+            .get(0)
+            .map(str::to_string);
+        assert!(picked.is_some_and(|id| client.station(&id).is_some()));
+
+        // Untagged clients don't get the column
+        let untagged = Meteostat::new()
+            .await?
+            .daily()
+            .station("10637")
+            .call()
+            .await?
+            .frame
+            .limit(1)
+            .collect()?;
+        assert!(untagged.column(STATION_ID_COLUMN).is_err());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_check_updates_reports_cache_state() -> Result<(), MeteostatError> {
+        let temp_dir = tempdir()?;
+        let client = Meteostat::with_cache_folder(temp_dir.path().to_path_buf()).await?;
+        client.daily().station("10637").call().await?;
+
+        let statuses = client
+            .check_updates(&["10637", "06240"], Frequency::Daily)
+            .await;
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[0].station, "10637");
+        assert!(statuses[0].remote_modified.is_some());
+        assert_eq!(statuses[0].is_stale(), Some(false));
+        assert_eq!(statuses[1].cached_at, None);
+        assert_eq!(statuses[1].is_stale(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_status_is_stale() {
+        let at = |hour| Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).single();
+        let status = |cached_at, remote_modified| UpdateStatus {
+            station: "10637".to_string(),
+            cached_at,
+            remote_modified,
+        };
+        assert_eq!(status(at(1), at(2)).is_stale(), Some(true));
+        assert_eq!(status(at(2), at(1)).is_stale(), Some(false));
+        assert_eq!(status(None, at(1)).is_stale(), None);
+        assert_eq!(status(at(1), None).is_stale(), None);
+    }
+
     // --- Constructor Tests ---
 
     #[tokio::test(flavor = "multi_thread")]
@@ -892,6 +3249,61 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_health_check() -> Result<(), MeteostatError> {
+        let client = Meteostat::new().await?;
+
+        let report = client.health_check().await;
+        assert!(report.is_healthy(), "Unexpected health report: {report:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_health_report_requires_all_checks() {
+        let report = HealthReport {
+            server_reachable: true,
+            station_cache_valid: true,
+            stations_loaded: 10,
+            cache_dir_writable: true,
+        };
+        assert!(report.is_healthy());
+        assert!(!HealthReport {
+            cache_dir_writable: false,
+            ..report
+        }
+        .is_healthy());
+        assert!(!HealthReport {
+            stations_loaded: 0,
+            ..report
+        }
+        .is_healthy());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_builder_uses_custom_station_data_url() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempdir()?;
+        let custom_url = "http://127.0.0.1:9/stations/lite.json.gz";
+
+        let result = Meteostat::builder()
+            .cache_folder(temp_dir.path().to_path_buf())
+            .station_data_url(custom_url)
+            .build()
+            .await;
+
+        // Nothing listens on the discard port, so the download must fail on our custom URL.
+        assert!(
+            matches!(
+                &result,
+                Err(MeteostatError::LocateStation(LocateStationError::NetworkRequest(url, _)))
+                    if url == custom_url
+            ),
+            "Expected a network error for the custom URL"
+        );
+
+        temp_dir.close()?;
+        Ok(())
+    }
+
     // --- find_stations Tests ---
 
     #[tokio::test(flavor = "multi_thread")]
@@ -1027,6 +3439,38 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_station_by_id() -> Result<(), MeteostatError> {
+        let client = Meteostat::new().await?;
+
+        let station = client.station("10637").expect("Station 10637 should exist");
+        assert_eq!(station.id, "10637");
+        assert!(client.station("INVALID_STATION_ID_123").is_none());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_station_by_icao_and_wmo() -> Result<(), MeteostatError> {
+        let client = Meteostat::new().await?;
+
+        let station = client
+            .station_by_icao("EGLL")
+            .expect("EGLL should be in the station list");
+        let wmo = station.wmo().expect("Heathrow should have a WMO id");
+        assert_eq!(client.station_by_wmo(wmo), Some(station));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_search_stations_by_name() -> Result<(), MeteostatError> {
+        let client = Meteostat::new().await?;
+
+        let stations = client.search_stations_by_name("Heathrow", 3);
+        assert!(!stations.is_empty());
+        assert!(stations.len() <= 3);
+        Ok(())
+    }
+
     // --- Error Handling Tests ---
 
     #[tokio::test(flavor = "multi_thread")]
@@ -1128,13 +3572,33 @@ mod tests {
             .call()
             .await; // Directly call on the client returned by .station()
 
-        // This should ideally result in an error related to fetching/finding data for that ID.
-        // The exact error might depend on FrameFetcher's implementation (e.g., file not found, download error).
+        // The ID isn't in the station list, so this should fail before any download is attempted.
         assert!(result.is_err());
         let err = result.err().unwrap();
         println!("Error fetching data for invalid station ID: {err:?}");
 
-        // The error should originate from the data fetching layer
+        assert!(
+            matches!(err, MeteostatError::UnknownStation { ref id } if id == invalid_station_id),
+            "Expected an UnknownStation error variant, got {err:?}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_data_from_invalid_station_id_skip_validation() -> Result<(), MeteostatError> {
+        let client = Meteostat::new().await?;
+        let invalid_station_id = "INVALID_STATION_ID_123";
+
+        let result = client
+            .hourly()
+            .station(invalid_station_id)
+            .skip_validation(true)
+            .call()
+            .await;
+
+        // With validation skipped, the failure comes from the data fetching layer instead.
+        let err = result.err().unwrap();
         assert!(
             matches!(err, MeteostatError::WeatherData(_)),
             "Expected a WeatherData error variant, got {err:?}"
@@ -0,0 +1,137 @@
+//! Parses Meteostat bulk CSV files obtained outside of this crate.
+//!
+//! These functions apply the same column names and types as data fetched through the
+//! [`crate::Meteostat`] clients, so the result can be used with the frame wrappers' filtering
+//! and collection methods. They expect the plain (decompressed) CSV contents of a Meteostat
+//! bulk file, which has no header row.
+
+use crate::types::csv_options::CsvOptions;
+use crate::types::frequency::Frequency;
+use crate::weather_data::data_loader::WeatherDataLoader;
+use crate::{ClimateLazyFrame, DailyLazyFrame, HourlyLazyFrame, MeteostatError, MonthlyLazyFrame};
+use polars::prelude::{IntoLazy, LazyFrame};
+
+/// Label used in place of a station ID in parse errors.
+const INPUT_LABEL: &str = "<csv input>";
+
+fn parse(bytes: &[u8], frequency: Frequency) -> Result<LazyFrame, MeteostatError> {
+    Ok(WeatherDataLoader::parse_csv(bytes, INPUT_LABEL, frequency, &CsvOptions::default())?.lazy())
+}
+
+/// Parses the contents of a Meteostat hourly CSV file.
+///
+/// # Arguments
+///
+/// * `bytes` - The decompressed CSV contents, without a header row.
+///
+/// # Returns
+///
+/// A `Result` containing an [`HourlyLazyFrame`] with the crate's hourly schema.
+///
+/// # Errors
+///
+/// Returns [`MeteostatError::WeatherData`] if the CSV cannot be read or its columns don't
+/// match the hourly format.
+pub fn parse_hourly_csv(bytes: &[u8]) -> Result<HourlyLazyFrame, MeteostatError> {
+    parse(bytes, Frequency::Hourly).map(HourlyLazyFrame::new)
+}
+
+/// Parses the contents of a Meteostat daily CSV file.
+///
+/// # Arguments
+///
+/// * `bytes` - The decompressed CSV contents, without a header row.
+///
+/// # Returns
+///
+/// A `Result` containing a [`DailyLazyFrame`] with the crate's daily schema.
+///
+/// # Errors
+///
+/// Returns [`MeteostatError::WeatherData`] if the CSV cannot be read or its columns don't
+/// match the daily format.
+///
+/// # Example
+///
+/// ```no_run
+/// use meteostat::{parse_daily_csv, MeteostatError, Year};
+///
+/// fn main() -> Result<(), MeteostatError> {
+///     let bytes = std::fs::read("10637.csv").expect("file should be readable");
+///     let daily = parse_daily_csv(&bytes)?
+///         .get_for_period(Year(2023))?
+///         .collect_daily()?;
+///     println!("{} days", daily.len());
+///     Ok(())
+/// }
+/// ```
+pub fn parse_daily_csv(bytes: &[u8]) -> Result<DailyLazyFrame, MeteostatError> {
+    parse(bytes, Frequency::Daily).map(DailyLazyFrame::new)
+}
+
+/// Parses the contents of a Meteostat monthly CSV file.
+///
+/// # Arguments
+///
+/// * `bytes` - The decompressed CSV contents, without a header row.
+///
+/// # Returns
+///
+/// A `Result` containing a [`MonthlyLazyFrame`] with the crate's monthly schema.
+///
+/// # Errors
+///
+/// Returns [`MeteostatError::WeatherData`] if the CSV cannot be read or its columns don't
+/// match the monthly format.
+pub fn parse_monthly_csv(bytes: &[u8]) -> Result<MonthlyLazyFrame, MeteostatError> {
+    parse(bytes, Frequency::Monthly).map(MonthlyLazyFrame::new)
+}
+
+/// Parses the contents of a Meteostat climate normals CSV file.
+///
+/// # Arguments
+///
+/// * `bytes` - The decompressed CSV contents, without a header row.
+///
+/// # Returns
+///
+/// A `Result` containing a [`ClimateLazyFrame`] with the crate's climate schema.
+///
+/// # Errors
+///
+/// Returns [`MeteostatError::WeatherData`] if the CSV cannot be read or its columns don't
+/// match the climate normals format.
+pub fn parse_climate_csv(bytes: &[u8]) -> Result<ClimateLazyFrame, MeteostatError> {
+    parse(bytes, Frequency::Climate).map(ClimateLazyFrame::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_daily_csv() -> Result<(), MeteostatError> {
+        let csv = "2023-01-02,4.0,1.1,6.9,0.0,,250,12.0,35.0,1015.0,\n\
+                   2023-01-01,5.1,2.0,8.3,1.2,,240,15.0,40.0,1012.3,60\n";
+        let daily = parse_daily_csv(csv.as_bytes())?.collect_daily()?;
+
+        assert_eq!(daily.len(), 2);
+        assert_eq!(
+            daily[0].date,
+            NaiveDate::from_ymd_opt(2023, 1, 1).unwrap_or_default()
+        );
+        assert_eq!(daily[0].average_temperature, Some(5.1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_wrong_format() {
+        // An hourly row has more columns than the monthly format allows
+        let hourly_csv = b"2023-01-01,0,1.0,,80,,,200,5.0,,1010.0,,3\n";
+        assert!(matches!(
+            parse_monthly_csv(hourly_csv),
+            Err(MeteostatError::WeatherData(_))
+        ));
+    }
+}
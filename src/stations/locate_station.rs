@@ -1,29 +1,130 @@
+use crate::cache_backend::{entry_path, CacheBackend};
 use crate::stations::error::LocateStationError;
-use crate::types::frequency::{Frequency, RequiredData};
+use crate::types::distance_metric::DistanceMetric;
+use crate::types::frequency::{month_bounds, Frequency, RequiredData};
 use crate::types::station::YearRange;
-use crate::types::station::{DateRange, Station};
+use crate::types::station::{Coverage, DateRange, Station};
 use async_compression::tokio::bufread::GzipDecoder;
 use chrono::{Datelike, NaiveDate};
 use futures_util::TryStreamExt;
-use haversine::{distance, Location as HaversineLocation, Units};
 use ordered_float::OrderedFloat;
-use reqwest::Client;
+use reqwest::header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{Client, Response, StatusCode};
+use rkyv::util::AlignedVec;
+use rstar::primitives::GeomWithData;
 use rstar::RTree;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::collections::BinaryHeap;
-use std::fs::remove_file;
-use std::io::Write;
+use std::collections::{BinaryHeap, HashMap};
+use std::f64::consts::PI;
 use std::io::{self};
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::io::{AsyncReadExt, BufReader};
+use tokio::sync::Semaphore;
 use tokio_util::io::StreamReader;
 
-const DATA_URL: &str = "https://bulk.meteostat.net/v2/stations/lite.json.gz";
+/// Official location of the (gzipped JSON) station list.
+pub const DEFAULT_STATION_DATA_URL: &str = "https://bulk.meteostat.net/v2/stations/lite.json.gz";
 pub const RKYV_CACHE_FILE_NAME: &str = "stations_lite.rkyv";
+/// Version of the layout of the rkyv station cache. Bumped whenever the serialized
+/// [`Station`] changes. It is written in the header of the cache file, and a cache written
+/// with another version (or by a crate version from before the header) is rebuilt from a
+/// fresh download instead of being decoded.
+pub const STATION_CACHE_FORMAT_VERSION: u32 = 1;
+/// Marks the start of the header of the rkyv station cache, followed by the little-endian
+/// [`STATION_CACHE_FORMAT_VERSION`].
+const STATION_CACHE_MAGIC: &[u8; 4] = b"MSRK";
+/// Stores the HTTP validators of the cached station list, next to the rkyv cache.
+pub const VALIDATORS_FILE_NAME: &str = "stations_lite.validators.json";
+/// Earth radius used to turn `max_distance_km` into a search radius on the unit sphere. This
+/// is the smallest meridional radius of curvature on the WGS-84 ellipsoid, so the radius also
+/// holds every station in range under the Vincenty metric (haversine uses the larger mean
+/// radius).
+const SEARCH_EARTH_RADIUS_KM: f64 = 6335.0;
+
+/// A station location in the R-tree as a point on the unit sphere (see [`unit_vector`]), with
+/// the position of the station in [`StationLocator::stations`].
+type StationPoint = GeomWithData<[f64; 3], usize>;
+
+/// Converts a latitude and longitude in degrees to a point on the unit sphere.
+///
+/// The straight-line distance between two such points grows with the great-circle distance,
+/// so the R-tree visits stations nearest first, also near the poles and the antimeridian.
+fn unit_vector(latitude: f64, longitude: f64) -> [f64; 3] {
+    let (lat, lon) = (latitude.to_radians(), longitude.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
 
 #[derive(Debug, Clone)]
 pub struct StationLocator {
-    rtree: RTree<Station>,
+    /// All loaded stations, each stored once. The indexes below refer to them by position.
+    stations: Vec<Station>,
+    /// Station locations on the unit sphere, with the position of the station.
+    rtree: RTree<StationPoint>,
+    /// Station ID -> position in `stations`, for O(1) lookups by ID.
+    id_index: HashMap<String, usize>,
+    /// Uppercase ICAO code -> station ID.
+    icao_index: HashMap<String, String>,
+    /// WMO identifier -> station ID.
+    wmo_index: HashMap<String, String>,
+    /// Where the station list is downloaded from.
+    data_url: String,
+    /// Shared with the weather data loader to bound the number of simultaneous downloads.
+    download_permits: Arc<Semaphore>,
+    /// How distances to stations are computed in `query`.
+    distance_metric: DistanceMetric,
+}
+
+/// `ETag` / `Last-Modified` values of a downloaded station list, used to make
+/// conditional requests when rebuilding the cache.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct CacheValidators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl CacheValidators {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let get = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        Self {
+            etag: get(ETAG),
+            last_modified: get(LAST_MODIFIED),
+        }
+    }
+
+    const fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+
+    /// Reads stored validators. Missing or unreadable entries just mean "no validators".
+    async fn read(cache: &dyn CacheBackend) -> Option<Self> {
+        let bytes = cache.read(VALIDATORS_FILE_NAME).await.ok()??;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    /// Stores the validators, or removes stale ones if the server didn't send any.
+    async fn write(&self, cache: &dyn CacheBackend) -> Result<(), LocateStationError> {
+        let write_error =
+            |e| LocateStationError::CacheWrite(entry_path(cache, VALIDATORS_FILE_NAME), e);
+        if self.is_empty() {
+            return cache
+                .delete(VALIDATORS_FILE_NAME)
+                .await
+                .map_err(write_error);
+        }
+        let json = serde_json::to_vec(self)?;
+        cache
+            .write(VALIDATORS_FILE_NAME, json)
+            .await
+            .map_err(write_error)
+    }
 }
 
 // Helper struct for BinaryHeap ordering
@@ -50,54 +151,161 @@ impl Ord for StationCandidate<'_> {
 }
 
 impl StationLocator {
-    pub async fn new(cache_dir: &Path) -> Result<Self, LocateStationError> {
-        let cache_file = cache_dir.join(RKYV_CACHE_FILE_NAME);
+    pub async fn new(
+        cache: &dyn CacheBackend,
+        data_url: &str,
+        download_permits: Arc<Semaphore>,
+    ) -> Result<Self, LocateStationError> {
+        let cache_path = entry_path(cache, RKYV_CACHE_FILE_NAME);
+        let cached = cache
+            .read(RKYV_CACHE_FILE_NAME)
+            .await
+            .map_err(|e| LocateStationError::CacheRead(cache_path.clone(), e))?;
 
         let stations: Vec<Station>;
 
-        if cache_file.exists() {
-            let path_clone = cache_file.clone();
-            stations = tokio::task::spawn_blocking(move || Self::get_cached_stations(&path_clone))
-                .await??;
+        // A cache in another format version is replaced like a missing one
+        if let Some(payload) = cached.and_then(|bytes| Self::cache_payload(&bytes)) {
+            stations =
+                tokio::task::spawn_blocking(move || Self::decode_stations(&payload, &cache_path))
+                    .await??;
         } else {
-            stations = Self::fetch_stations().await?;
-            Self::cache_stations(stations.clone(), &cache_file).await?;
+            let validators;
+            (stations, validators) = {
+                let _permit = download_permits.acquire().await.ok();
+                let response = Self::request_stations(data_url, None).await?;
+                Self::parse_stations_response(response).await?
+            };
+            Self::cache_stations(stations.clone(), cache).await?;
+            validators.write(cache).await?;
         }
 
-        let rtree = RTree::bulk_load(stations);
-        Ok(Self { rtree })
+        Ok(Self::from_stations(
+            stations,
+            data_url.to_string(),
+            download_permits,
+        ))
+    }
+
+    /// The URL the station list is downloaded from.
+    pub fn data_url(&self) -> &str {
+        &self.data_url
+    }
+
+    /// Sets the metric used for station distances in `query`.
+    #[must_use]
+    pub const fn with_distance_metric(mut self, distance_metric: DistanceMetric) -> Self {
+        self.distance_metric = distance_metric;
+        self
+    }
+
+    /// Builds the spatial R-tree and the ID, ICAO and WMO indexes from a list of stations.
+    pub fn from_stations(
+        stations: Vec<Station>,
+        data_url: String,
+        download_permits: Arc<Semaphore>,
+    ) -> Self {
+        let id_index = stations
+            .iter()
+            .enumerate()
+            .map(|(index, station)| (station.id.clone(), index))
+            .collect();
+        let points = stations
+            .iter()
+            .enumerate()
+            .map(|(index, station)| {
+                StationPoint::new(
+                    unit_vector(station.location.latitude, station.location.longitude),
+                    index,
+                )
+            })
+            .collect();
+        let icao_index = stations
+            .iter()
+            .filter_map(|station| Some((station.icao()?.to_uppercase(), station.id.clone())))
+            .collect();
+        let wmo_index = stations
+            .iter()
+            .filter_map(|station| Some((station.wmo()?.to_string(), station.id.clone())))
+            .collect();
+        Self {
+            rtree: RTree::bulk_load(points),
+            stations,
+            id_index,
+            icao_index,
+            wmo_index,
+            data_url,
+            download_permits,
+            distance_metric: DistanceMetric::default(),
+        }
     }
 
     // --- Caching and Fetching methods ---
-    fn get_cached_stations(cache_path: &Path) -> Result<Vec<Station>, LocateStationError> {
-        let bytes = std::fs::read(cache_path)
-            .map_err(|e| LocateStationError::CacheRead(cache_path.to_path_buf(), e))?;
-        let decoded_stations = rkyv::from_bytes::<Vec<Station>, rkyv::rancor::Error>(&bytes)
+    /// Decodes a cached station list. `cache_path` is only used to label errors.
+    fn decode_stations(
+        payload: &[u8],
+        cache_path: &Path,
+    ) -> Result<Vec<Station>, LocateStationError> {
+        let decoded_stations = rkyv::from_bytes::<Vec<Station>, rkyv::rancor::Error>(payload)
             .map_err(|e| LocateStationError::CacheDecode(cache_path.to_path_buf(), e))?;
         Ok(decoded_stations)
     }
 
-    async fn fetch_stations() -> Result<Vec<Station>, LocateStationError> {
+    /// The header written before the rkyv data of the station cache.
+    fn cache_header() -> Vec<u8> {
+        let mut header = STATION_CACHE_MAGIC.to_vec();
+        header.extend_from_slice(&STATION_CACHE_FORMAT_VERSION.to_le_bytes());
+        header
+    }
+
+    /// The rkyv data of a station cache file, copied to an aligned buffer as rkyv requires,
+    /// or `None` if the file wasn't written with the current [`STATION_CACHE_FORMAT_VERSION`].
+    fn cache_payload(bytes: &[u8]) -> Option<AlignedVec> {
+        let payload = bytes.strip_prefix(Self::cache_header().as_slice())?;
+        let mut aligned = AlignedVec::with_capacity(payload.len());
+        aligned.extend_from_slice(payload);
+        Some(aligned)
+    }
+
+    /// Requests the station list. If `validators` are given, the request is conditional and
+    /// the server may answer with `304 Not Modified`.
+    async fn request_stations(
+        data_url: &str,
+        validators: Option<&CacheValidators>,
+    ) -> Result<Response, LocateStationError> {
         let client = Client::new();
-        let response = client
-            .get(DATA_URL)
+        let mut request = client.get(data_url);
+        if let Some(validators) = validators {
+            if let Some(etag) = &validators.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &validators.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+        let response = request
             .send()
             .await
-            .map_err(|e| LocateStationError::NetworkRequest(DATA_URL.to_string(), e))?;
-        let response = match response.error_for_status() {
-            Ok(resp) => resp,
-            Err(e) => {
-                return if let Some(status) = e.status() {
-                    Err(LocateStationError::HttpStatus {
-                        url: DATA_URL.to_string(),
-                        status,
-                        source: e,
-                    })
-                } else {
-                    Err(LocateStationError::NetworkRequest(DATA_URL.to_string(), e))
+            .map_err(|e| LocateStationError::NetworkRequest(data_url.to_string(), e))?;
+        response.error_for_status().map_err(|e| {
+            if let Some(status) = e.status() {
+                LocateStationError::HttpStatus {
+                    url: data_url.to_string(),
+                    status,
+                    source: e,
                 }
+            } else {
+                LocateStationError::NetworkRequest(data_url.to_string(), e)
             }
-        };
+        })
+    }
+
+    /// Decompresses and parses a station list response, returning the stations together
+    /// with the response's cache validators.
+    async fn parse_stations_response(
+        response: Response,
+    ) -> Result<(Vec<Station>, CacheValidators), LocateStationError> {
+        let validators = CacheValidators::from_headers(response.headers());
         let stream = response.bytes_stream().map_err(io::Error::other);
         let stream_reader = StreamReader::new(stream);
         let gzip_decoder = GzipDecoder::new(BufReader::new(stream_reader));
@@ -109,12 +317,12 @@ impl StationLocator {
                 .map_err(LocateStationError::from)
         })
         .await??;
-        Ok(stations)
+        Ok((stations, validators))
     }
 
     async fn cache_stations(
         stations: Vec<Station>,
-        cache_path: &Path,
+        cache: &dyn CacheBackend,
     ) -> Result<(), LocateStationError> {
         let rkyv_data = tokio::task::spawn_blocking({
             move || {
@@ -123,58 +331,236 @@ impl StationLocator {
             }
         })
         .await??;
-        let path_buf = cache_path.to_path_buf();
-        tokio::task::spawn_blocking(move || {
-            let parent = path_buf.parent().ok_or_else(|| {
-                LocateStationError::CacheWrite(
-                    path_buf.clone(),
-                    io::Error::new(io::ErrorKind::NotFound, "No parent directory"),
-                )
-            })?;
-            if path_buf.exists() {
-                return Ok::<(), LocateStationError>(());
-            }
-            let mut temp_file = tempfile::NamedTempFile::new_in(parent)
-                .map_err(|e| LocateStationError::CacheWrite(path_buf.clone(), e))?;
-            temp_file
-                .write_all(&rkyv_data)
-                .map_err(|e| LocateStationError::CacheWrite(path_buf.clone(), e))?;
-            temp_file
-                .flush()
-                .map_err(|e| LocateStationError::CacheWrite(path_buf.clone(), e))?;
-            if path_buf.exists() {
-                return Ok::<(), LocateStationError>(());
-            }
-            if let Err(err) = temp_file.persist(&path_buf) {
-                if path_buf.exists() {
-                    return Ok::<(), LocateStationError>(());
-                }
-                return Err(LocateStationError::CacheWrite(path_buf.clone(), err.error));
+        let mut bytes = Self::cache_header();
+        bytes.extend_from_slice(&rkyv_data);
+        cache
+            .write(RKYV_CACHE_FILE_NAME, bytes)
+            .await
+            .map_err(|e| LocateStationError::CacheWrite(entry_path(cache, RKYV_CACHE_FILE_NAME), e))
+    }
+
+    /// Clears the cache and rebuilds the rtree from fresh data.
+    ///
+    /// If the cached list has stored `ETag`/`Last-Modified` validators, a conditional request
+    /// is made and a `304 Not Modified` response keeps the existing cache.
+    pub async fn rebuild_cache(
+        &mut self,
+        cache: &dyn CacheBackend,
+    ) -> Result<(), LocateStationError> {
+        // Validators are only meaningful while the data they describe is still cached
+        let is_cached = cache
+            .modified(RKYV_CACHE_FILE_NAME)
+            .await
+            .is_ok_and(|modified| modified.is_some());
+        let validators = if is_cached {
+            CacheValidators::read(cache).await
+        } else {
+            None
+        };
+
+        let (stations, validators) = {
+            let _permit = self.download_permits.acquire().await.ok();
+            let response = Self::request_stations(&self.data_url, validators.as_ref()).await?;
+            if validators.is_some() && response.status() == StatusCode::NOT_MODIFIED {
+                return Ok(());
             }
-            Ok::<(), LocateStationError>(())
-        })
-        .await??;
+            Self::parse_stations_response(response).await?
+        };
 
+        Self::cache_stations(stations.clone(), cache).await?;
+        validators.write(cache).await?;
+        *self = Self::from_stations(
+            stations,
+            self.data_url.clone(),
+            self.download_permits.clone(),
+        )
+        .with_distance_metric(self.distance_metric);
         Ok(())
     }
 
-    /// Clears the cache and rebuilds the rtree from fresh data
-    pub async fn rebuild_cache(&mut self, cache_dir: &Path) -> Result<(), LocateStationError> {
-        let cache_file = cache_dir.join(RKYV_CACHE_FILE_NAME);
-        if cache_file.exists() {
-            remove_file(&cache_file)
-                .map_err(|e| LocateStationError::CacheWrite(cache_file.clone(), e))?;
-        }
-        let stations = Self::fetch_stations().await?;
-        Self::cache_stations(stations.clone(), &cache_file).await?;
-        self.rtree = RTree::bulk_load(stations);
-        Ok(())
+    /// Checks that the cached station list exists and holds valid data in the current
+    /// format version, without deserializing it.
+    pub async fn is_cache_valid(cache: &dyn CacheBackend) -> bool {
+        let Ok(Some(bytes)) = cache.read(RKYV_CACHE_FILE_NAME).await else {
+            return false;
+        };
+        tokio::task::spawn_blocking(move || {
+            Self::cache_payload(&bytes).is_some_and(|payload| {
+                rkyv::access::<rkyv::Archived<Vec<Station>>, rkyv::rancor::Error>(&payload).is_ok()
+            })
+        })
+        .await
+        .unwrap_or(false)
+    }
+
+    /// Checks whether the station list server answers a lightweight `HEAD` request.
+    pub async fn is_server_reachable(&self, timeout: Duration) -> bool {
+        let Ok(client) = Client::builder().timeout(timeout).build() else {
+            return false;
+        };
+        client
+            .head(&self.data_url)
+            .send()
+            .await
+            .is_ok_and(|response| response.status().is_success())
+    }
+
+    /// The number of stations in the loaded station list.
+    pub fn station_count(&self) -> usize {
+        self.stations.len()
+    }
+
+    /// The south-west and north-east corners of the box around all stations, as
+    /// `[latitude, longitude]`, or `None` if no stations are loaded.
+    pub fn station_bounds(&self) -> Option<([f64; 2], [f64; 2])> {
+        self.stations
+            .iter()
+            .map(|station| [station.location.latitude, station.location.longitude])
+            .fold(None, |bounds, point| {
+                let (lower, upper) = bounds.unwrap_or((point, point));
+                Some((
+                    [lower[0].min(point[0]), lower[1].min(point[1])],
+                    [upper[0].max(point[0]), upper[1].max(point[1])],
+                ))
+            })
     }
 
     // --- End Caching/Fetching ---
 
-    /// Finds up to N nearest stations matching the criteria. Uses a fast path for simple
-    /// proximity queries and a heap-based approach with heuristic limits for filtered queries.
+    /// Looks up a station by its Meteostat ID.
+    pub fn get_station(&self, id: &str) -> Option<&Station> {
+        self.id_index.get(id).map(|&index| &self.stations[index])
+    }
+
+    /// Looks up a station by its ICAO code (case-insensitive).
+    pub fn get_station_by_icao(&self, code: &str) -> Option<&Station> {
+        self.icao_index
+            .get(&code.trim().to_uppercase())
+            .and_then(|id| self.get_station(id))
+    }
+
+    /// Looks up a station by its WMO identifier.
+    pub fn get_station_by_wmo(&self, code: &str) -> Option<&Station> {
+        self.wmo_index
+            .get(code.trim())
+            .and_then(|id| self.get_station(id))
+    }
+
+    /// Distance in kilometers between two stations, using the same metric as `query`.
+    /// `None` if either ID is unknown.
+    pub fn distance_between(&self, a: &str, b: &str) -> Option<f64> {
+        let a = self.get_station(a)?;
+        self.distance_to(b, a.location.latitude, a.location.longitude)
+    }
+
+    /// Distance in kilometers from a station to a point, using the same metric as `query`.
+    /// `None` if the ID is unknown.
+    pub fn distance_to(&self, id: &str, latitude: f64, longitude: f64) -> Option<f64> {
+        let station = self.get_station(id)?;
+        // Same argument order as `query`, so the results match to the last bit
+        Some(self.distance_metric.distance_km(
+            (latitude, longitude),
+            (station.location.latitude, station.location.longitude),
+        ))
+    }
+
+    /// Collapses stations lying within `within_km` of each other into a single one, e.g. the
+    /// old and new ID of a relocated station.
+    ///
+    /// Of each group, the station with the best reported inventory is kept: the best
+    /// coverage of `required_data` for `frequency`, then the most years reported for
+    /// `frequency` (or for all frequencies without one). Remaining ties go to the station
+    /// listed first, i.e. the closest for results of `query`, so the result is
+    /// deterministic. The kept stations are returned in their original order.
+    pub fn dedupe(
+        &self,
+        stations: Vec<(Station, f64)>,
+        within_km: f64,
+        frequency: Option<Frequency>,
+        required_data: Option<&RequiredData>,
+    ) -> Vec<(Station, f64)> {
+        let score = |station: &Station| {
+            let coverage = frequency.map_or(0, |frequency| {
+                match station
+                    .inventory
+                    .coverage(frequency, required_data.unwrap_or(&RequiredData::Any))
+                {
+                    Coverage::Full => 2,
+                    Coverage::Partial => 1,
+                    Coverage::None => 0,
+                }
+            });
+            let years: i32 = frequency
+                .as_ref()
+                .map_or(&Frequency::ALL[..], std::slice::from_ref)
+                .iter()
+                .filter_map(|frequency| station.inventory.years(*frequency))
+                .map(|(start, end)| end - start + 1)
+                .sum();
+            (coverage, years)
+        };
+        let mut ranked: Vec<(usize, (Station, f64))> = stations.into_iter().enumerate().collect();
+        // Stable, so equally scored stations keep their order
+        ranked.sort_by_cached_key(|(_, (station, _))| std::cmp::Reverse(score(station)));
+
+        let mut kept: Vec<(usize, (Station, f64))> = Vec::new();
+        for (index, (station, distance)) in ranked {
+            let near_kept = kept.iter().any(|(_, (other, _))| {
+                self.distance_metric.distance_km(
+                    (other.location.latitude, other.location.longitude),
+                    (station.location.latitude, station.location.longitude),
+                ) < within_km
+            });
+            if !near_kept {
+                kept.push((index, (station, distance)));
+            }
+        }
+        kept.sort_by_key(|(index, _)| *index);
+        kept.into_iter().map(|(_, station)| station).collect()
+    }
+
+    /// Searches stations by name across all languages in [`Station::name`].
+    ///
+    /// Matching is case-insensitive. Exact matches rank first, followed by prefix matches,
+    /// word-prefix matches, substring matches and finally fuzzy (in-order subsequence) matches.
+    /// Ties are broken by the length of the matched name, then by station ID.
+    pub fn search_by_name(&self, query: &str, limit: usize) -> Vec<Station> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() || limit == 0 {
+            return vec![];
+        }
+
+        let mut matches: Vec<((u8, usize), &Station)> = self
+            .stations
+            .iter()
+            .filter_map(|station| {
+                station
+                    .name
+                    .values()
+                    .filter_map(|name| {
+                        let name = name.to_lowercase();
+                        name_match_score(&name, &query).map(|score| (score, name.len()))
+                    })
+                    .min()
+                    .map(|best| (best, station))
+            })
+            .collect();
+
+        matches.sort_by(|(a_score, a), (b_score, b)| a_score.cmp(b_score).then(a.id.cmp(&b.id)));
+        matches.truncate(limit);
+        matches.into_iter().map(|(_, s)| s.to_owned()).collect()
+    }
+
+    /// Finds up to N nearest stations matching the criteria.
+    ///
+    /// The R-tree visits stations in great-circle order, so by default the search stops once
+    /// enough stations are found. That is exact for the haversine metric; the Vincenty metric
+    /// can rank stations at nearly the same distance slightly differently. With `exact`, every
+    /// station within `max_distance_km` is measured with the configured metric instead.
+    ///
+    /// With `include_unverified`, stations without an inventory range for `frequency` pass
+    /// the inventory filter instead of being excluded.
+    #[allow(clippy::too_many_arguments)]
     pub fn query(
         &self,
         latitude: f64,
@@ -183,26 +569,30 @@ impl StationLocator {
         max_distance_km: f64,
         frequency: Option<Frequency>,
         required_data: Option<RequiredData>,
+        include_unverified: bool,
+        exact: bool,
     ) -> Vec<(Station, f64)> {
         if n_results == 0 {
             return vec![];
         }
 
         // --- Fast path: If no inventory filters are applied, use a simpler, faster method ---
-        if frequency.is_none() {
+        if frequency.is_none() && !exact {
             // required_date is ignored if frequency is None by station_meets_criteria,
             // so we can reliably use the fast path here.
             return self.fast_proximity_query(latitude, longitude, n_results, max_distance_km);
         }
 
-        // --- Filtered path: Use heap ---
+        // --- Filtered or exact path: Use heap ---
         self.filtered_heap_query(
             latitude,
             longitude,
             n_results,
             max_distance_km,
-            frequency.unwrap(),
+            frequency,
             required_data,
+            include_unverified,
+            exact,
         )
     }
 
@@ -215,29 +605,22 @@ impl StationLocator {
         n_results: usize,
         max_distance_km: f64,
     ) -> Vec<(Station, f64)> {
-        let query_point_rtree = [latitude, longitude];
+        let query_point_rtree = unit_vector(latitude, longitude);
 
         // Heuristic limit: Take slightly more than needed to account for distance filtering
-        // and Haversine vs R-tree distance differences.
+        // and metric vs R-tree distance differences.
         let candidate_limit = (n_results * 2).max(20); // Check at least 20 or 2x n_results
 
         let mut stations_with_dist: Vec<(Station, f64)> = self
             .rtree
             .nearest_neighbor_iter(query_point_rtree)
             .take(candidate_limit)
+            .map(|point| &self.stations[point.data])
             .filter_map(|station| {
-                // Use filter_map for combined Haversine calc + distance filter
-                let station_loc = HaversineLocation {
-                    latitude: station.location.latitude,
-                    longitude: station.location.longitude,
-                };
-                let dist_km = distance(
-                    HaversineLocation {
-                        latitude,
-                        longitude,
-                    },
-                    station_loc,
-                    Units::Kilometers,
+                // Use filter_map for combined distance calc + distance filter
+                let dist_km = self.distance_metric.distance_km(
+                    (latitude, longitude),
+                    (station.location.latitude, station.location.longitude),
                 );
 
                 if dist_km <= max_distance_km {
@@ -256,53 +639,54 @@ impl StationLocator {
         stations_with_dist
     }
 
-    /// Query using `BinaryHeap` for filtering.
+    /// Query using `BinaryHeap` for filtering. Without a `frequency`, every station in range
+    /// qualifies.
+    ///
+    /// Stations are visited nearest first, so the search stops at the first `n_results`
+    /// qualifying stations, or with `exact` at the edge of `max_distance_km`.
+    #[allow(clippy::too_many_arguments)]
     fn filtered_heap_query(
         &self,
         latitude: f64,
         longitude: f64,
         n_results: usize,
         max_distance_km: f64,
-        frequency: Frequency,
+        frequency: Option<Frequency>,
         required_date: Option<RequiredData>,
+        include_unverified: bool,
+        exact: bool,
     ) -> Vec<(Station, f64)> {
-        let query_point_rtree = [latitude, longitude];
-        let mut heap: BinaryHeap<StationCandidate<'_>> = BinaryHeap::with_capacity(n_results);
-
-        // Heuristic limit for filtered queries. Might need tuning.
-        // A larger iteration_limit increases chance of correctness but potentially slows down.
-        let iteration_limit = n_results + 3;
-        let mut items_checked = 0;
-
-        for station in self.rtree.nearest_neighbor_iter(query_point_rtree) {
-            items_checked += 1;
+        let mut heap: BinaryHeap<StationCandidate<'_>> =
+            BinaryHeap::with_capacity(n_results.min(self.rtree.size()));
 
+        let query_point_rtree = unit_vector(latitude, longitude);
+        let max_distance_2 = Self::search_radius_2(max_distance_km);
+        for (point, distance_2) in self
+            .rtree
+            .nearest_neighbor_iter_with_distance_2(query_point_rtree)
+        {
+            if distance_2 > max_distance_2 {
+                // Every following station is farther away
+                break;
+            }
+            let station = &self.stations[point.data];
             // --- Check inventory criteria (relatively cheap) ---
-            if !Self::station_meets_criteria(station, Some(frequency), required_date.as_ref()) {
+            if !Self::station_meets_criteria(
+                station,
+                frequency,
+                required_date.as_ref(),
+                include_unverified,
+            ) {
                 continue;
             }
 
-            // --- Calculate Haversine distance (more expensive) ---
-            let station_loc = HaversineLocation {
-                latitude: station.location.latitude,
-                longitude: station.location.longitude,
-            };
-            let dist_km = distance(
-                HaversineLocation {
-                    latitude,
-                    longitude,
-                },
-                station_loc,
-                Units::Kilometers,
+            // --- Calculate distance with the configured metric (more expensive) ---
+            let dist_km = self.distance_metric.distance_km(
+                (latitude, longitude),
+                (station.location.latitude, station.location.longitude),
             );
-
-            // --- Check max distance ---
-            if dist_km > max_distance_km * 2.0 {
-                // It's Joever.
-                break;
-            }
             if dist_km > max_distance_km {
-                // Slight chance one of the next stations will be within range.
+                // Within the search radius, but out of range under the configured metric
                 continue;
             }
 
@@ -322,11 +706,9 @@ impl StationLocator {
                 }
             }
 
-            // Heuristic Early Exit Check ---
-            // If we have checked enough items and the heap is full,
-            // assume we are unlikely to find a better candidate later.
-            // This is the key performance optimization for filtered queries.
-            if items_checked >= iteration_limit && heap.len() == n_results {
+            // Early exit: later stations are farther away on the sphere. Exact mode keeps
+            // measuring, in case the configured metric ranks them differently.
+            if !exact && heap.len() == n_results {
                 break;
             }
         } // End R-tree iteration
@@ -341,35 +723,54 @@ impl StationLocator {
         results
     }
 
+    /// The squared straight-line distance on the unit sphere (see [`unit_vector`]) that
+    /// holds every point within `max_distance_km`.
+    fn search_radius_2(max_distance_km: f64) -> f64 {
+        let angular_radius = (max_distance_km / SEARCH_EARTH_RADIUS_KM).min(PI);
+        let chord = 2.0 * (angular_radius / 2.0).sin();
+        chord * chord
+    }
+
     // --- Inventory check helpers ---
+    // A missing inventory range means "unverified": rejected unless `include_unverified` is set.
     fn station_meets_criteria(
         station: &Station,
         frequency: Option<Frequency>,
         required_date: Option<&RequiredData>,
+        include_unverified: bool,
     ) -> bool {
         let Some(freq) = frequency else { return true };
         let req_date = required_date.unwrap_or(&RequiredData::Any);
         match freq {
-            Frequency::Daily => {
-                Self::check_date_range_inventory(&station.inventory.daily, req_date)
-            }
-            Frequency::Hourly => {
-                Self::check_date_range_inventory(&station.inventory.hourly, req_date)
-            }
-            Frequency::Monthly => {
-                Self::check_year_range_inventory(&station.inventory.monthly, req_date)
-            }
-            Frequency::Climate => {
-                Self::check_year_range_inventory(&station.inventory.normals, req_date)
-            }
+            Frequency::Daily => Self::check_date_range_inventory(
+                &station.inventory.daily,
+                req_date,
+                include_unverified,
+            ),
+            Frequency::Hourly => Self::check_date_range_inventory(
+                &station.inventory.hourly,
+                req_date,
+                include_unverified,
+            ),
+            Frequency::Monthly => Self::check_year_range_inventory(
+                &station.inventory.monthly,
+                req_date,
+                include_unverified,
+            ),
+            Frequency::Climate => Self::check_year_range_inventory(
+                &station.inventory.normals,
+                req_date,
+                include_unverified,
+            ),
         }
     }
     fn check_date_range_inventory(
         inventory_range: &DateRange,
         required_date: &RequiredData,
+        include_unverified: bool,
     ) -> bool {
         let (Some(inv_start), Some(inv_end)) = (inventory_range.start, inventory_range.end) else {
-            return false;
+            return include_unverified;
         };
         match required_date {
             RequiredData::Any => true,
@@ -387,15 +788,22 @@ impl StationLocator {
                 };
                 inv_start <= req_start && inv_end >= req_end
             }
+            RequiredData::MonthCoverage { year, month } => {
+                let Some((req_start, req_end)) = month_bounds(*year, *month) else {
+                    return false;
+                };
+                inv_start <= req_start && inv_end >= req_end
+            }
         }
     }
     fn check_year_range_inventory(
         inventory_range: &YearRange,
         required_date: &RequiredData,
+        include_unverified: bool,
     ) -> bool {
         let (Some(inv_start_y), Some(inv_end_y)) = (inventory_range.start, inventory_range.end)
         else {
-            return false;
+            return include_unverified;
         };
         match required_date {
             RequiredData::Any => true,
@@ -415,10 +823,38 @@ impl StationLocator {
                 let req_y = *year;
                 inv_start_y <= req_y && req_y <= inv_end_y
             }
+            RequiredData::MonthCoverage { year, month } => {
+                (1..=12).contains(month) && inv_start_y <= *year && *year <= inv_end_y
+            }
         }
     }
 }
 
+/// Scores how well a lowercase station name matches a lowercase query. Lower is better,
+/// `None` means no match at all.
+fn name_match_score(name: &str, query: &str) -> Option<u8> {
+    if name == query {
+        Some(0)
+    } else if name.starts_with(query) {
+        Some(1)
+    } else if name
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|word| word.starts_with(query))
+    {
+        Some(2)
+    } else if name.contains(query) {
+        Some(3)
+    } else {
+        // Fuzzy: every query character appears in the name, in order
+        let mut name_chars = name.chars();
+        query
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .all(|qc| name_chars.any(|nc| nc == qc))
+            .then_some(4)
+    }
+}
+
 // --- Tests Module ---
 #[cfg(test)]
 mod tests {
@@ -426,6 +862,7 @@ mod tests {
     use crate::types::frequency::{Frequency, RequiredData};
     use crate::types::station::Station;
     use crate::utils::get_cache_dir;
+    use crate::FileSystemCache;
     use chrono::{Datelike, NaiveDate};
 
     async fn get_locator() -> Result<StationLocator, LocateStationError> {
@@ -433,9 +870,13 @@ mod tests {
         tokio::fs::create_dir_all(&cache_path)
             .await
             .expect("Failed to create cache dir");
-        Ok(StationLocator::new(&cache_path)
-            .await
-            .expect("Failed to initialize StationLocator"))
+        Ok(StationLocator::new(
+            &FileSystemCache::new(cache_path),
+            DEFAULT_STATION_DATA_URL,
+            Arc::new(Semaphore::new(1)),
+        )
+        .await
+        .expect("Failed to initialize StationLocator"))
     }
 
     fn validate_results(results: &[(Station, f64)], expected_max_len: usize, max_distance_km: f64) {
@@ -474,7 +915,7 @@ mod tests {
         let lon = -74.0060;
         let n = 5;
         let max_d = 100.0;
-        let results = locator.query(lat, lon, n, max_d, None, None);
+        let results = locator.query(lat, lon, n, max_d, None, None, false, false);
         println!(
             "Basic Query (NYC): Found {} results (max {}) within {} km",
             results.len(),
@@ -493,7 +934,7 @@ mod tests {
         let max_d = 150.0;
         let freq = Some(Frequency::Daily);
         let req_date = Some(RequiredData::Any);
-        let results = locator.query(lat, lon, n, max_d, freq, req_date);
+        let results = locator.query(lat, lon, n, max_d, freq, req_date, false, false);
         println!(
             "Frequency Query (Berlin, Daily, Any): Found {} results (max {}) within {} km",
             results.len(),
@@ -516,7 +957,7 @@ mod tests {
         let freq = Some(Frequency::Hourly);
         let specific_date = NaiveDate::from_ymd_opt(2022, 1, 15).unwrap();
         let req_date = Some(RequiredData::SpecificDate(specific_date));
-        let results = locator.query(lat, lon, n, max_d, freq, req_date);
+        let results = locator.query(lat, lon, n, max_d, freq, req_date, false, false);
         println!(
             "Frequency+Date Query (LA, Hourly, {}): Found {} results (max {}) within {} km",
             specific_date,
@@ -549,7 +990,7 @@ mod tests {
             start: start_date,
             end: end_date,
         });
-        let results = locator.query(lat, lon, n, max_d, freq, req_date);
+        let results = locator.query(lat, lon, n, max_d, freq, req_date, false, false);
         println!(
             "Frequency+Range Query (Paris, Monthly, {}-{}): Found {} results (max {}) within {} km",
             start_date.year(),
@@ -577,7 +1018,7 @@ mod tests {
         let max_d = 300.0;
         let freq = Some(Frequency::Climate);
         let req_date = Some(RequiredData::Any);
-        let results = locator.query(lat, lon, n, max_d, freq, req_date);
+        let results = locator.query(lat, lon, n, max_d, freq, req_date, false, false);
         println!(
             "Climate Query (Sydney, Any): Found {} results (max {}) within {} km",
             results.len(),
@@ -594,7 +1035,7 @@ mod tests {
         let lon = 0.0;
         let n = 5;
         let max_d = 1.0;
-        let results = locator.query(lat, lon, n, max_d, None, None);
+        let results = locator.query(lat, lon, n, max_d, None, None, false, false);
         println!(
             "No Results Query (0,0): Found {} results (max {}) within {} km",
             results.len(),
@@ -605,6 +1046,339 @@ mod tests {
         assert!(results.is_empty());
         Ok(())
     }
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_station_matches_tree() -> Result<(), LocateStationError> {
+        let locator = get_locator().await?;
+        let results = locator.query(52.5200, 13.4050, 3, 100.0, None, None, false, false);
+        for (station, _) in &results {
+            assert_eq!(locator.get_station(&station.id), Some(station));
+        }
+        assert!(locator.get_station("INVALID_STATION_ID_123").is_none());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_station_by_alternate_ids() -> Result<(), LocateStationError> {
+        let locator = get_locator().await?;
+        let heathrow = locator
+            .get_station_by_icao("egll")
+            .expect("EGLL should be in the station list");
+        assert_eq!(heathrow.icao(), Some("EGLL"));
+        if let Some(wmo) = heathrow.wmo() {
+            assert_eq!(locator.get_station_by_wmo(wmo), Some(heathrow));
+        }
+        assert!(locator.get_station_by_icao("ZZZZ").is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cache_validators_round_trip() -> Result<(), LocateStationError> {
+        let temp_dir = tempfile::tempdir()?;
+        let cache = FileSystemCache::new(temp_dir.path());
+        let path = temp_dir.path().join(VALIDATORS_FILE_NAME);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, "\"abc123\"".parse().unwrap());
+        let validators = CacheValidators::from_headers(&headers);
+        assert_eq!(validators.etag.as_deref(), Some("\"abc123\""));
+        assert_eq!(validators.last_modified, None);
+
+        validators.write(&cache).await?;
+        assert_eq!(CacheValidators::read(&cache).await, Some(validators));
+
+        // A response without validators removes the stale ones
+        CacheValidators::default().write(&cache).await?;
+        assert!(!path.exists());
+        assert_eq!(CacheValidators::read(&cache).await, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_is_cache_valid_rejects_missing_and_corrupt_files(
+    ) -> Result<(), LocateStationError> {
+        let temp_dir = tempfile::tempdir()?;
+        let cache = FileSystemCache::new(temp_dir.path());
+        assert!(!StationLocator::is_cache_valid(&cache).await);
+
+        std::fs::write(temp_dir.path().join(RKYV_CACHE_FILE_NAME), b"not rkyv data")?;
+        assert!(!StationLocator::is_cache_valid(&cache).await);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cache_with_other_format_version_is_rebuilt() -> Result<(), LocateStationError> {
+        let cache = crate::MemoryCache::new();
+        // Unreachable, so a rebuild shows up as a failed download
+        let unreachable = "http://127.0.0.1:9/stations.json.gz";
+        let permits = || Arc::new(Semaphore::new(1));
+        let stations = vec![station_at("10637", 50.05, 8.6, (None, None))];
+
+        StationLocator::cache_stations(stations.clone(), &cache).await?;
+        assert!(StationLocator::is_cache_valid(&cache).await);
+        let locator = StationLocator::new(&cache, unreachable, permits()).await?;
+        assert!(locator.get_station("10637").is_some());
+
+        // Without the header, as written before it existed, and with another version
+        let rkyv_data = rkyv::to_bytes::<rkyv::rancor::Error>(&stations)
+            .map_err(LocateStationError::CacheEncode)?;
+        let mut other_version = STATION_CACHE_MAGIC.to_vec();
+        other_version.extend_from_slice(&(STATION_CACHE_FORMAT_VERSION + 1).to_le_bytes());
+        other_version.extend_from_slice(&rkyv_data);
+        for bytes in [rkyv_data.to_vec(), other_version] {
+            cache.write(RKYV_CACHE_FILE_NAME, bytes).await?;
+            assert!(!StationLocator::is_cache_valid(&cache).await);
+            assert!(matches!(
+                StationLocator::new(&cache, unreachable, permits()).await,
+                Err(LocateStationError::NetworkRequest(..))
+            ));
+        }
+        Ok(())
+    }
+
+    /// Builds a station with the given daily inventory and no other inventory.
+    fn station_at(
+        id: &str,
+        latitude: f64,
+        longitude: f64,
+        daily: (Option<&str>, Option<&str>),
+    ) -> Station {
+        let null_range = serde_json::json!({ "start": null, "end": null });
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "country": "XX",
+            "region": null,
+            "timezone": null,
+            "name": { "en": id },
+            "identifiers": { "national": null, "wmo": null, "icao": null },
+            "location": { "latitude": latitude, "longitude": longitude, "elevation": null },
+            "inventory": {
+                "daily": { "start": daily.0, "end": daily.1 },
+                "hourly": null_range,
+                "model": null_range,
+                "monthly": null_range,
+                "normals": null_range,
+            },
+        }))
+        .expect("Failed to build test station")
+    }
+
+    fn locator_from(stations: Vec<Station>) -> StationLocator {
+        StationLocator::from_stations(
+            stations,
+            DEFAULT_STATION_DATA_URL.to_string(),
+            Arc::new(Semaphore::new(1)),
+        )
+    }
+
+    #[test]
+    fn test_dedupe_keeps_best_covered_station_per_location() {
+        let locator = locator_from(vec![
+            station_at(
+                "NEW01",
+                50.0,
+                10.0,
+                (Some("2000-01-01"), Some("2024-12-31")),
+            ),
+            station_at(
+                "OLD01",
+                50.001,
+                10.0,
+                (Some("1950-01-01"), Some("2000-12-31")),
+            ),
+            station_at("FAR01", 50.1, 10.0, (None, None)),
+        ]);
+        let ids = |stations: Vec<(Station, f64)>| -> Vec<String> {
+            stations
+                .into_iter()
+                .map(|(station, _)| station.id)
+                .collect()
+        };
+        let nearby = locator.query(50.0, 10.0, 10, 50.0, None, None, false, true);
+
+        // Without a request, the station reporting the most years wins
+        let deduped = locator.dedupe(nearby.clone(), 1.0, None, None);
+        assert_eq!(ids(deduped), vec!["OLD01", "FAR01"]);
+        // Coverage of the requested period takes precedence over the number of years
+        let recent = RequiredData::FullYear(2023);
+        let deduped = locator.dedupe(nearby.clone(), 1.0, Some(Frequency::Daily), Some(&recent));
+        assert_eq!(ids(deduped), vec!["NEW01", "FAR01"]);
+        // A threshold below the distance between the stations keeps all of them
+        assert_eq!(locator.dedupe(nearby, 0.05, None, None).len(), 3);
+    }
+
+    #[test]
+    fn test_distances_match_query_metric() {
+        let stations = vec![
+            station_at("AMS01", 52.3676, 4.9041, (None, None)),
+            station_at("BER01", 52.5200, 13.4050, (None, None)),
+        ];
+        for metric in [DistanceMetric::Haversine, DistanceMetric::Vincenty] {
+            let locator = locator_from(stations.clone()).with_distance_metric(metric);
+            let expected = metric.distance_km((52.3676, 4.9041), (52.5200, 13.4050));
+
+            assert_eq!(locator.distance_between("AMS01", "BER01"), Some(expected));
+            assert_eq!(
+                locator.distance_to("BER01", 52.3676, 4.9041),
+                Some(expected)
+            );
+            let (nearest, queried) =
+                locator.query(50.0, 10.0, 1, 1000.0, None, None, false, true)[0].clone();
+            assert_eq!(locator.distance_to(&nearest.id, 50.0, 10.0), Some(queried));
+        }
+        let locator = locator_from(stations);
+        assert_eq!(locator.distance_between("AMS01", "XXXXX"), None);
+        assert_eq!(locator.distance_to("XXXXX", 0.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_query_include_unverified_stations() {
+        let locator = locator_from(vec![station_at("NEW01", 52.5, 13.4, (None, None))]);
+
+        let query = |include_unverified| {
+            locator.query(
+                52.5,
+                13.4,
+                5,
+                10.0,
+                Some(Frequency::Daily),
+                Some(RequiredData::Any),
+                include_unverified,
+                false,
+            )
+        };
+        assert!(query(false).is_empty());
+        let results = query(true);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.id, "NEW01");
+    }
+
+    #[test]
+    fn test_filtered_query_finds_closest_beyond_degree_neighbours() {
+        let daily = (Some("2000-01-01"), Some("2020-12-31"));
+        // At 70°N a degree of longitude is only ~38 km, so EAST is the closest qualifying
+        // station (~46 km) even though NORTH (~56 km) and several stations without daily
+        // data are nearer in degree space.
+        let mut stations: Vec<Station> = (1..=5)
+            .map(|i| {
+                station_at(
+                    &format!("NODATA{i}"),
+                    70.0 - 0.08 * f64::from(i),
+                    20.0,
+                    (None, None),
+                )
+            })
+            .collect();
+        stations.push(station_at("NORTH", 70.5, 20.0, daily));
+        stations.push(station_at("EAST", 70.0, 21.2, daily));
+        let locator = locator_from(stations);
+
+        let results = locator.query(
+            70.0,
+            20.0,
+            1,
+            100.0,
+            Some(Frequency::Daily),
+            Some(RequiredData::Any),
+            false,
+            false,
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.id, "EAST");
+
+        // Near the antimeridian the search wraps around instead of cutting off
+        let locator = locator_from(vec![station_at("WEST", 10.0, -179.9, daily)]);
+        let results = locator.query(
+            10.0,
+            179.9,
+            1,
+            50.0,
+            Some(Frequency::Daily),
+            Some(RequiredData::Any),
+            false,
+            false,
+        );
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_exact_unfiltered_query_finds_closest_station() {
+        // At 80°N two degrees of longitude are ~39 km, closer than any of the 30 stations
+        // spaced along the meridian, which are nearer in degree space. Both modes find it.
+        let mut stations: Vec<Station> = (0..30)
+            .map(|i| {
+                let offset = 0.4 + 0.05 * f64::from(i);
+                station_at(&format!("MERIDIAN{i}"), 80.0 - offset, 20.0, (None, None))
+            })
+            .collect();
+        stations.push(station_at("EAST", 80.0, 22.0, (None, None)));
+        let locator = locator_from(stations);
+
+        let query = |exact| locator.query(80.0, 20.0, 1, 300.0, None, None, false, exact);
+        for exact in [false, true] {
+            let results = query(exact);
+            assert_eq!(results[0].0.id, "EAST");
+            assert!((results[0].1 - 38.6).abs() < 0.5);
+        }
+    }
+
+    #[test]
+    fn test_month_coverage_requires_whole_month() {
+        let full = station_at("FULL", 52.0, 13.0, (Some("2021-08-01"), Some("2021-08-31")));
+        let partial = station_at("PART", 52.0, 13.0, (Some("2021-08-02"), Some("2023-01-01")));
+        let august = RequiredData::MonthCoverage {
+            year: 2021,
+            month: 8,
+        };
+        let meets = |station: &Station, required: RequiredData| {
+            StationLocator::station_meets_criteria(
+                station,
+                Some(Frequency::Daily),
+                Some(&required),
+                false,
+            )
+        };
+
+        assert!(meets(&full, august));
+        assert!(!meets(&partial, august));
+        assert!(meets(
+            &partial,
+            RequiredData::MonthCoverage {
+                year: 2021,
+                month: 12
+            }
+        ));
+        assert!(!meets(
+            &full,
+            RequiredData::MonthCoverage {
+                year: 2021,
+                month: 13
+            }
+        ));
+    }
+
+    #[test]
+    fn test_name_match_score_ordering() {
+        assert_eq!(name_match_score("heathrow", "heathrow"), Some(0));
+        assert_eq!(name_match_score("heathrow airport", "heathrow"), Some(1));
+        assert_eq!(name_match_score("london / heathrow", "heath"), Some(2));
+        assert_eq!(name_match_score("london-heathrow", "throw"), Some(3));
+        assert_eq!(name_match_score("london heathrow", "lndhthrw"), Some(4));
+        assert_eq!(name_match_score("gatwick", "heathrow"), None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_search_by_name() -> Result<(), LocateStationError> {
+        let locator = get_locator().await?;
+        let results = locator.search_by_name("heathrow", 5);
+        assert!(!results.is_empty() && results.len() <= 5);
+        assert!(results[0]
+            .name
+            .values()
+            .any(|name| name.to_lowercase().contains("heathrow")));
+        assert!(locator.search_by_name("   ", 5).is_empty());
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_query_n_results_zero() -> Result<(), LocateStationError> {
         let locator = get_locator().await?;
@@ -612,7 +1386,7 @@ mod tests {
         let lon = -74.0060;
         let n = 0;
         let max_d = 500.0;
-        let results = locator.query(lat, lon, n, max_d, None, None);
+        let results = locator.query(lat, lon, n, max_d, None, None, false, false);
         println!(
             "Zero Results Query (NYC): Found {} results (max {}) within {} km",
             results.len(),
@@ -632,7 +1406,7 @@ mod tests {
         let freq = Some(Frequency::Daily);
         let specific_date = NaiveDate::from_ymd_opt(2099, 12, 31).unwrap();
         let req_date = Some(RequiredData::SpecificDate(specific_date));
-        let results = locator.query(lat, lon, n, max_d, freq, req_date);
+        let results = locator.query(lat, lon, n, max_d, freq, req_date, false, false);
         println!(
             "Date Outside Range Query (London, Daily, {}): Found {} results (max {}) within {} km",
             specific_date,
@@ -0,0 +1,43 @@
+//! Defines the `CsvOptions` used to read Meteostat CSV files that deviate from the format.
+
+use serde::{Deserialize, Serialize};
+
+/// Escape hatches for reading Meteostat CSV files that deviate from the documented format.
+///
+/// Everything is off by default, so a file that doesn't match the format fails to parse
+/// with an error naming the station and quoting the offending line. Pass these options to
+/// the builder with `.csv_options(..)` to read such files anyway.
+///
+/// # Example
+///
+/// ```no_run
+/// use meteostat::{CsvOptions, Meteostat, MeteostatError};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), MeteostatError> {
+/// let client = Meteostat::builder()
+///     .csv_options(CsvOptions {
+///         ignore_extra_columns: true,
+///         null_values: vec!["NA".to_string()],
+///         ..CsvOptions::default()
+///     })
+///     .build()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct CsvOptions {
+    /// Drop columns beyond the ones of the frequency's format instead of failing, for the
+    /// whole file as well as for single rows with stray fields. The optional trailing
+    /// `source` column is only recognized when it is the only additional column.
+    pub ignore_extra_columns: bool,
+    /// Skip the first line of the file, for files that unexpectedly start with a header
+    /// row.
+    pub skip_header: bool,
+    /// Read values that don't fit their column's type (e.g. text in a numeric column) as
+    /// null, instead of failing the whole file.
+    pub flexible_schema: bool,
+    /// Values read as null in addition to empty fields, e.g. `"NA"` or `"-9999"`.
+    pub null_values: Vec<String>,
+}
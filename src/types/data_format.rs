@@ -0,0 +1,114 @@
+//! Defines the `DataFormat` of the weather data files downloaded from the bulk server, and
+//! the `DataSource`s weather data is loaded from.
+
+use std::fmt;
+
+/// The format of the weather data files downloaded from the bulk server.
+///
+/// Both formats are parsed into the same frame schema, so the format doesn't matter once
+/// the data is loaded. Set it with [`crate::Meteostat::with_data_format`].
+///
+/// Meteostat's own bulk server only publishes CSV files. [`DataFormat::Json`] is meant for
+/// self-hosted mirrors, set with `.bulk_data_base_url(..)` on the builder; against the
+/// default server every JSON download fails as not found.
+///
+/// # Example
+///
+/// ```no_run
+/// use meteostat::{DataFormat, Meteostat, MeteostatError};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), MeteostatError> {
+/// let client = Meteostat::builder()
+///     .bulk_data_base_url("https://weather-mirror.example.com")
+///     .build()
+///     .await?
+///     .with_data_format(DataFormat::Json);
+/// let daily = client.daily().station("10637").call().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DataFormat {
+    /// Gzipped CSV files without a header row, at `{frequency}/{station}.csv.gz`. This is
+    /// the format of Meteostat's bulk data.
+    #[default]
+    Csv,
+    /// Gzipped JSON files at `{frequency}/{station}.json.gz` on a self-hosted mirror (the
+    /// Meteostat bulk server doesn't publish these), holding an object whose
+    /// `data` array has one record per row, keyed by the column names of the frequency
+    /// (see [`crate::Frequency::columns`]). Missing keys and `null` values are read as
+    /// null, and unknown keys are ignored, except for the optional `source` column.
+    Json,
+}
+
+impl DataFormat {
+    /// The extension of the data files in this format.
+    pub(crate) const fn file_extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv.gz",
+            Self::Json => "json.gz",
+        }
+    }
+}
+
+/// A place weather data can be loaded from, tried in order by
+/// [`crate::Meteostat::with_source_fallback`].
+///
+/// # Example
+///
+/// ```no_run
+/// use meteostat::{DataSource, Meteostat, MeteostatError};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), MeteostatError> {
+/// // Prefer fresh downloads, and use the cache only when they fail
+/// let client = Meteostat::new()
+///     .await?
+///     .with_source_fallback(&[DataSource::Csv, DataSource::Cache]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataSource {
+    /// The Parquet file cached by an earlier download. A missing cache entry is not a
+    /// failure, the next source is simply tried.
+    Cache,
+    /// The bulk CSV file, see [`DataFormat::Csv`].
+    Csv,
+    /// The JSON file of a self-hosted mirror, see [`DataFormat::Json`].
+    Json,
+}
+
+impl DataSource {
+    /// The order used unless configured otherwise: the cache, then the bulk CSV file.
+    pub(crate) const DEFAULT_FALLBACK: [Self; 2] = [Self::Cache, Self::Csv];
+
+    /// The format downloaded by this source, or `None` for the cache.
+    pub(crate) const fn format(self) -> Option<DataFormat> {
+        match self {
+            Self::Cache => None,
+            Self::Csv => Some(DataFormat::Csv),
+            Self::Json => Some(DataFormat::Json),
+        }
+    }
+}
+
+impl From<DataFormat> for DataSource {
+    fn from(format: DataFormat) -> Self {
+        match format {
+            DataFormat::Csv => Self::Csv,
+            DataFormat::Json => Self::Json,
+        }
+    }
+}
+
+impl fmt::Display for DataSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cache => write!(f, "cache"),
+            Self::Csv => write!(f, "CSV download"),
+            Self::Json => write!(f, "JSON download"),
+        }
+    }
+}
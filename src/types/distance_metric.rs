@@ -0,0 +1,185 @@
+//! Defines the `DistanceMetric` enum, selecting how distances between a query point and
+//! weather stations are computed.
+
+use crate::LatLon;
+use haversine::{distance, Location as HaversineLocation, Units};
+use serde::{Deserialize, Serialize};
+
+/// WGS-84 semi-major axis in meters.
+const WGS84_A: f64 = 6_378_137.0;
+/// WGS-84 flattening.
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+/// WGS-84 semi-minor axis in meters.
+const WGS84_B: f64 = WGS84_A * (1.0 - WGS84_F);
+/// Iteration cap for Vincenty's inverse formula. It converges in a handful of iterations
+/// except for nearly antipodal points.
+const VINCENTY_MAX_ITERATIONS: usize = 200;
+/// Convergence threshold for lambda, about 0.06 mm on the ground.
+const VINCENTY_TOLERANCE: f64 = 1e-12;
+
+/// How the distance between a location and a station is computed during station searches.
+///
+/// The metric affects both the distances reported (e.g. [`crate::Meteostat::find_stations`])
+/// and the ranking of nearby stations.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+pub enum DistanceMetric {
+    /// Great-circle distance on a spherical Earth. Fast, with errors of up to about 0.5%.
+    #[default]
+    Haversine,
+    /// Geodesic distance on the WGS-84 ellipsoid using Vincenty's inverse formula.
+    /// Accurate to well under a meter, at a higher computational cost. Falls back to
+    /// haversine for nearly antipodal points, where the formula does not converge.
+    Vincenty,
+}
+
+impl DistanceMetric {
+    /// Computes the distance in kilometers between two points given in decimal degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Latitude and longitude of the first point.
+    /// * `to` - Latitude and longitude of the second point.
+    ///
+    /// # Returns
+    ///
+    /// The distance in kilometers according to this metric.
+    #[must_use]
+    pub fn distance_km(self, from: (f64, f64), to: (f64, f64)) -> f64 {
+        match self {
+            Self::Haversine => haversine_km(from, to),
+            Self::Vincenty => vincenty_km(from, to).unwrap_or_else(|| haversine_km(from, to)),
+        }
+    }
+}
+
+/// Computes the great-circle distance in kilometers between two points, with the exact
+/// computation the client uses for station distances under the default
+/// [`DistanceMetric::Haversine`].
+///
+/// Use it to label or compare distances client-side consistently with
+/// [`crate::Meteostat::find_stations`]. For a client built with another metric, use
+/// [`DistanceMetric::distance_km`] instead.
+///
+/// # Example
+///
+/// ```
+/// use meteostat::{distance_km, LatLon};
+///
+/// let amsterdam = LatLon(52.3676, 4.9041);
+/// let berlin = LatLon(52.5200, 13.4050);
+/// assert!((distance_km(amsterdam, berlin) - 577.0).abs() < 1.0);
+/// ```
+#[must_use]
+pub fn distance_km(a: LatLon, b: LatLon) -> f64 {
+    haversine_km((a.lat(), a.lon()), (b.lat(), b.lon()))
+}
+
+fn haversine_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    distance(
+        HaversineLocation {
+            latitude: from.0,
+            longitude: from.1,
+        },
+        HaversineLocation {
+            latitude: to.0,
+            longitude: to.1,
+        },
+        Units::Kilometers,
+    )
+}
+
+/// Vincenty's inverse formula on the WGS-84 ellipsoid. Returns `None` if it doesn't converge.
+///
+/// Written in the notation of Vincenty (1975) for easy comparison with the paper, rather than
+/// with fused multiply-adds.
+#[allow(clippy::suboptimal_flops)]
+fn vincenty_km(from: (f64, f64), to: (f64, f64)) -> Option<f64> {
+    let u1 = ((1.0 - WGS84_F) * from.0.to_radians().tan()).atan();
+    let u2 = ((1.0 - WGS84_F) * to.0.to_radians().tan()).atan();
+    let l = (to.1 - from.1).to_radians();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    for _ in 0..VINCENTY_MAX_ITERATIONS {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = (cos_u2 * sin_lambda).hypot(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+        if sin_sigma == 0.0 {
+            // Coincident points
+            return Some(0.0);
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+        // Both points on the equator: cos_sq_alpha is 0 and the term vanishes
+        let cos_2sigma_m = if cos_sq_alpha == 0.0 {
+            0.0
+        } else {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+        };
+        let c = WGS84_F / 16.0 * cos_sq_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos_sq_alpha));
+        let previous_lambda = lambda;
+        lambda = l
+            + (1.0 - c)
+                * WGS84_F
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m + c * cos_sigma * (2.0 * cos_2sigma_m.powi(2) - 1.0)));
+
+        if (lambda - previous_lambda).abs() < VINCENTY_TOLERANCE {
+            let u_sq = cos_sq_alpha * (WGS84_A.powi(2) - WGS84_B.powi(2)) / WGS84_B.powi(2);
+            let a =
+                1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+            let b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+            let delta_sigma = b
+                * sin_sigma
+                * (cos_2sigma_m
+                    + b / 4.0
+                        * (cos_sigma * (2.0 * cos_2sigma_m.powi(2) - 1.0)
+                            - b / 6.0
+                                * cos_2sigma_m
+                                * (4.0 * sin_sigma.powi(2) - 3.0)
+                                * (4.0 * cos_2sigma_m.powi(2) - 3.0)));
+            return Some(WGS84_B * a * (sigma - delta_sigma) / 1000.0);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vincenty_matches_reference_distance() {
+        // Flinders Peak to Buninyong, the classic example from Vincenty's paper: 54972.271 m
+        let flinders_peak = (-37.951_033_4, 144.424_867_9);
+        let buninyong = (-37.652_821_1, 143.926_495_5);
+        let km = DistanceMetric::Vincenty.distance_km(flinders_peak, buninyong);
+        assert!((km - 54.972_271).abs() < 1e-5, "got {km} km");
+    }
+
+    #[test]
+    fn test_metrics_agree_roughly_and_handle_edge_cases() {
+        let amsterdam = (52.3676, 4.9041);
+        let berlin = (52.5200, 13.4050);
+        let haversine = DistanceMetric::Haversine.distance_km(amsterdam, berlin);
+        let vincenty = DistanceMetric::Vincenty.distance_km(amsterdam, berlin);
+        assert!((haversine - vincenty).abs() / vincenty < 0.005);
+        assert_eq!(
+            distance_km(LatLon(amsterdam.0, amsterdam.1), LatLon(berlin.0, berlin.1)),
+            haversine
+        );
+
+        assert_eq!(DistanceMetric::Vincenty.distance_km(berlin, berlin), 0.0);
+        // Along the equator the ellipsoidal result is exact: a quarter of the circumference
+        let quarter = DistanceMetric::Vincenty.distance_km((0.0, 0.0), (0.0, 90.0));
+        assert!((quarter - WGS84_A * std::f64::consts::FRAC_PI_2 / 1000.0).abs() < 1e-6);
+        // Nearly antipodal points fall back to haversine instead of failing
+        assert!(DistanceMetric::Vincenty
+            .distance_km((0.0, 0.0), (0.5, 179.7))
+            .is_finite());
+    }
+}
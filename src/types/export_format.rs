@@ -0,0 +1,151 @@
+use crate::error::MeteostatError;
+use crate::types::frequency::Frequency;
+use polars::prelude::{CsvWriter, DataFrame, SerWriter};
+use std::fs::File;
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// File format used by [`crate::Meteostat::export_station`].
+///
+/// Every format bundles all exported frequencies of a station into a single file,
+/// with one entry per frequency named after it (`hourly`, `daily`, `monthly`, `normals`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ExportFormat {
+    /// A zip archive with one CSV file per frequency (e.g. `daily.csv`).
+    #[default]
+    ZipCsv,
+    /// An Excel workbook with one sheet per frequency.
+    ///
+    /// Only available with the `xlsx` feature. A sheet holds at most 1,048,576 rows, which
+    /// the hourly data of long-running stations can exceed; use [`ExportFormat::ZipCsv`] for those.
+    #[cfg(feature = "xlsx")]
+    Xlsx,
+}
+
+/// Writes the frames to `path` in the given format, one entry per frequency.
+pub fn write_export(
+    frames: &mut [(Frequency, DataFrame)],
+    path: &Path,
+    format: ExportFormat,
+) -> Result<(), MeteostatError> {
+    match format {
+        ExportFormat::ZipCsv => write_zip_csv(frames, path),
+        #[cfg(feature = "xlsx")]
+        ExportFormat::Xlsx => write_xlsx(frames, path),
+    }
+}
+
+fn write_zip_csv(frames: &mut [(Frequency, DataFrame)], path: &Path) -> Result<(), MeteostatError> {
+    let archive_error = |e| MeteostatError::ExportArchive(path.to_path_buf(), e);
+    let file = File::create(path).map_err(|e| MeteostatError::ExportIo(path.to_path_buf(), e))?;
+    let mut archive = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for (frequency, frame) in frames.iter_mut() {
+        archive
+            .start_file(format!("{frequency}.csv"), options)
+            .map_err(archive_error)?;
+        CsvWriter::new(&mut archive).finish(frame)?;
+    }
+    archive.finish().map_err(archive_error)?;
+    Ok(())
+}
+
+#[cfg(feature = "xlsx")]
+fn write_xlsx(frames: &[(Frequency, DataFrame)], path: &Path) -> Result<(), MeteostatError> {
+    use polars::prelude::DataType;
+    use rust_xlsxwriter::Workbook;
+
+    let sheet_error = |e| MeteostatError::ExportSpreadsheet(path.to_path_buf(), e);
+    let mut workbook = Workbook::new();
+
+    for (frequency, frame) in frames {
+        let sheet = workbook
+            .add_worksheet()
+            .set_name(frequency.to_string())
+            .map_err(sheet_error)?;
+        for (col_idx, column) in (0u16..).zip(frame.columns()) {
+            sheet
+                .write_string(0, col_idx, column.name().as_str())
+                .map_err(sheet_error)?;
+            // Numbers stay numeric so they can be used in formulas, everything else
+            // (dates, datetimes, station IDs) is written as text.
+            if column.dtype().is_primitive_numeric() {
+                let values = column.cast(&DataType::Float64)?;
+                for (row, value) in (1u32..).zip(values.f64()?.iter()) {
+                    if let Some(value) = value {
+                        sheet
+                            .write_number(row, col_idx, value)
+                            .map_err(sheet_error)?;
+                    }
+                }
+            } else {
+                let values = column.cast(&DataType::String)?;
+                for (row, value) in (1u32..).zip(values.str()?.iter()) {
+                    if let Some(value) = value {
+                        sheet
+                            .write_string(row, col_idx, value)
+                            .map_err(sheet_error)?;
+                    }
+                }
+            }
+        }
+    }
+    workbook.save(path).map_err(sheet_error)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_daily_csv;
+    use std::io::Read;
+    use zip::ZipArchive;
+
+    fn test_frames() -> Result<Vec<(Frequency, DataFrame)>, MeteostatError> {
+        let daily = parse_daily_csv(
+            b"2023-01-01,5.1,2.0,8.3,1.2,,250,12.5,30.0,1015.2,120\n\
+              2023-01-02,4.0,1.1,6.9,0.0,,240,10.1,25.2,1018.0,60\n",
+        )?
+        .frame
+        .collect()?;
+        Ok(vec![(Frequency::Daily, daily)])
+    }
+
+    #[test]
+    fn test_write_zip_csv() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("export.zip");
+        let mut frames = test_frames()?;
+
+        write_export(&mut frames, &path, ExportFormat::ZipCsv)?;
+
+        let mut archive = ZipArchive::new(File::open(&path)?)?;
+        assert_eq!(archive.len(), 1);
+        let mut csv = String::new();
+        archive.by_name("daily.csv")?.read_to_string(&mut csv)?;
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("date,tavg,tmin,tmax"));
+        assert!(lines[1].starts_with("2023-01-01,5.1,2.0,8.3"));
+        Ok(())
+    }
+
+    #[cfg(feature = "xlsx")]
+    #[test]
+    fn test_write_xlsx() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("export.xlsx");
+        let mut frames = test_frames()?;
+
+        write_export(&mut frames, &path, ExportFormat::Xlsx)?;
+
+        // An XLSX file is a zip archive with a part per sheet
+        let archive = ZipArchive::new(File::open(&path)?)?;
+        assert!(archive
+            .file_names()
+            .any(|name| name == "xl/worksheets/sheet1.xml"));
+        Ok(())
+    }
+}
@@ -1,9 +1,45 @@
 //! Defines types related to the frequency of Meteostat data and requirements
 //! for checking station data availability (inventory).
 
+use crate::utils::round_to_i64;
 use chrono::NaiveDate;
+use polars::prelude::{col, DataType, Expr, Field, LazyFrame, PolarsResult, Schema, TimeUnit};
 use std::fmt;
 
+/// Columns that are stored as floats in the CSV files but hold whole numbers,
+/// so they are cast to `Int64` after parsing.
+const INTEGER_COLUMNS: [&str; 5] = ["rhum", "snow", "wdir", "tsun", "coco"];
+
+/// Columns of the frames returned for [`Frequency::Hourly`], in order. `date` and `hour`
+/// are the raw date string and hour of the day that `datetime` is built from.
+pub const HOURLY_COLUMNS: &[&str] = &[
+    "date", "hour", "temp", "dwpt", "rhum", "prcp", "snow", "wdir", "wspd", "wpgt", "pres", "tsun",
+    "coco", "datetime",
+];
+
+/// Columns of the frames returned for [`Frequency::Daily`], in order.
+pub const DAILY_COLUMNS: &[&str] = &[
+    "date", "tavg", "tmin", "tmax", "prcp", "snow", "wdir", "wspd", "wpgt", "pres", "tsun",
+];
+
+/// Columns of the frames returned for [`Frequency::Monthly`], in order.
+pub const MONTHLY_COLUMNS: &[&str] = &[
+    "year", "month", "tavg", "tmin", "tmax", "prcp", "wspd", "pres", "tsun",
+];
+
+/// Columns of the frames returned for [`Frequency::Climate`], in order.
+pub const CLIMATE_COLUMNS: &[&str] = &[
+    "start_year",
+    "end_year",
+    "month",
+    "tmin",
+    "tmax",
+    "prcp",
+    "wspd",
+    "pres",
+    "tsun",
+];
+
 /// Represents the time frequency or granularity of Meteostat weather data.
 ///
 /// Used to specify the type of data to fetch (e.g., hourly temperature vs. daily average)
@@ -21,6 +57,9 @@ pub enum Frequency {
 }
 
 impl Frequency {
+    /// All frequencies, from finest to coarsest.
+    pub const ALL: [Self; 4] = [Self::Hourly, Self::Daily, Self::Monthly, Self::Climate];
+
     pub(crate) const fn path_segment(self) -> &'static str {
         match self {
             Self::Hourly => "hourly",
@@ -30,6 +69,167 @@ impl Frequency {
         }
     }
 
+    /// The column names of the frames returned for this frequency, in order: one of
+    /// [`HOURLY_COLUMNS`], [`DAILY_COLUMNS`], [`MONTHLY_COLUMNS`] or [`CLIMATE_COLUMNS`].
+    ///
+    /// These are the names of [`Frequency::schema`]. The optional `source` column, only
+    /// present when Meteostat provides it, is not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meteostat::{Frequency, DAILY_COLUMNS};
+    ///
+    /// assert_eq!(Frequency::Daily.columns(), DAILY_COLUMNS);
+    /// assert!(Frequency::Hourly.columns().contains(&"temp"));
+    /// ```
+    #[must_use]
+    pub const fn columns(self) -> &'static [&'static str] {
+        match self {
+            Self::Hourly => HOURLY_COLUMNS,
+            Self::Daily => DAILY_COLUMNS,
+            Self::Monthly => MONTHLY_COLUMNS,
+            Self::Climate => CLIMATE_COLUMNS,
+        }
+    }
+
+    /// Whether Meteostat also publishes this frequency as per-year files
+    /// (`{frequency}/{year}/{station}.csv.gz`), which allows incremental cache updates.
+    pub(crate) const fn has_yearly_files(self) -> bool {
+        matches!(self, Self::Hourly)
+    }
+
+    /// Returns the canonical schema of the data frames for this frequency: the column
+    /// names and types of the frames returned by the frequency clients.
+    ///
+    /// This is the schema the data loader produces and the cache files hold, so it can be
+    /// used to validate or build compatible frames. The optional `source` column, only
+    /// present when Meteostat provides it, is not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meteostat::Frequency;
+    /// use polars::prelude::DataType;
+    ///
+    /// let schema = Frequency::Daily.schema();
+    /// assert_eq!(schema.get("date"), Some(&DataType::Date));
+    /// assert_eq!(schema.get("tavg"), Some(&DataType::Float64));
+    /// ```
+    #[must_use]
+    pub fn schema(self) -> Schema {
+        let mut schema: Schema = self
+            .csv_schema()
+            .iter()
+            .map(|(name, dtype)| {
+                let dtype = if name == "date" && self == Self::Daily {
+                    DataType::Date
+                } else if INTEGER_COLUMNS.contains(&name.as_str()) {
+                    DataType::Int64
+                } else {
+                    dtype.clone()
+                };
+                Field::new(name.clone(), dtype)
+            })
+            .collect();
+        if self == Self::Hourly {
+            schema.with_column(
+                "datetime".into(),
+                DataType::Datetime(TimeUnit::Milliseconds, None),
+            );
+        }
+        schema
+    }
+
+    /// Returns the schema of the raw (header-less) CSV files for this frequency, before
+    /// any type casting.
+    pub(crate) fn csv_schema(self) -> Schema {
+        let fields = match self {
+            Self::Hourly => vec![
+                ("date", DataType::String),
+                ("hour", DataType::Int64),
+                ("temp", DataType::Float64),
+                ("dwpt", DataType::Float64),
+                ("rhum", DataType::Float64),
+                ("prcp", DataType::Float64),
+                ("snow", DataType::Float64),
+                ("wdir", DataType::Float64),
+                ("wspd", DataType::Float64),
+                ("wpgt", DataType::Float64),
+                ("pres", DataType::Float64),
+                ("tsun", DataType::Float64),
+                ("coco", DataType::Float64),
+            ],
+            Self::Daily => vec![
+                ("date", DataType::String),
+                ("tavg", DataType::Float64),
+                ("tmin", DataType::Float64),
+                ("tmax", DataType::Float64),
+                ("prcp", DataType::Float64),
+                ("snow", DataType::Float64),
+                ("wdir", DataType::Float64),
+                ("wspd", DataType::Float64),
+                ("wpgt", DataType::Float64),
+                ("pres", DataType::Float64),
+                ("tsun", DataType::Float64),
+            ],
+            Self::Monthly => vec![
+                ("year", DataType::Int64),
+                ("month", DataType::Int64),
+                ("tavg", DataType::Float64),
+                ("tmin", DataType::Float64),
+                ("tmax", DataType::Float64),
+                ("prcp", DataType::Float64),
+                ("wspd", DataType::Float64),
+                ("pres", DataType::Float64),
+                ("tsun", DataType::Float64),
+            ],
+            Self::Climate => vec![
+                ("start_year", DataType::Int64),
+                ("end_year", DataType::Int64),
+                ("month", DataType::Int64),
+                ("tmin", DataType::Float64),
+                ("tmax", DataType::Float64),
+                ("prcp", DataType::Float64),
+                ("wspd", DataType::Float64),
+                ("pres", DataType::Float64),
+                ("tsun", DataType::Float64),
+            ],
+        };
+        fields
+            .into_iter()
+            .map(|(name, dtype)| Field::new(name.into(), dtype))
+            .collect()
+    }
+
+    /// Casts the columns of `frame` whose type differs from [`Frequency::schema`] to their
+    /// canonical type, whatever type they were parsed or cached as. Floats cast to integer
+    /// columns are rounded with [`round_to_i64`]. Columns that already have the right type,
+    /// and columns outside the schema such as the optional source column, are left alone, so
+    /// filters on them can still be pushed down into a Parquet scan.
+    pub(crate) fn cast_to_schema(self, mut frame: LazyFrame) -> PolarsResult<LazyFrame> {
+        let current = frame.collect_schema()?;
+        let casts: Vec<Expr> = self
+            .schema()
+            .iter()
+            .filter(|(name, dtype)| current.get(name) != Some(*dtype))
+            .map(|(name, dtype)| {
+                let column = col(name.clone());
+                let is_float = current.get(name).is_some_and(DataType::is_float);
+                if *dtype == DataType::Int64 && is_float {
+                    round_to_i64(column).alias(name.clone())
+                } else {
+                    column.cast(dtype.clone())
+                }
+            })
+            .collect();
+        Ok(if casts.is_empty() {
+            frame
+        } else {
+            frame.with_columns(casts)
+        })
+    }
+
     pub(crate) fn cache_file_prefix(self) -> String {
         format!("{}-", self.path_segment())
     }
@@ -95,16 +295,58 @@ pub enum RequiredData {
     ///
     /// **Example Use:** Find stations reporting daily data for the full year 2022.
     FullYear(i32),
+
+    /// Checks if the station's reported data availability range covers the entire
+    /// specified calendar month, i.e. a `DateRange` from its first to its last day.
+    /// Less strict than [`RequiredData::FullYear`] when only one month matters. For
+    /// [`Frequency::Monthly`] and [`Frequency::Climate`] the year has to be in range.
+    /// An invalid month (outside 1-12) matches no station.
+    ///
+    /// **Example Use:** Find stations reporting hourly data throughout August 2021,
+    /// e.g. to analyze a specific storm.
+    MonthCoverage {
+        /// The calendar year.
+        year: i32,
+        /// The month (1-12).
+        month: u32,
+    },
 }
 
 impl RequiredData {
+    /// The first and last day this requirement asks for, or `None` for [`RequiredData::Any`]
+    /// and for an invalid month or year.
+    pub(crate) fn date_bounds(&self) -> Option<(NaiveDate, NaiveDate)> {
+        match self {
+            Self::Any => None,
+            Self::SpecificDate(date) => Some((*date, *date)),
+            Self::DateRange { start, end } => Some((*start, *end)),
+            Self::FullYear(year) => Some((
+                NaiveDate::from_ymd_opt(*year, 1, 1)?,
+                NaiveDate::from_ymd_opt(*year, 12, 31)?,
+            )),
+            Self::MonthCoverage { year, month } => month_bounds(*year, *month),
+        }
+    }
+
     #[allow(dead_code)]
-    pub(crate) const fn get_end_date(&self) -> Option<NaiveDate> {
+    pub(crate) fn get_end_date(&self) -> Option<NaiveDate> {
         match self {
             Self::Any => None,
             Self::SpecificDate(date) => Some(*date),
             Self::DateRange { start: _, end } => Some(*end),
             Self::FullYear(year) => NaiveDate::from_ymd_opt(*year, 12, 31),
+            Self::MonthCoverage { year, month } => month_bounds(*year, *month).map(|(_, end)| end),
         }
     }
 }
+
+/// First and last day of a calendar month, or `None` if the month is invalid.
+pub fn month_bounds(year: i32, month: u32) -> Option<(NaiveDate, NaiveDate)> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let next_first = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+    Some((first, next_first.pred_opt()?))
+}
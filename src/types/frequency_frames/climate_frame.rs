@@ -2,10 +2,16 @@
 
 //! Contains the `ClimateLazyFrame` structure for handling lazy operations on Meteostat climate data.
 
+use crate::types::frequency_frames::concat_frames;
+use crate::types::frequency_frames::meteostat_frame::{run_blocking, MeteostatFrame};
+use crate::types::records::ClimateRecords;
+use crate::types::unit_system::UnitSystem;
+use crate::utils::{column_f64, column_i64, Measurement};
 use crate::{MeteostatError, Year};
 // Added MeteostatError
 use polars::prelude::{col, lit, DataFrame, Expr, LazyFrame};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 // Added DataFrame
 
 /// Represents a row of climate normals data, suitable for collecting results.
@@ -31,6 +37,70 @@ pub struct Climate {
     pub sunshine_minutes: Option<i32>, // tsun (read as i64, store as i32)
 }
 
+impl Climate {
+    /// Returns this record with its temperatures, precipitation, wind speeds and pressure
+    /// converted from Meteostat's metric units into `units`.
+    ///
+    /// The fields don't carry their unit, so only convert a record once. The `Display` output
+    /// keeps labelling the metric units.
+    #[must_use]
+    pub fn with_units(self, units: UnitSystem) -> Self {
+        Self {
+            minimum_temperature: self
+                .minimum_temperature
+                .map(|value| units.temperature(value)),
+            maximum_temperature: self
+                .maximum_temperature
+                .map(|value| units.temperature(value)),
+            precipitation: self.precipitation.map(|value| units.precipitation(value)),
+            wind_speed: self.wind_speed.map(|value| units.wind_speed(value)),
+            pressure: self.pressure.map(|value| units.pressure(value)),
+            ..self
+        }
+    }
+
+    /// The normal total sunshine duration of the month as a [`chrono::Duration`], or `None` if missing.
+    #[must_use]
+    pub fn sunshine_duration(&self) -> Option<chrono::Duration> {
+        self.sunshine_minutes
+            .map(|minutes| chrono::Duration::minutes(i64::from(minutes)))
+    }
+
+    /// Mean air temperature in Celsius for the month, derived as `(tmin + tmax) / 2`.
+    ///
+    /// Climate normals only provide the average minimum and maximum, so this midpoint is the
+    /// conventional monthly mean. Returns `None` if either of them is missing.
+    #[must_use]
+    pub fn average_temperature(&self) -> Option<f64> {
+        Some(f64::midpoint(
+            self.minimum_temperature?,
+            self.maximum_temperature?,
+        ))
+    }
+}
+
+/// Formats the record as a one-line summary with units, rendering missing values as "n/a".
+///
+/// For example: `1991-2020, month 01: min -0.5 °C, max 4.9 °C, precipitation 61 mm,
+/// wind n/a, pressure 1016.2 hPa, sunshine 3720 min`.
+impl fmt::Display for Climate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}, month {:02}: min {}, max {}, precipitation {}, wind {}, pressure {}, sunshine {}",
+            self.start_year,
+            self.end_year,
+            self.month,
+            Measurement(&self.minimum_temperature, " °C"),
+            Measurement(&self.maximum_temperature, " °C"),
+            Measurement(&self.precipitation, " mm"),
+            Measurement(&self.wind_speed, " km/h"),
+            Measurement(&self.pressure, " hPa"),
+            Measurement(&self.sunshine_minutes, " min"),
+        )
+    }
+}
+
 /// A wrapper around a Polars `LazyFrame` specifically for Meteostat climate data.
 ///
 /// This struct provides methods tailored for common operations on climate normals datasets,
@@ -70,6 +140,17 @@ impl ClimateLazyFrame {
         Self { frame }
     }
 
+    /// Vertically stacks several `ClimateLazyFrame`s into one, e.g. data from several stations.
+    /// See [stacking frames](MeteostatFrame#stacking-frames) for the rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::EmptyConcat`] or [`MeteostatError::SchemaMismatch`] if the
+    /// frames can't be stacked, or [`MeteostatError::PolarsError`] if a schema can't be resolved.
+    pub fn concat(frames: Vec<Self>) -> Result<Self, MeteostatError> {
+        concat_frames(frames.into_iter().map(|lazy| lazy.frame).collect()).map(Self::new)
+    }
+
     /// Filters the climate data based on a Polars predicate expression.
     ///
     /// This method allows applying arbitrary filtering logic supported by Polars.
@@ -119,6 +200,30 @@ impl ClimateLazyFrame {
         }
     }
 
+    /// Converts the temperature, wind speed and pressure columns from Meteostat's metric units
+    /// into `units`. Precipitation keeps its values, see [`UnitSystem::Scientific`].
+    ///
+    /// Call [`ClimateLazyFrame::with_average_temperature`] after converting, so the derived
+    /// average is in the same unit as `tmin` and `tmax`.
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The unit system to convert into. [`UnitSystem::Metric`] changes nothing.
+    ///
+    /// # Returns
+    ///
+    /// A new `ClimateLazyFrame` with the conversion applied lazily.
+    #[must_use]
+    pub fn with_units(&self, units: UnitSystem) -> Self {
+        let exprs = units.column_exprs(&["tmin", "tmax"], &["wspd"], &["pres"]);
+        if exprs.is_empty() {
+            return self.clone();
+        }
+        Self {
+            frame: self.frame.clone().with_columns(exprs),
+        }
+    }
+
     /// Filters the climate data to get the normals for a specific period and month.
     ///
     /// This is a convenience method that filters the data based on the `start_year`,
@@ -176,7 +281,60 @@ impl ClimateLazyFrame {
         )
     }
 
-    /// Executes the lazy query and collects the results into a `Vec<Climate>`.
+    /// Adds a "tavg" column with the mean temperature, derived as `(tmin + tmax) / 2`.
+    ///
+    /// This is the lazy counterpart of [`Climate::average_temperature`]; the value is null
+    /// for rows where either `tmin` or `tmax` is missing.
+    ///
+    /// # Returns
+    ///
+    /// A new `ClimateLazyFrame` with the extra "tavg" column.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let climate_lazy = client.climate().station("10382").call().await?;
+    /// let df = climate_lazy.with_average_temperature().frame.collect()?;
+    /// println!("{}", df.column("tavg")?.as_materialized_series());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_average_temperature(&self) -> Self {
+        Self {
+            frame: self
+                .frame
+                .clone()
+                .with_column(((col("tmin") + col("tmax")) / lit(2.0)).alias("tavg")),
+        }
+    }
+
+    /// Counts the rows of the lazy query without collecting all columns, see
+    /// [`MeteostatFrame::row_count`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    pub fn row_count(&self) -> Result<usize, MeteostatError> {
+        MeteostatFrame::row_count(self)
+    }
+
+    /// Executes the lazy query and returns all columns as a Polars `DataFrame`, see
+    /// [`MeteostatFrame::collect_frame`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    pub fn collect_frame(&self) -> Result<DataFrame, MeteostatError> {
+        MeteostatFrame::collect_frame(self)
+    }
+
+    /// Executes the lazy query and collects the results into [`ClimateRecords`].
     ///
     /// This method triggers the computation defined by the `LazyFrame` (including any
     /// previous filtering operations) and maps each resulting row to a `Climate` struct.
@@ -185,7 +343,7 @@ impl ClimateLazyFrame {
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `Vec<Climate>` on success, or a [`MeteostatError`]
+    /// A `Result` containing the [`ClimateRecords`] on success, or a [`MeteostatError`]
     /// if the computation or mapping fails.
     ///
     /// # Errors
@@ -198,7 +356,7 @@ impl ClimateLazyFrame {
     /// # Example
     ///
     /// ```no_run
-    /// # use meteostat::{Meteostat, MeteostatError, LatLon, Climate};
+    /// # use meteostat::{Meteostat, MeteostatError, LatLon, Climate, ClimateRecords};
     /// use polars::prelude::PolarsError;
     ///
     /// # #[tokio::main]
@@ -213,7 +371,7 @@ impl ClimateLazyFrame {
     ///     .await?;
     ///
     /// // Collect all available climate normals
-    /// let climate_vec: Vec<Climate> = climate_lazy.collect_climate()?;
+    /// let climate_vec: ClimateRecords = climate_lazy.collect_climate()?;
     ///
     /// println!("Collected {} climate normal records.", climate_vec.len());
     /// if let Some(first_normal) = climate_vec.first() {
@@ -222,14 +380,8 @@ impl ClimateLazyFrame {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn collect_climate(&self) -> Result<Vec<Climate>, MeteostatError> {
-        let df = self
-            .frame
-            .clone() // Clone frame as collect consumes it
-            .collect()
-            .map_err(MeteostatError::PolarsError)?; // Map PolarsError
-
-        Self::dataframe_to_climate_vec(&df) // Use helper function
+    pub fn collect_climate(&self) -> Result<ClimateRecords, MeteostatError> {
+        self.clone().into_collect_climate()
     }
 
     /// Executes the lazy query, expecting exactly one row, and collects it into a `Climate` struct.
@@ -287,11 +439,52 @@ impl ClimateLazyFrame {
     /// # }
     /// ```
     pub fn collect_single_climate(&self) -> Result<Climate, MeteostatError> {
-        let df = self
-            .frame
-            .clone()
-            .collect()
-            .map_err(MeteostatError::PolarsError)?;
+        self.clone().into_collect_single_climate()
+    }
+
+    /// Consuming variant of [`Self::collect_climate`].
+    ///
+    /// Takes the frame by value, so the query plan doesn't have to be cloned before
+    /// collecting. Prefer this for one-shot queries where the frame isn't reused.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_climate`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Climate, ClimateRecords};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let records: ClimateRecords = client
+    ///     .climate()
+    ///     .station("10637")
+    ///     .call()
+    ///     .await?
+    ///     .into_collect_climate()?; // No clone of the query plan needed
+    /// println!("Collected {} records.", records.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_collect_climate(self) -> Result<ClimateRecords, MeteostatError> {
+        let df = self.frame.collect().map_err(MeteostatError::PolarsError)?; // Map PolarsError
+
+        Self::dataframe_to_climate_vec(&df).map(ClimateRecords::from) // Use helper function
+    }
+
+    /// Consuming variant of [`Self::collect_single_climate`].
+    ///
+    /// Takes the frame by value, so the query plan doesn't have to be cloned before
+    /// collecting.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_single_climate`].
+    pub fn into_collect_single_climate(self) -> Result<Climate, MeteostatError> {
+        let df = self.frame.collect().map_err(MeteostatError::PolarsError)?;
 
         if df.height() != 1 {
             return Err(MeteostatError::ExpectedSingleRow {
@@ -305,6 +498,27 @@ impl ClimateLazyFrame {
             .ok_or(MeteostatError::ExpectedSingleRow { actual: 0 }) // Should be unreachable
     }
 
+    /// Async variant of [`Self::collect_climate`], see [`MeteostatFrame::collect_frame_async`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_climate`], or [`MeteostatError::TaskJoin`] if the background task fails.
+    pub async fn collect_climate_async(&self) -> Result<ClimateRecords, MeteostatError> {
+        let data = self.clone();
+        run_blocking(move || data.into_collect_climate()).await
+    }
+
+    /// Async variant of [`Self::collect_single_climate`], see [`MeteostatFrame::collect_frame_async`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_single_climate`], or [`MeteostatError::TaskJoin`] if the background
+    /// task fails.
+    pub async fn collect_single_climate_async(&self) -> Result<Climate, MeteostatError> {
+        let data = self.clone();
+        run_blocking(move || data.into_collect_single_climate()).await
+    }
+
     // --- Helper function to map DataFrame rows to Vec<Climate> ---
     fn dataframe_to_climate_vec(df: &DataFrame) -> Result<Vec<Climate>, MeteostatError> {
         // --- Get required columns as Series ---
@@ -318,16 +532,16 @@ impl ClimateLazyFrame {
         let pres_series = df.column("pres")?;
         let tsun_series = df.column("tsun")?;
 
-        // --- Get ChunkedArrays (assuming Polars read them as i64/f64) ---
-        let start_year_ca = start_year_series.i64()?;
-        let end_year_ca = end_year_series.i64()?;
-        let month_ca = month_series.i64()?;
-        let tmin_ca = tmin_series.f64()?;
-        let tmax_ca = tmax_series.f64()?;
-        let prcp_ca = prcp_series.f64()?;
-        let wspd_ca = wspd_series.f64()?;
-        let pres_ca = pres_series.f64()?;
-        let tsun_ca = tsun_series.i64()?; // Read as i64 initially
+        // --- Get ChunkedArrays (numeric columns are cast from whatever type Polars read) ---
+        let start_year_ca = column_i64(start_year_series)?;
+        let end_year_ca = column_i64(end_year_series)?;
+        let month_ca = column_i64(month_series)?;
+        let tmin_ca = column_f64(tmin_series)?;
+        let tmax_ca = column_f64(tmax_series)?;
+        let prcp_ca = column_f64(prcp_series)?;
+        let wspd_ca = column_f64(wspd_series)?;
+        let pres_ca = column_f64(pres_series)?;
+        let tsun_ca = column_i64(tsun_series)?;
 
         let mut climate_vec = Vec::with_capacity(df.height());
 
@@ -631,4 +845,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_average_temperature() -> Result<(), Box<dyn std::error::Error>> {
+        let df = df!(
+            "start_year" => [1991i64, 1991],
+            "end_year" => [2020i64, 2020],
+            "month" => [1i64, 2],
+            "tmin" => [Some(-1.0), None],
+            "tmax" => [Some(5.0), Some(6.0)],
+            "prcp" => [None::<f64>, None],
+            "wspd" => [None::<f64>, None],
+            "pres" => [None::<f64>, None],
+            "tsun" => [None::<i64>, None],
+        )?;
+        let climate_lazy = ClimateLazyFrame::new(df.lazy()).with_average_temperature();
+
+        let collected = climate_lazy.clone().frame.collect()?;
+        let tavg = collected.column("tavg")?.f64()?;
+        assert_eq!(tavg.get(0), Some(2.0));
+        assert_eq!(tavg.get(1), None);
+
+        let records = climate_lazy.collect_climate()?;
+        let averages: Vec<_> = records.iter().map(Climate::average_temperature).collect();
+        assert_eq!(averages, vec![Some(2.0), None]);
+        Ok(())
+    }
 }
@@ -2,12 +2,23 @@
 
 //! Contains the `DailyLazyFrame` structure for handling lazy operations on Meteostat daily weather data.
 
+use crate::types::frequency_frames::concat_frames;
+use crate::types::frequency_frames::meteostat_frame::{run_blocking, MeteostatFrame};
+use crate::types::frequency_frames::period_filter::PeriodFilter;
+use crate::types::records::DailyRecords;
 use crate::types::traits::any::any_date::AnyDate;
 use crate::types::traits::period::date_period::DatePeriod;
-use crate::MeteostatError;
-use chrono::{Duration, NaiveDate};
-use polars::prelude::{col, lit, DataFrame, Expr, LazyFrame};
+use crate::types::unit_system::UnitSystem;
+use crate::utils::{column_f64, column_i64, polars_date_to_naive, round_to_i64, Measurement};
+use crate::weather_data::data_loader::SOURCE_COLUMN;
+use crate::{meteo_calc, MeteostatError};
+use chrono::{DateTime, Datelike, Days, Duration, NaiveDate, Utc};
+use polars::prelude::{
+    col, len, lit, when, Column, DataFrame, DataType, Expr, JoinArgs, JoinType, LazyFrame,
+    QuantileMethod,
+};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents a row of daily weather data, suitable for collecting results.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -34,6 +45,250 @@ pub struct Daily {
     pub pressure: Option<f64>, // pres
     /// Total sunshine duration in minutes.
     pub sunshine_minutes: Option<i32>, // tsun
+    /// Provenance or quality flag for this record (e.g. measured vs. model-derived), if the
+    /// source file provides one. `None` when the column is absent.
+    pub source: Option<String>,
+}
+
+impl Daily {
+    /// Returns this record with its temperatures, precipitation, wind speeds and pressure
+    /// converted from Meteostat's metric units into `units`.
+    ///
+    /// The fields don't carry their unit, so only convert a record once. The `Display` output
+    /// keeps labelling the metric units.
+    #[must_use]
+    pub fn with_units(self, units: UnitSystem) -> Self {
+        Self {
+            average_temperature: self
+                .average_temperature
+                .map(|value| units.temperature(value)),
+            minimum_temperature: self
+                .minimum_temperature
+                .map(|value| units.temperature(value)),
+            maximum_temperature: self
+                .maximum_temperature
+                .map(|value| units.temperature(value)),
+            precipitation: self.precipitation.map(|value| units.precipitation(value)),
+            wind_speed: self.wind_speed.map(|value| units.wind_speed(value)),
+            peak_wind_gust: self.peak_wind_gust.map(|value| units.wind_speed(value)),
+            pressure: self.pressure.map(|value| units.pressure(value)),
+            ..self
+        }
+    }
+
+    /// The total sunshine duration of the day as a [`chrono::Duration`], or `None` if missing.
+    #[must_use]
+    pub fn sunshine_duration(&self) -> Option<Duration> {
+        self.sunshine_minutes
+            .map(|minutes| Duration::minutes(i64::from(minutes)))
+    }
+
+    /// The spread between the maximum and minimum temperature of the day in °C, or
+    /// `None` if either is missing.
+    #[must_use]
+    pub fn temperature_range(&self) -> Option<f64> {
+        Some(self.maximum_temperature? - self.minimum_temperature?)
+    }
+
+    /// Estimates the day's mean dew point from its average temperature and a supplied humidity.
+    ///
+    /// Daily data has no humidity column, so the relative humidity (in percent) has to come
+    /// from elsewhere, e.g. the mean of the day's hourly `rhum` values. See
+    /// [`crate::meteo_calc`] for the underlying conversions.
+    ///
+    /// # Returns
+    ///
+    /// The dew point in °C, or `None` if the average temperature is missing.
+    #[must_use]
+    pub fn dewpoint_estimate(&self, relative_humidity: f64) -> Option<f64> {
+        self.average_temperature
+            .map(|tavg| meteo_calc::dewpoint_from_relative_humidity(tavg, relative_humidity))
+    }
+}
+
+/// Formats the record as a one-line summary with units, rendering missing values as "n/a".
+///
+/// For example: `2023-01-01: avg 5.1 °C (min 2 °C, max 8.3 °C), precipitation 1.2 mm,
+/// wind 15 km/h, pressure 1012.3 hPa, sunshine 60 min`.
+impl fmt::Display for Daily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: avg {} (min {}, max {}), precipitation {}, wind {}, pressure {}, sunshine {}",
+            self.date,
+            Measurement(&self.average_temperature, " °C"),
+            Measurement(&self.minimum_temperature, " °C"),
+            Measurement(&self.maximum_temperature, " °C"),
+            Measurement(&self.precipitation, " mm"),
+            Measurement(&self.wind_speed, " km/h"),
+            Measurement(&self.pressure, " hPa"),
+            Measurement(&self.sunshine_minutes, " min"),
+        )
+    }
+}
+
+/// The numeric fields of a [`Daily`] record, used to pick matrix columns with
+/// [`DailyLazyFrame::to_matrix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DailyField {
+    /// Average air temperature in Celsius (`tavg`).
+    AverageTemperature,
+    /// Minimum air temperature in Celsius (`tmin`).
+    MinimumTemperature,
+    /// Maximum air temperature in Celsius (`tmax`).
+    MaximumTemperature,
+    /// Total precipitation amount in mm (`prcp`).
+    Precipitation,
+    /// Snow depth on the ground in mm (`snow`).
+    Snow,
+    /// Average wind direction in degrees (`wdir`).
+    WindDirection,
+    /// Average wind speed in km/h (`wspd`).
+    WindSpeed,
+    /// Peak wind gust speed in km/h (`wpgt`).
+    PeakWindGust,
+    /// Average sea-level air pressure in hPa (`pres`).
+    Pressure,
+    /// Total sunshine duration in minutes (`tsun`).
+    SunshineMinutes,
+}
+
+impl DailyField {
+    /// The name of the column holding this field in the daily data frame.
+    #[must_use]
+    pub const fn column_name(self) -> &'static str {
+        match self {
+            Self::AverageTemperature => "tavg",
+            Self::MinimumTemperature => "tmin",
+            Self::MaximumTemperature => "tmax",
+            Self::Precipitation => "prcp",
+            Self::Snow => "snow",
+            Self::WindDirection => "wdir",
+            Self::WindSpeed => "wspd",
+            Self::PeakWindGust => "wpgt",
+            Self::Pressure => "pres",
+            Self::SunshineMinutes => "tsun",
+        }
+    }
+
+    /// Whether the column holding this field has an integer type.
+    const fn is_integer(self) -> bool {
+        matches!(
+            self,
+            Self::Snow | Self::WindDirection | Self::SunshineMinutes
+        )
+    }
+}
+
+/// The fields averaged by [`DailyLazyFrame::daily_climatology`]. Wind direction is left out,
+/// since angles need a circular mean rather than the arithmetic one used here.
+const CLIMATOLOGY_FIELDS: [DailyField; 9] = [
+    DailyField::AverageTemperature,
+    DailyField::MinimumTemperature,
+    DailyField::MaximumTemperature,
+    DailyField::Precipitation,
+    DailyField::Snow,
+    DailyField::WindSpeed,
+    DailyField::PeakWindGust,
+    DailyField::Pressure,
+    DailyField::SunshineMinutes,
+];
+
+/// The number of days in a climatology year, which has no February 29.
+const CLIMATOLOGY_DAYS: usize = 365;
+
+/// A record value together with the date on which it was observed.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct DailyExtreme {
+    /// The date of the observation. For ties, the earliest date.
+    pub date: NaiveDate,
+    /// The observed value, in the unit of the field.
+    pub value: f64,
+}
+
+/// The record values of a daily frame, as returned by [`DailyLazyFrame::extremes`].
+///
+/// Each field is `None` if the frame has no non-null values for it.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct DailyExtremes {
+    /// Highest maximum temperature in Celsius (the hottest day).
+    pub highest_maximum_temperature: Option<DailyExtreme>,
+    /// Lowest minimum temperature in Celsius (the coldest night).
+    pub lowest_minimum_temperature: Option<DailyExtreme>,
+    /// Highest average temperature in Celsius.
+    pub highest_average_temperature: Option<DailyExtreme>,
+    /// Lowest average temperature in Celsius.
+    pub lowest_average_temperature: Option<DailyExtreme>,
+    /// Highest total precipitation in mm (the wettest day).
+    pub highest_precipitation: Option<DailyExtreme>,
+    /// Highest snow depth in mm.
+    pub highest_snow: Option<DailyExtreme>,
+    /// Highest average wind speed in km/h.
+    pub highest_wind_speed: Option<DailyExtreme>,
+    /// Highest peak wind gust in km/h.
+    pub highest_peak_wind_gust: Option<DailyExtreme>,
+    /// Highest average sea-level air pressure in hPa.
+    pub highest_pressure: Option<DailyExtreme>,
+    /// Lowest average sea-level air pressure in hPa.
+    pub lowest_pressure: Option<DailyExtreme>,
+}
+
+/// The result of [`DailyLazyFrame::exceedance_count`]: how often a field exceeded a
+/// percentile threshold taken from a baseline period.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct Exceedance {
+    /// The percentile value of the baseline, or `None` if the baseline has no values.
+    pub threshold: Option<f64>,
+    /// Number of days in the frame with a value strictly above the threshold.
+    pub exceeding_days: usize,
+    /// Number of days in the frame with a non-null value for the field.
+    pub valid_days: usize,
+}
+
+impl Exceedance {
+    /// The exceeding days as a percentage of the valid days, as used by indices like `TX90p`.
+    /// `None` if there are no valid days.
+    #[must_use]
+    pub fn percentage(&self) -> Option<f64> {
+        day_percentage(self.exceeding_days, self.valid_days)
+    }
+}
+
+/// How [`DailyLazyFrame::snow_days`] treats days without a snow depth.
+///
+/// Meteostat often leaves `snow` empty instead of reporting 0 mm, so a null can mean
+/// "no snow" as well as "not measured". Which reading fits depends on the station and season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MissingSnow {
+    /// Count days without a snow depth as snow-free days.
+    TreatAsZero,
+    /// Leave days without a snow depth out of the count entirely.
+    #[default]
+    Skip,
+}
+
+/// The result of [`DailyLazyFrame::snow_days`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SnowDays {
+    /// Number of days with at least the requested snow depth.
+    pub snow_days: usize,
+    /// Number of days considered: all days with [`MissingSnow::TreatAsZero`], only days with
+    /// a snow depth with [`MissingSnow::Skip`].
+    pub valid_days: usize,
+}
+
+impl SnowDays {
+    /// The snow days as a percentage of the valid days, or `None` if there are no valid days.
+    #[must_use]
+    pub fn percentage(&self) -> Option<f64> {
+        day_percentage(self.snow_days, self.valid_days)
+    }
+}
+
+/// `days` as a percentage of `valid_days`, or `None` if there are no valid days.
+#[allow(clippy::cast_precision_loss)]
+fn day_percentage(days: usize, valid_days: usize) -> Option<f64> {
+    (valid_days > 0).then(|| 100.0 * days as f64 / valid_days as f64)
 }
 
 /// A wrapper around a Polars `LazyFrame` specifically for Meteostat daily weather data.
@@ -77,6 +332,17 @@ impl DailyLazyFrame {
         Self { frame }
     }
 
+    /// Vertically stacks several `DailyLazyFrame`s into one, e.g. data from several stations.
+    /// See [stacking frames](MeteostatFrame#stacking-frames) for the rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::EmptyConcat`] or [`MeteostatError::SchemaMismatch`] if the
+    /// frames can't be stacked, or [`MeteostatError::PolarsError`] if a schema can't be resolved.
+    pub fn concat(frames: Vec<Self>) -> Result<Self, MeteostatError> {
+        concat_frames(frames.into_iter().map(|lazy| lazy.frame).collect()).map(Self::new)
+    }
+
     /// Filters the daily data based on a Polars predicate expression.
     ///
     /// This method allows applying arbitrary filtering logic supported by Polars.
@@ -123,6 +389,47 @@ impl DailyLazyFrame {
         Self::new(self.frame.clone().filter(predicate))
     }
 
+    /// Converts the temperature, wind speed and pressure columns from Meteostat's metric units
+    /// into `units`. Precipitation keeps its values, see [`UnitSystem::Scientific`].
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The unit system to convert into. [`UnitSystem::Metric`] changes nothing.
+    ///
+    /// # Returns
+    ///
+    /// A new `DailyLazyFrame` with the conversion applied lazily.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, UnitSystem, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let daily_lazy = client.daily().station("06240").call().await?;
+    ///
+    /// // Temperatures in K, wind in m/s, pressure in Pa, precipitation in kg/m²
+    /// let si = daily_lazy
+    ///     .get_for_period(Year(2023))?
+    ///     .with_units(UnitSystem::Scientific)
+    ///     .frame
+    ///     .collect()?;
+    /// println!("{}", si.head(Some(3)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_units(&self, units: UnitSystem) -> Self {
+        let exprs = units.column_exprs(&["tavg", "tmin", "tmax"], &["wspd", "wpgt"], &["pres"]);
+        if exprs.is_empty() {
+            return self.clone();
+        }
+        Self {
+            frame: self.frame.clone().with_columns(exprs),
+        }
+    }
+
     /// Filters the daily data to include only dates within the specified range (inclusive).
     ///
     /// The `start` and `end` arguments can be any type that implements [`AnyDate`],
@@ -147,20 +454,123 @@ impl DailyLazyFrame {
         start: impl AnyDate,
         end: impl AnyDate,
     ) -> Result<Self, MeteostatError> {
-        let start_naive = start
-            .get_date_range()
-            .ok_or(MeteostatError::DateParsingError)?
-            .start;
-        let end_naive = end
-            .get_date_range()
-            .ok_or(MeteostatError::DateParsingError)?
-            .end;
+        PeriodFilter::get_range_from(self, start)?.get_range_until(end)
+    }
 
-        Ok(self.filter(
+    /// Filters the daily data to include only dates on or after `start`, without an end bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The starting date boundary (inclusive), implementing [`AnyDate`].
+    ///   A range like [`crate::Year`] starts at its first day.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `DailyLazyFrame` with the dates from `start` onward.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `start` cannot be resolved into a
+    /// valid `NaiveDate`.
+    pub fn get_range_from(&self, start: impl AnyDate) -> Result<Self, MeteostatError> {
+        PeriodFilter::get_range_from(self, start)
+    }
+
+    /// Filters the daily data to include only dates on or before `end`, without a start bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - The ending date boundary (inclusive), implementing [`AnyDate`].
+    ///   A range like [`crate::Year`] ends at its last day.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `DailyLazyFrame` with the dates up to and including `end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `end` cannot be resolved into a
+    /// valid `NaiveDate`.
+    pub fn get_range_until(&self, end: impl AnyDate) -> Result<Self, MeteostatError> {
+        PeriodFilter::get_range_until(self, end)
+    }
+
+    /// Filters the daily data to the dates from `duration` ago up to today (UTC).
+    ///
+    /// Both ends are whole days: the date `duration` before now and today are included. The
+    /// range is computed from [`Utc::now`] when this method is called. Meteostat publishes
+    /// data with a delay, so the last few days are often not available yet. Negative
+    /// durations are treated as positive.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - How far back to keep records, e.g. `Duration::days(30)`.
+    ///
+    /// # Returns
+    ///
+    /// A new `DailyLazyFrame` with the filter applied lazily.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// use chrono::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let daily_lazy = client.daily().station("06240").call().await?;
+    ///
+    /// let recent = daily_lazy.since(Duration::weeks(4)).collect_daily()?;
+    /// println!("{} days in the past four weeks", recent.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn since(&self, duration: Duration) -> Self {
+        self.since_at(Utc::now(), duration)
+    }
+
+    /// Filters the daily data to the last `n` days, counting today (UTC) as the last one.
+    /// `n = 0` keeps no records.
+    ///
+    /// Like [`DailyLazyFrame::since`], the range is computed from [`Utc::now`].
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of days to keep.
+    ///
+    /// # Returns
+    ///
+    /// A new `DailyLazyFrame` with the filter applied lazily.
+    #[must_use]
+    pub fn last_n_days(&self, n: u32) -> Self {
+        self.last_n_days_at(Utc::now(), n)
+    }
+
+    fn since_at(&self, now: DateTime<Utc>, duration: Duration) -> Self {
+        let start = now
+            .checked_sub_signed(duration.abs())
+            .map_or(NaiveDate::MIN, |start| start.date_naive());
+        self.between(start, now.date_naive())
+    }
+
+    fn last_n_days_at(&self, now: DateTime<Utc>, n: u32) -> Self {
+        let today = now.date_naive();
+        let start = today
+            .checked_sub_days(Days::new(u64::from(n)))
+            .and_then(|date| date.succ_opt())
+            .unwrap_or(NaiveDate::MIN);
+        self.between(start, today)
+    }
+
+    /// Keeps the dates from `start` up to and including `end`.
+    fn between(&self, start: NaiveDate, end: NaiveDate) -> Self {
+        self.filter(
             col("date")
-                .gt_eq(lit(start_naive))
-                .and(col("date").lt_eq(lit(end_naive))),
-        ))
+                .gt_eq(lit(start))
+                .and(col("date").lt_eq(lit(end))),
+        )
     }
 
     /// Filters the daily data to get the record for a specific date.
@@ -181,166 +591,1012 @@ impl DailyLazyFrame {
     ///
     /// # Errors
     ///
-    /// Returns [`MeteostatError::DateParsingError`] if the input date cannot be
-    /// resolved into a valid `NaiveDate`.
-    pub fn get_at(&self, date: impl AnyDate) -> Result<Self, MeteostatError> {
-        // Use the start of the range provided by AnyDate for the equality check
-        let naive_date = date
-            .get_date_range()
-            .ok_or(MeteostatError::DateParsingError)?
-            .start;
-        Ok(self.filter(col("date").eq(lit(naive_date))))
+    /// Returns [`MeteostatError::DateParsingError`] if the input date cannot be
+    /// resolved into a valid `NaiveDate`.
+    pub fn get_at(&self, date: impl AnyDate) -> Result<Self, MeteostatError> {
+        // Use the start of the range provided by AnyDate for the equality check
+        let naive_date = date
+            .get_date_range()
+            .ok_or(MeteostatError::DateParsingError)?
+            .start;
+        Ok(self.filter(col("date").eq(lit(naive_date))))
+    }
+
+    /// Filters the daily data to include only dates within a specified period.
+    ///
+    /// This is a convenience method that accepts types implementing [`DatePeriod`],
+    /// such as [`crate::Year`]. It resolves the period into a start and end `NaiveDate`
+    /// and filters with [`PeriodFilter::get_for_period`].
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - The time period (e.g., a specific year), implementing [`DatePeriod`].
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `DailyLazyFrame` filtered by the period's date range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if the given period cannot be
+    /// resolved into a valid start and end `NaiveDate`.
+    pub fn get_for_period(&self, period: impl DatePeriod) -> Result<Self, MeteostatError> {
+        let period = period
+            .get_date_period()
+            .ok_or(MeteostatError::DateParsingError)?;
+        PeriodFilter::get_for_period(self, period)
+    }
+
+    /// Filters the daily data to the dates within any of several periods.
+    ///
+    /// The periods don't have to be contiguous, e.g. the summer months of several years.
+    /// They are combined into a single predicate, so the frame is scanned only once and
+    /// rows stay in their original order. Overlapping periods don't duplicate rows, and an
+    /// empty list of periods keeps no rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if any of the periods cannot be
+    /// resolved into a valid start and end `NaiveDate`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Month};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let daily = client.daily().station("06240").call().await?;
+    ///
+    /// // June to August of 2010, 2015 and 2020
+    /// let summers = [2010, 2015, 2020]
+    ///     .into_iter()
+    ///     .flat_map(|year| (6..=8).map(move |month| Month(year, month)));
+    /// let summer_days = daily.get_for_periods(summers)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_for_periods<P: DatePeriod>(
+        &self,
+        periods: impl IntoIterator<Item = P>,
+    ) -> Result<Self, MeteostatError> {
+        let periods = periods
+            .into_iter()
+            .map(|period| {
+                period
+                    .get_date_period()
+                    .ok_or(MeteostatError::DateParsingError)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        PeriodFilter::get_for_periods(self, periods)
+    }
+
+    /// Counts the rows of the lazy query without collecting all columns, see
+    /// [`MeteostatFrame::row_count`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    pub fn row_count(&self) -> Result<usize, MeteostatError> {
+        MeteostatFrame::row_count(self)
+    }
+
+    /// Executes the lazy query and returns all columns as a Polars `DataFrame`, see
+    /// [`MeteostatFrame::collect_frame`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    pub fn collect_frame(&self) -> Result<DataFrame, MeteostatError> {
+        MeteostatFrame::collect_frame(self)
+    }
+
+    /// Executes the lazy query and collects the results into [`DailyRecords`].
+    ///
+    /// This method triggers the computation defined by the `LazyFrame` (including any
+    /// previous filtering operations) and maps each resulting row to a `Daily` struct.
+    /// Rows where the essential 'date' column is missing or invalid are skipped.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the [`DailyRecords`] on success, or a [`MeteostatError`]
+    /// if the computation or mapping fails.
+    ///
+    /// # Errors
+    ///
+    /// This method can return a [`MeteostatError::PolarsError`] in two main scenarios:
+    /// 1.  The lazy computation fails when `.collect()` is called on the `LazyFrame`.
+    /// 2.  The resulting `DataFrame` does not have the expected schema (e.g., a required
+    ///     column is missing or has an incorrect data type for conversion).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, LatLon, Year, Daily, DailyRecords};
+    /// use polars::prelude::PolarsError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Meteostat::new().await?;
+    /// let paris = LatLon(48.85, 2.35);
+    ///
+    /// let daily_lazy = client
+    ///     .daily()
+    ///     .location(paris)
+    ///     .call()
+    ///     .await?;
+    ///
+    /// // Get data for a specific year
+    /// let year_data_lazy = daily_lazy.get_for_period(Year(2022))?;
+    ///
+    /// // Collect into DailyRecords
+    /// let daily_vec: DailyRecords = year_data_lazy.collect_daily()?;
+    ///
+    /// println!("Collected {} daily records for 2022.", daily_vec.len());
+    /// if let Some(first_day) = daily_vec.first() {
+    ///     println!("First record: {:?}", first_day);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn collect_daily(&self) -> Result<DailyRecords, MeteostatError> {
+        self.clone().into_collect_daily()
+    }
+
+    /// Executes the lazy query, expecting exactly one row, and collects it into a `Daily` struct.
+    ///
+    /// This is useful after filtering the frame down to a single expected record,
+    /// for example using `get_at()`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the single `Daily` struct on success.
+    ///
+    /// # Errors
+    ///
+    /// *   Returns [`MeteostatError::ExpectedSingleRow`] if the collected `DataFrame` does not
+    ///     contain exactly one row.
+    /// *   Returns [`MeteostatError::PolarsError`] if the lazy computation fails or if the
+    ///     resulting `DataFrame` has an unexpected schema (missing columns or wrong data types).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, LatLon, Daily};
+    /// use chrono::NaiveDate;
+    /// use polars::prelude::PolarsError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let station_id = "10384"; // Berlin Tempelhof
+    ///
+    /// let daily_lazy = client.daily().station(station_id).call().await?;
+    ///
+    /// // Get data for a specific day
+    /// let target_date = NaiveDate::from_ymd_opt(2022, 8, 15).unwrap();
+    /// let single_day_lazy = daily_lazy.get_at(target_date)?;
+    ///
+    /// // Collect the single expected row
+    /// match single_day_lazy.collect_single_daily() {
+    ///     Ok(daily_data) => {
+    ///         println!("Collected single day data: {:?}", daily_data);
+    ///         assert_eq!(daily_data.date, target_date); // Verify correct date
+    ///     },
+    ///     Err(MeteostatError::ExpectedSingleRow { actual }) => {
+    ///          println!("Expected 1 row, but found {}.", actual);
+    ///     },
+    ///     Err(e) => return Err(e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn collect_single_daily(&self) -> Result<Daily, MeteostatError> {
+        self.clone().into_collect_single_daily()
+    }
+
+    /// Consuming variant of [`Self::collect_daily`].
+    ///
+    /// Takes the frame by value, so the query plan doesn't have to be cloned before
+    /// collecting. Prefer this for one-shot queries where the frame isn't reused.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_daily`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Daily, DailyRecords};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let records: DailyRecords = client
+    ///     .daily()
+    ///     .station("10637")
+    ///     .call()
+    ///     .await?
+    ///     .into_collect_daily()?; // No clone of the query plan needed
+    /// println!("Collected {} records.", records.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_collect_daily(self) -> Result<DailyRecords, MeteostatError> {
+        let df = self.frame.collect().map_err(MeteostatError::PolarsError)?;
+
+        Self::dataframe_to_daily_vec(&df).map(DailyRecords::from)
+    }
+
+    /// Consuming variant of [`Self::collect_single_daily`].
+    ///
+    /// Takes the frame by value, so the query plan doesn't have to be cloned before
+    /// collecting.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_single_daily`].
+    pub fn into_collect_single_daily(self) -> Result<Daily, MeteostatError> {
+        let df = self.frame.collect().map_err(MeteostatError::PolarsError)?;
+
+        if df.height() != 1 {
+            return Err(MeteostatError::ExpectedSingleRow {
+                actual: df.height(),
+            });
+        }
+
+        // Use the Vec conversion helper and take the guaranteed single element
+        Self::dataframe_to_daily_vec(&df)?
+            .pop() // Take the only element
+            .ok_or(MeteostatError::ExpectedSingleRow { actual: 0 }) // Should be unreachable after height check
+    }
+
+    /// Async variant of [`Self::collect_daily`], see [`MeteostatFrame::collect_frame_async`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_daily`], or [`MeteostatError::TaskJoin`] if the background task fails.
+    pub async fn collect_daily_async(&self) -> Result<DailyRecords, MeteostatError> {
+        let data = self.clone();
+        run_blocking(move || data.into_collect_daily()).await
+    }
+
+    /// Async variant of [`Self::collect_single_daily`], see [`MeteostatFrame::collect_frame_async`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_single_daily`], or [`MeteostatError::TaskJoin`] if the background
+    /// task fails.
+    pub async fn collect_single_daily_async(&self) -> Result<Daily, MeteostatError> {
+        let data = self.clone();
+        run_blocking(move || data.into_collect_single_daily()).await
+    }
+
+    /// Executes the lazy query and collects the selected fields into a numeric matrix.
+    ///
+    /// Each row of the matrix is one day and each column one of `fields`, in the given
+    /// order. Missing values become `f64::NAN`. The dates of the rows are returned
+    /// alongside the matrix so it stays interpretable.
+    ///
+    /// Requires the `ndarray` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The [`DailyField`]s to use as matrix columns.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the row dates and a `(dates.len(), fields.len())` matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails or if the
+    /// resulting `DataFrame` has an unexpected schema.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{DailyField, Meteostat, MeteostatError, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let daily_lazy = client.daily().station("10637").call().await?;
+    ///
+    /// let (dates, matrix) = daily_lazy
+    ///     .get_for_period(Year(2023))?
+    ///     .to_matrix(&[DailyField::MinimumTemperature, DailyField::MaximumTemperature])?;
+    /// println!("{} days, shape {:?}", dates.len(), matrix.shape());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "ndarray")]
+    pub fn to_matrix(
+        &self,
+        fields: &[DailyField],
+    ) -> Result<(Vec<NaiveDate>, ndarray::Array2<f64>), MeteostatError> {
+        let mut columns = vec![col("date")];
+        columns.extend(
+            fields
+                .iter()
+                .map(|field| col(field.column_name()).cast(polars::prelude::DataType::Float64)),
+        );
+        // Rows without a date can't be labeled, so leave them out like `collect_daily` does
+        let df = self
+            .frame
+            .clone()
+            .filter(col("date").is_not_null())
+            .select(columns)
+            .collect()?;
+
+        let date_ca = df.column("date")?.date()?;
+        let dates = (0..df.height())
+            .filter_map(|i| date_ca.phys.get(i).and_then(polars_date_to_naive))
+            .collect();
+
+        let field_cas = fields
+            .iter()
+            .map(|field| df.column(field.column_name())?.f64().cloned())
+            .collect::<Result<Vec<_>, _>>()?;
+        let matrix = ndarray::Array2::from_shape_fn((df.height(), fields.len()), |(row, c)| {
+            field_cas[c].get(row).unwrap_or(f64::NAN)
+        });
+
+        Ok((dates, matrix))
+    }
+
+    /// Executes the lazy query and groups the collected `Daily` records by calendar month.
+    ///
+    /// Groups are returned in chronological order as `(year, month, days)` tuples, and the
+    /// records within each group are sorted by date. Months without any records are omitted.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<(i32, u32, Vec<Daily>)>` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DailyLazyFrame::collect_daily`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Meteostat::new().await?;
+    /// let daily_lazy = client.daily().station("10384").call().await?;
+    ///
+    /// for (year, month, days) in daily_lazy.get_for_period(Year(2022))?.group_by_month()? {
+    ///     println!("{year}-{month:02}: {} days", days.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn group_by_month(&self) -> Result<Vec<(i32, u32, Vec<Daily>)>, MeteostatError> {
+        let groups = Self::group_sorted_by(self.collect_daily()?.into_vec(), |d| {
+            (d.date.year(), d.date.month())
+        });
+        Ok(groups
+            .into_iter()
+            .map(|((year, month), days)| (year, month, days))
+            .collect())
+    }
+
+    /// Executes the lazy query and groups the collected `Daily` records by calendar year.
+    ///
+    /// Groups are returned in chronological order as `(year, days)` tuples, and the records
+    /// within each group are sorted by date. Years without any records are omitted.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<(i32, Vec<Daily>)>` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`DailyLazyFrame::collect_daily`].
+    pub fn group_by_year(&self) -> Result<Vec<(i32, Vec<Daily>)>, MeteostatError> {
+        Ok(Self::group_sorted_by(
+            self.collect_daily()?.into_vec(),
+            |d| d.date.year(),
+        ))
+    }
+
+    /// Finds the record values in the frame, such as the hottest day or the strongest gust,
+    /// and the dates they occurred on.
+    ///
+    /// Null values are ignored. When several days share the record value, the earliest
+    /// date is returned. All extremes are computed in a single query.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the [`DailyExtremes`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let extremes = client.daily().station("10637").call().await?.extremes()?;
+    ///
+    /// if let Some(hottest) = extremes.highest_maximum_temperature {
+    ///     println!("Hottest day on record: {} with {} °C", hottest.date, hottest.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn extremes(&self) -> Result<DailyExtremes, MeteostatError> {
+        // (field, whether the record is the highest value) in the order of `DailyExtremes`
+        const SPECS: [(DailyField, bool); 10] = [
+            (DailyField::MaximumTemperature, true),
+            (DailyField::MinimumTemperature, false),
+            (DailyField::AverageTemperature, true),
+            (DailyField::AverageTemperature, false),
+            (DailyField::Precipitation, true),
+            (DailyField::Snow, true),
+            (DailyField::WindSpeed, true),
+            (DailyField::PeakWindGust, true),
+            (DailyField::Pressure, true),
+            (DailyField::Pressure, false),
+        ];
+
+        let exprs: Vec<Expr> = SPECS
+            .iter()
+            .enumerate()
+            .flat_map(|(i, (field, highest))| {
+                let value = col(field.column_name()).cast(DataType::Float64);
+                let record = if *highest {
+                    value.clone().max()
+                } else {
+                    value.clone().min()
+                };
+                [
+                    record.clone().alias(format!("value_{i}")),
+                    col("date")
+                        .filter(value.eq(record))
+                        .min()
+                        .alias(format!("date_{i}")),
+                ]
+            })
+            .collect();
+        let df = self.frame.clone().select(exprs).collect()?;
+
+        let mut extremes = Vec::with_capacity(SPECS.len());
+        for i in 0..SPECS.len() {
+            let value = df.column(&format!("value_{i}"))?.f64()?.get(0);
+            let date = df
+                .column(&format!("date_{i}"))?
+                .date()?
+                .phys
+                .get(0)
+                .and_then(polars_date_to_naive);
+            extremes.push(
+                value
+                    .zip(date)
+                    .map(|(value, date)| DailyExtreme { date, value }),
+            );
+        }
+
+        Ok(DailyExtremes {
+            highest_maximum_temperature: extremes[0],
+            lowest_minimum_temperature: extremes[1],
+            highest_average_temperature: extremes[2],
+            lowest_average_temperature: extremes[3],
+            highest_precipitation: extremes[4],
+            highest_snow: extremes[5],
+            highest_wind_speed: extremes[6],
+            highest_peak_wind_gust: extremes[7],
+            highest_pressure: extremes[8],
+            lowest_pressure: extremes[9],
+        })
+    }
+
+    /// Computes a quantile of a field over the frame, e.g. the 95th percentile of daily
+    /// precipitation as a flood threshold.
+    ///
+    /// Missing values are excluded from the computation.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The [`DailyField`] to compute the quantile of.
+    /// * `q` - The quantile, from 0 to 1 (e.g. `0.95` for the 95th percentile).
+    /// * `method` - How to interpolate when the quantile falls between two values, e.g.
+    ///   `QuantileMethod::Linear` or `QuantileMethod::Nearest`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the quantile in the unit of the field, or `None` if the frame
+    /// has no values for the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::InvalidQuantile`] if `q` is not between 0 and 1, or
+    /// [`MeteostatError::PolarsError`] if the lazy computation fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{DailyField, Meteostat, MeteostatError, Year};
+    /// use polars::prelude::QuantileMethod;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let daily_lazy = client.daily().station("10637").call().await?;
+    ///
+    /// let p95 = daily_lazy
+    ///     .get_range(Year(1991), Year(2020))?
+    ///     .quantile(DailyField::Precipitation, 0.95, QuantileMethod::Linear)?;
+    /// println!("95th percentile of daily precipitation: {p95:?} mm");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn quantile(
+        &self,
+        field: DailyField,
+        q: f64,
+        method: QuantileMethod,
+    ) -> Result<Option<f64>, MeteostatError> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(MeteostatError::InvalidQuantile(q));
+        }
+        let quantile = self
+            .frame
+            .clone()
+            .select([col(field.column_name())
+                .cast(DataType::Float64)
+                .quantile(lit(q), method)
+                .alias("quantile")])
+            .collect()?;
+        Ok(quantile.column("quantile")?.f64()?.get(0))
+    }
+
+    /// Counts the days on which a field exceeds a percentile of a baseline period, as in
+    /// ETCCDI indices like warm days (`TX90p`: days with `tmax` above the 90th percentile).
+    ///
+    /// The threshold is a single percentile over all baseline values, computed with linear
+    /// interpolation. The ETCCDI definitions use a separate threshold per calendar day
+    /// (from a 5-day window), so results differ somewhat for fields with a strong seasonal
+    /// cycle; filter both frames to the same season to reduce the difference.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The [`DailyField`] to compare.
+    /// * `percentile` - The percentile of the baseline to use as threshold, from 0 to 100.
+    /// * `baseline` - The frame to compute the threshold from, typically the same station
+    ///   filtered to a reference period like 1961-1990 with [`DailyLazyFrame::get_range`].
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the [`Exceedance`] with the threshold and the day counts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::InvalidPercentile`] if `percentile` is not between 0 and 100,
+    /// or [`MeteostatError::PolarsError`] if the lazy computation fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{DailyField, Meteostat, MeteostatError, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let daily_lazy = client.daily().station("10637").call().await?;
+    ///
+    /// let baseline = daily_lazy.get_range(Year(1961), Year(1990))?;
+    /// let warm_days = daily_lazy
+    ///     .get_for_period(Year(2023))?
+    ///     .exceedance_count(DailyField::MaximumTemperature, 90.0, &baseline)?;
+    /// println!("TX90p in 2023: {:?} %", warm_days.percentage());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn exceedance_count(
+        &self,
+        field: DailyField,
+        percentile: f64,
+        baseline: &Self,
+    ) -> Result<Exceedance, MeteostatError> {
+        if !(0.0..=100.0).contains(&percentile) {
+            return Err(MeteostatError::InvalidPercentile(percentile));
+        }
+        let value = col(field.column_name()).cast(DataType::Float64);
+
+        let threshold = baseline.quantile(field, percentile / 100.0, QuantileMethod::Linear)?;
+        let Some(threshold) = threshold else {
+            return Ok(Exceedance {
+                threshold: None,
+                exceeding_days: 0,
+                valid_days: self.filter(value.is_not_null()).row_count()?,
+            });
+        };
+
+        let (exceeding_days, valid_days) =
+            self.count_days(value.clone().gt(lit(threshold)), value.count())?;
+        Ok(Exceedance {
+            threshold: Some(threshold),
+            exceeding_days,
+            valid_days,
+        })
     }
 
-    /// Filters the daily data to include only dates within a specified period.
+    /// Computes a daily climatology: the average of each field per day of the year, across
+    /// all years in the frame, optionally smoothed with a circular moving average.
     ///
-    /// This is a convenience method that accepts types implementing [`DatePeriod`],
-    /// such as [`crate::Year`]. It resolves the period into a start and end `NaiveDate`
-    /// and then calls `get_range`.
+    /// Unlike the monthly climate normals, this gives a baseline for every calendar day, e.g.
+    /// to compute daily anomalies. Filter the frame to a reference period like 1991-2020
+    /// first with [`DailyLazyFrame::get_range`].
+    ///
+    /// Days are numbered on a 365-day calendar: February 29 is pooled with February 28
+    /// (day 59), and the later days of leap years are shifted back by one, so March 1 is
+    /// always day 60.
     ///
     /// # Arguments
     ///
-    /// * `period` - The time period (e.g., a specific year), implementing [`DatePeriod`].
+    /// * `smooth_window_days` - The width of the centered moving average applied to the
+    ///   per-day averages, wrapping around from December 31 to January 1. `0` and `1`
+    ///   disable smoothing, even widths are widened by one day to stay centered, and widths
+    ///   beyond a year average the whole year. Missing days are skipped in the average.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a new `DailyLazyFrame` filtered by the period's date range.
+    /// A `Result` containing a `DataFrame` with 365 rows and the columns `day_of_year`
+    /// (1-365), `month` and `day` (the calendar day in a non-leap year), followed by the
+    /// averages of `tavg`, `tmin`, `tmax`, `prcp`, `snow`, `wspd`, `wpgt`, `pres` and
+    /// `tsun` as floats. Wind direction is left out, since angles need a circular mean
+    /// (see [`crate::meteo_calc::circular_mean_degrees`]) rather than an arithmetic one.
+    /// A value is null if no data was available within the window.
     ///
     /// # Errors
     ///
-    /// Returns [`MeteostatError::DateParsingError`] if the given period cannot be
-    /// resolved into a valid start and end `NaiveDate`.
-    pub fn get_for_period(&self, period: impl DatePeriod) -> Result<Self, MeteostatError> {
-        let date_period = period
-            .get_date_period()
-            .ok_or(MeteostatError::DateParsingError)?;
-        // Delegate to get_range using the resolved start and end dates
-        self.get_range(date_period.start, date_period.end)
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let daily_lazy = client.daily().station("10637").call().await?;
+    ///
+    /// let climatology = daily_lazy
+    ///     .get_range(Year(1991), Year(2020))?
+    ///     .daily_climatology(31)?;
+    /// println!("{}", climatology.column("tmax")?.as_materialized_series());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    pub fn daily_climatology(
+        &self,
+        smooth_window_days: usize,
+    ) -> Result<DataFrame, MeteostatError> {
+        // Pool February 29 with February 28 and shift later leap-year days back by one
+        let ordinal = col("date").dt().ordinal_day().cast(DataType::Int64);
+        let day_of_year = when(
+            col("date")
+                .dt()
+                .is_leap_year()
+                .and(ordinal.clone().gt(lit(59))),
+        )
+        .then(ordinal.clone() - lit(1))
+        .otherwise(ordinal)
+        .alias("day_of_year");
+        let means = self
+            .frame
+            .clone()
+            .group_by([day_of_year])
+            .agg(
+                CLIMATOLOGY_FIELDS
+                    .map(|field| col(field.column_name()).cast(DataType::Float64).mean()),
+            )
+            .collect()?;
+        let days: Vec<Option<usize>> = column_i64(means.column("day_of_year")?)?
+            .iter()
+            .map(|day| {
+                let index = usize::try_from(day? - 1).ok()?;
+                (index < CLIMATOLOGY_DAYS).then_some(index)
+            })
+            .collect();
+
+        let calendar: Vec<NaiveDate> = (1..=CLIMATOLOGY_DAYS as u32)
+            .filter_map(|day| NaiveDate::from_yo_opt(2023, day))
+            .collect();
+        let mut columns = vec![
+            Column::new(
+                "day_of_year".into(),
+                (1..=CLIMATOLOGY_DAYS as i64).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "month".into(),
+                calendar
+                    .iter()
+                    .map(|date| i64::from(date.month()))
+                    .collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "day".into(),
+                calendar
+                    .iter()
+                    .map(|date| i64::from(date.day()))
+                    .collect::<Vec<_>>(),
+            ),
+        ];
+
+        let half_window = smooth_window_days.min(CLIMATOLOGY_DAYS) / 2;
+        for field in CLIMATOLOGY_FIELDS {
+            let mut per_day = [None; CLIMATOLOGY_DAYS];
+            let values = column_f64(means.column(field.column_name())?)?;
+            for (day, value) in days.iter().zip(values.iter()) {
+                if let Some(day) = day {
+                    per_day[*day] = value;
+                }
+            }
+            let smoothed: Vec<Option<f64>> = (0..CLIMATOLOGY_DAYS)
+                .map(|day| {
+                    let (sum, count) = (0..=2 * half_window)
+                        .filter_map(|offset| {
+                            per_day
+                                [(day + CLIMATOLOGY_DAYS + offset - half_window) % CLIMATOLOGY_DAYS]
+                        })
+                        .fold((0.0, 0_usize), |(sum, count), value| {
+                            (sum + value, count + 1)
+                        });
+                    (count > 0).then(|| sum / count as f64)
+                })
+                .collect();
+            columns.push(Column::new(field.column_name().into(), smoothed));
+        }
+        Ok(DataFrame::new(CLIMATOLOGY_DAYS, columns)?)
     }
 
-    /// Executes the lazy query and collects the results into a `Vec<Daily>`.
+    /// Fits a linear correction that maps this station's values of a field onto a reference
+    /// station's values, for bias correction or homogenization.
     ///
-    /// This method triggers the computation defined by the `LazyFrame` (including any
-    /// previous filtering operations) and maps each resulting row to a `Daily` struct.
-    /// Rows where the essential 'date' column is missing or invalid are skipped.
+    /// The coefficients come from an ordinary least squares fit of
+    /// `reference = slope * self + intercept` over the days on which both frames have a value.
+    /// Pass them to [`DailyLazyFrame::apply_linear_correction`] to correct this station's data,
+    /// e.g. outside the overlap period. Fitting and applying are separate so the coefficients
+    /// can be stored and reused.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The frame of the station to match, e.g. a long-running, well-maintained
+    ///   station nearby.
+    /// * `field` - The [`DailyField`] to fit.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `Vec<Daily>` on success, or a [`MeteostatError`]
-    /// if the computation or mapping fails.
+    /// A `Result` containing the `(slope, intercept)` of the correction.
     ///
     /// # Errors
     ///
-    /// This method can return a [`MeteostatError::PolarsError`] in two main scenarios:
-    /// 1.  The lazy computation fails when `.collect()` is called on the `LazyFrame`.
-    /// 2.  The resulting `DataFrame` does not have the expected schema (e.g., a required
-    ///     column is missing or has an incorrect data type for conversion).
+    /// Returns [`MeteostatError::InsufficientOverlap`] if fewer than 2 days overlap, or this
+    /// station's values don't vary over the overlap, and [`MeteostatError::PolarsError`] if the
+    /// lazy computation fails.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// # use meteostat::{Meteostat, MeteostatError, LatLon, Year, Daily};
-    /// use polars::prelude::PolarsError;
-    ///
+    /// # use meteostat::{DailyField, Meteostat, MeteostatError, Year};
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn main() -> Result<(), MeteostatError> {
     /// let client = Meteostat::new().await?;
-    /// let paris = LatLon(48.85, 2.35);
-    ///
-    /// let daily_lazy = client
-    ///     .daily()
-    ///     .location(paris)
-    ///     .call()
-    ///     .await?;
-    ///
-    /// // Get data for a specific year
-    /// let year_data_lazy = daily_lazy.get_for_period(Year(2022))?;
-    ///
-    /// // Collect into Vec<Daily>
-    /// let daily_vec: Vec<Daily> = year_data_lazy.collect_daily()?;
+    /// let station = client.daily().station("06260").call().await?; // De Bilt
+    /// let reference = client.daily().station("06240").call().await?; // Schiphol
     ///
-    /// println!("Collected {} daily records for 2022.", daily_vec.len());
-    /// if let Some(first_day) = daily_vec.first() {
-    ///     println!("First record: {:?}", first_day);
-    /// }
+    /// let field = DailyField::MaximumTemperature;
+    /// let (slope, intercept) = station
+    ///     .get_range(Year(2000), Year(2020))?
+    ///     .fit_linear_correction(&reference, field)?;
+    /// let corrected = station.apply_linear_correction(slope, intercept, field);
+    /// println!("{}", corrected.frame.collect()?);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn collect_daily(&self) -> Result<Vec<Daily>, MeteostatError> {
-        let df = self
+    #[allow(clippy::cast_precision_loss)]
+    pub fn fit_linear_correction(
+        &self,
+        reference: &Self,
+        field: DailyField,
+    ) -> Result<(f64, f64), MeteostatError> {
+        let value = || col(field.column_name()).cast(DataType::Float64);
+        let pairs = self
             .frame
             .clone()
-            .collect()
-            .map_err(MeteostatError::PolarsError)?;
+            .select([col("date"), value().alias("x")])
+            .join(
+                reference
+                    .frame
+                    .clone()
+                    .select([col("date"), value().alias("y")]),
+                [col("date")],
+                [col("date")],
+                JoinArgs::new(JoinType::Inner),
+            )
+            .drop_nulls(None)
+            .collect()?;
+        let xs = pairs.column("x")?.f64()?;
+        let ys = pairs.column("y")?.f64()?;
+        let points: Vec<(f64, f64)> = (0..pairs.height())
+            .filter_map(|i| Some((xs.get(i)?, ys.get(i)?)))
+            .collect();
+
+        let n = points.len();
+        if n < 2 {
+            return Err(MeteostatError::InsufficientOverlap(n));
+        }
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n as f64;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n as f64;
+        let (covariance, variance) =
+            points
+                .iter()
+                .fold((0.0, 0.0), |(covariance, variance), (x, y)| {
+                    let dx = x - mean_x;
+                    (dx.mul_add(y - mean_y, covariance), dx.mul_add(dx, variance))
+                });
+        if variance == 0.0 {
+            return Err(MeteostatError::InsufficientOverlap(n));
+        }
+        let slope = covariance / variance;
+        Ok((slope, slope.mul_add(-mean_x, mean_y)))
+    }
 
-        Self::dataframe_to_daily_vec(&df)
+    /// Applies a linear correction `slope * value + intercept` to a field, e.g. with
+    /// coefficients from [`DailyLazyFrame::fit_linear_correction`].
+    ///
+    /// Missing values stay missing. Integer fields (snow, wind direction and sunshine
+    /// minutes) are rounded to the nearest whole number, so the frame keeps its schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `slope` - The factor to multiply each value by.
+    /// * `intercept` - The offset to add after scaling.
+    /// * `field` - The [`DailyField`] to correct.
+    ///
+    /// # Returns
+    ///
+    /// A new `DailyLazyFrame` with the corrected field, computed lazily.
+    #[must_use]
+    pub fn apply_linear_correction(&self, slope: f64, intercept: f64, field: DailyField) -> Self {
+        let name = field.column_name();
+        let corrected = col(name).cast(DataType::Float64) * lit(slope) + lit(intercept);
+        let corrected = if field.is_integer() {
+            round_to_i64(corrected)
+        } else {
+            corrected
+        };
+        Self::new(self.frame.clone().with_column(corrected.alias(name)))
     }
 
-    /// Executes the lazy query, expecting exactly one row, and collects it into a `Daily` struct.
+    /// Counts the days with snow on the ground of at least `min_depth_mm`.
     ///
-    /// This is useful after filtering the frame down to a single expected record,
-    /// for example using `get_at()`.
+    /// Days without a snow depth never count as snow days; `missing` decides whether they
+    /// still count towards [`SnowDays::valid_days`].
+    ///
+    /// # Arguments
+    ///
+    /// * `min_depth_mm` - The minimum snow depth in mm. Values below 1 count any snow cover.
+    /// * `missing` - How to treat days without a snow depth, see [`MissingSnow`].
     ///
     /// # Returns
     ///
-    /// A `Result` containing the single `Daily` struct on success.
+    /// A `Result` containing the [`SnowDays`] counts.
     ///
     /// # Errors
     ///
-    /// *   Returns [`MeteostatError::ExpectedSingleRow`] if the collected `DataFrame` does not
-    ///     contain exactly one row.
-    /// *   Returns [`MeteostatError::PolarsError`] if the lazy computation fails or if the
-    ///     resulting `DataFrame` has an unexpected schema (missing columns or wrong data types).
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// # use meteostat::{Meteostat, MeteostatError, LatLon, Daily};
-    /// use chrono::NaiveDate;
-    /// use polars::prelude::PolarsError;
-    ///
+    /// # use meteostat::{Meteostat, MeteostatError, MissingSnow, Year};
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), MeteostatError> {
     /// let client = Meteostat::new().await?;
-    /// let station_id = "10384"; // Berlin Tempelhof
-    ///
-    /// let daily_lazy = client.daily().station(station_id).call().await?;
-    ///
-    /// // Get data for a specific day
-    /// let target_date = NaiveDate::from_ymd_opt(2022, 8, 15).unwrap();
-    /// let single_day_lazy = daily_lazy.get_at(target_date)?;
+    /// let daily_lazy = client.daily().station("10961").call().await?; // Zugspitze
     ///
-    /// // Collect the single expected row
-    /// match single_day_lazy.collect_single_daily() {
-    ///     Ok(daily_data) => {
-    ///         println!("Collected single day data: {:?}", daily_data);
-    ///         assert_eq!(daily_data.date, target_date); // Verify correct date
-    ///     },
-    ///     Err(MeteostatError::ExpectedSingleRow { actual }) => {
-    ///          println!("Expected 1 row, but found {}.", actual);
-    ///     },
-    ///     Err(e) => return Err(e),
-    /// }
+    /// let powder_days = daily_lazy
+    ///     .get_for_period(Year(2023))?
+    ///     .snow_days(300, MissingSnow::Skip)?;
+    /// println!("{} days with at least 30 cm of snow", powder_days.snow_days);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn collect_single_daily(&self) -> Result<Daily, MeteostatError> {
+    pub fn snow_days(
+        &self,
+        min_depth_mm: i32,
+        missing: MissingSnow,
+    ) -> Result<SnowDays, MeteostatError> {
+        let snow = col(DailyField::Snow.column_name());
+        let valid = match missing {
+            MissingSnow::TreatAsZero => len(),
+            MissingSnow::Skip => snow.clone().count(),
+        };
+        let (snow_days, valid_days) =
+            self.count_days(snow.gt_eq(lit(i64::from(min_depth_mm.max(1)))), valid)?;
+        Ok(SnowDays {
+            snow_days,
+            valid_days,
+        })
+    }
+
+    /// Counts the days for which `matching` is true, and evaluates the `valid` day count, in
+    /// one pass. Shared by [`DailyLazyFrame::exceedance_count`] and
+    /// [`DailyLazyFrame::snow_days`].
+    fn count_days(&self, matching: Expr, valid: Expr) -> Result<(usize, usize), MeteostatError> {
+        let counts = self
+            .frame
+            .clone()
+            .select([
+                matching.cast(DataType::UInt32).sum().alias("matching"),
+                valid.alias("valid"),
+            ])
+            .collect()?;
+        let count_at = |name: &str| -> Result<usize, MeteostatError> {
+            let column = counts.column(name)?.cast(&DataType::UInt64)?;
+            Ok(usize::try_from(column.u64()?.get(0).unwrap_or(0)).unwrap_or(usize::MAX))
+        };
+        Ok((count_at("matching")?, count_at("valid")?))
+    }
+
+    /// Returns the first and last date in the frame with snow on the ground.
+    ///
+    /// Only days with a snow depth above 0 mm count; days without a snow depth are ignored,
+    /// since they can't show that there was snow. Filter the frame to one winter first (e.g.
+    /// with [`DailyLazyFrame::get_range`]) to get the bounds of a single season.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `Some((first, last))`, or `None` if no day in the frame has snow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    pub fn snow_season_bounds(&self) -> Result<Option<(NaiveDate, NaiveDate)>, MeteostatError> {
+        let snow_dates = col("date").filter(col(DailyField::Snow.column_name()).gt(lit(0)));
         let df = self
             .frame
             .clone()
-            .collect()
-            .map_err(MeteostatError::PolarsError)?;
+            .select([
+                snow_dates.clone().min().alias("first"),
+                snow_dates.max().alias("last"),
+            ])
+            .collect()?;
+        let date_at = |name: &str| -> Result<Option<NaiveDate>, MeteostatError> {
+            Ok(df
+                .column(name)?
+                .date()?
+                .phys
+                .get(0)
+                .and_then(polars_date_to_naive))
+        };
+        Ok(date_at("first")?.zip(date_at("last")?))
+    }
 
-        if df.height() != 1 {
-            return Err(MeteostatError::ExpectedSingleRow {
-                actual: df.height(),
-            });
+    // --- Helper to sort records by date and split them into consecutive groups ---
+    fn group_sorted_by<K: PartialEq>(
+        mut days: Vec<Daily>,
+        key: impl Fn(&Daily) -> K,
+    ) -> Vec<(K, Vec<Daily>)> {
+        days.sort_by_key(|d| d.date);
+
+        let mut groups: Vec<(K, Vec<Daily>)> = Vec::new();
+        for day in days {
+            let day_key = key(&day);
+            match groups.last_mut() {
+                Some((last_key, group)) if *last_key == day_key => group.push(day),
+                _ => groups.push((day_key, vec![day])),
+            }
         }
-
-        // Use the Vec conversion helper and take the guaranteed single element
-        Self::dataframe_to_daily_vec(&df)?
-            .pop() // Take the only element
-            .ok_or(MeteostatError::ExpectedSingleRow { actual: 0 }) // Should be unreachable after height check
+        groups
     }
 
     // --- Helper function to map DataFrame rows to Vec<Daily> ---
@@ -358,33 +1614,27 @@ impl DailyLazyFrame {
         let pres_series = df.column("pres")?;
         let tsun_series = df.column("tsun")?;
 
-        // --- Get ChunkedArrays (handle potential type variations if needed) ---
+        // --- Get ChunkedArrays (numeric columns are cast from whatever type Polars read) ---
         let date_ca = date_series.date()?;
-        let tavg_ca = tavg_series.f64()?;
-        let tmin_ca = tmin_series.f64()?;
-        let tmax_ca = tmax_series.f64()?;
-        let prcp_ca = prcp_series.f64()?;
-        let snow_ca = snow_series.i64()?;
-        let wdir_ca = wdir_series.i64()?;
-        let wspd_ca = wspd_series.f64()?;
-        let wpgt_ca = wpgt_series.f64()?;
-        let pres_ca = pres_series.f64()?;
-        let tsun_ca = tsun_series.i64()?;
+        let tavg_ca = column_f64(tavg_series)?;
+        let tmin_ca = column_f64(tmin_series)?;
+        let tmax_ca = column_f64(tmax_series)?;
+        let prcp_ca = column_f64(prcp_series)?;
+        let snow_ca = column_i64(snow_series)?;
+        let wdir_ca = column_i64(wdir_series)?;
+        let wspd_ca = column_f64(wspd_series)?;
+        let wpgt_ca = column_f64(wpgt_series)?;
+        let pres_ca = column_f64(pres_series)?;
+        let tsun_ca = column_i64(tsun_series)?;
+        // Optional provenance column, only present in some source files
+        let source_ca = df.column(SOURCE_COLUMN).ok().map(Column::str).transpose()?;
 
         let mut daily_vec = Vec::with_capacity(df.height());
 
-        // --- Define epoch once for date conversion ---
-        // Polars Date type stores days since UNIX_EPOCH (1970-01-01)
-        let epoch_date =
-            NaiveDate::from_ymd_opt(1970, 1, 1).expect("Failed to create epoch NaiveDate");
-
         // --- Iterate through rows and map ---
         for i in 0..df.height() {
             // Get date (essential) - skip row if missing/invalid
-            let date_opt: Option<NaiveDate> = date_ca
-                .phys
-                .get(i)
-                .map(|days_since_epoch| epoch_date + Duration::days(i64::from(days_since_epoch)));
+            let date_opt: Option<NaiveDate> = date_ca.phys.get(i).and_then(polars_date_to_naive);
 
             let Some(date) = date_opt else {
                 // Skip row if date is missing or invalid
@@ -404,6 +1654,7 @@ impl DailyLazyFrame {
                 peak_wind_gust: wpgt_ca.get(i),
                 pressure: pres_ca.get(i),
                 sunshine_minutes: tsun_ca.get(i).and_then(|v| i32::try_from(v).ok()), // Convert Option<i64> to Option<i32>
+                source: source_ca.and_then(|ca| ca.get(i)).map(str::to_string),
             };
 
             daily_vec.push(daily_record);
@@ -416,9 +1667,8 @@ impl DailyLazyFrame {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Meteostat, MeteostatError, Year};
-    use chrono::{Datelike, NaiveDate};
-    use polars::prelude::{DataType, PlSmallStr};
+    use crate::{Meteostat, MeteostatError, Month, WeatherComparison, Year};
+    use polars::prelude::{DataType, IntoLazy, PlSmallStr};
 
     // Helper to fetch daily data for tests - uses Berlin Tempelhof ("10384")
     // This station usually has extensive daily records.
@@ -480,12 +1730,6 @@ mod tests {
         Ok(())
     }
 
-    // Helper function to convert polars date int to NaiveDate
-    fn polars_date_to_naive(days: i32) -> NaiveDate {
-        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
-        epoch + Duration::days(i64::from(days))
-    }
-
     #[tokio::test(flavor = "multi_thread")]
     async fn test_daily_frame_get_at_specific_date() -> Result<(), Box<dyn std::error::Error>> {
         let daily_lazy = get_test_daily_frame().await?;
@@ -505,7 +1749,7 @@ mod tests {
         // Verify the date in that row
         let date_series = df.column("date")?.date()?;
         let retrieved_date_int = date_series.phys.get(0).unwrap(); // Get date as i32 days since epoch
-        let actual_date = polars_date_to_naive(retrieved_date_int);
+        let actual_date = polars_date_to_naive(retrieved_date_int).unwrap();
 
         assert_eq!(actual_date, target_date);
 
@@ -533,8 +1777,7 @@ mod tests {
         let date_series = df.column("date")?.date()?;
         assert!(date_series.phys.iter().all(|opt_date_int| {
             opt_date_int.is_some_and(|di| {
-                let d = polars_date_to_naive(di);
-                d >= start_date && d <= end_date
+                polars_date_to_naive(di).is_some_and(|d| d >= start_date && d <= end_date)
             })
         }));
 
@@ -569,7 +1812,9 @@ mod tests {
         // Verify all dates are within the target year
         let date_series = df.column("date")?.date()?;
         assert!(date_series.phys.iter().all(|opt_date_int| {
-            opt_date_int.is_some_and(|di| polars_date_to_naive(di).year() == target_year.get())
+            opt_date_int
+                .and_then(polars_date_to_naive)
+                .is_some_and(|d| d.year() == target_year.get())
         }));
 
         Ok(())
@@ -619,7 +1864,7 @@ mod tests {
             for i in 0..df.height() {
                 let date_int = date_series.phys.get(i).unwrap();
                 let prcp_val = prcp_series.get(i).unwrap_or(0.0); // Default to 0 if null
-                let date = polars_date_to_naive(date_int);
+                let date = polars_date_to_naive(date_int).unwrap();
 
                 assert_eq!(date.year(), target_year.get());
                 assert!(prcp_val > 5.0);
@@ -750,4 +1995,468 @@ mod tests {
 
         Ok(())
     }
+
+    // --- Grouping ---
+
+    fn test_day(date: &str) -> Daily {
+        Daily {
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            average_temperature: None,
+            minimum_temperature: None,
+            maximum_temperature: None,
+            precipitation: None,
+            snow: None,
+            wind_direction: None,
+            wind_speed: None,
+            peak_wind_gust: None,
+            pressure: None,
+            sunshine_minutes: None,
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_daily_display_renders_units_and_missing_values() {
+        let mut day = test_day("2023-01-01");
+        day.average_temperature = Some(5.1);
+        day.precipitation = Some(1.2);
+        day.sunshine_minutes = Some(60);
+
+        assert_eq!(
+            day.to_string(),
+            "2023-01-01: avg 5.1 °C (min n/a, max n/a), precipitation 1.2 mm, wind n/a, \
+             pressure n/a, sunshine 60 min"
+        );
+    }
+
+    #[test]
+    fn test_daily_dewpoint_estimate() {
+        let mut day = test_day("2023-01-01");
+        assert_eq!(day.dewpoint_estimate(50.0), None);
+
+        day.average_temperature = Some(20.0);
+        let dewpoint = day.dewpoint_estimate(50.0).unwrap();
+        assert!((dewpoint - 9.26).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_daily_comparisons_handle_missing_values() {
+        let mut warm = test_day("2023-07-01");
+        warm.average_temperature = Some(24.5);
+        warm.minimum_temperature = Some(16.0);
+        warm.maximum_temperature = Some(31.0);
+        warm.precipitation = Some(0.0);
+        let mut cool = test_day("2023-07-02");
+        cool.average_temperature = Some(18.0);
+        cool.precipitation = Some(4.2);
+        let unknown = test_day("2023-07-03");
+
+        assert_eq!(warm.temperature_difference(&cool), Some(6.5));
+        assert_eq!(warm.is_warmer_than(&cool), Some(true));
+        assert_eq!(warm.is_colder_than(&cool), Some(false));
+        assert_eq!(cool.is_wetter_than(&warm), Some(true));
+        assert_eq!(warm.temperature_range(), Some(15.0));
+
+        assert_eq!(warm.is_warmer_than(&unknown), None);
+        assert_eq!(unknown.is_wetter_than(&cool), None);
+        assert_eq!(cool.temperature_range(), None);
+    }
+
+    #[test]
+    fn test_daily_sunshine_duration() {
+        let mut day = test_day("2023-06-21");
+        assert_eq!(day.sunshine_duration(), None);
+
+        day.sunshine_minutes = Some(390);
+        assert_eq!(
+            day.sunshine_duration(),
+            Some(Duration::hours(6) + Duration::minutes(30))
+        );
+    }
+
+    #[test]
+    fn test_row_count_matches_filtered_height() -> Result<(), MeteostatError> {
+        let df = polars::df!(
+            "date" => [
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 1, 2).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 2, 1).unwrap(),
+            ],
+            "tavg" => [Some(1.0), None, Some(3.0)],
+        )?;
+        let frame = DailyLazyFrame::new(df.lazy());
+
+        assert_eq!(frame.row_count()?, 3);
+        assert_eq!(frame.filter(col("tavg").is_not_null()).row_count()?, 2);
+        Ok(())
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_to_matrix_maps_nulls_to_nan() -> Result<(), MeteostatError> {
+        let df = polars::df!(
+            "date" => [
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 1, 2).unwrap(),
+            ],
+            "tmin" => [Some(-1.5), None],
+            "wdir" => [Some(180_i64), Some(270)],
+        )?;
+        let frame = DailyLazyFrame::new(df.lazy());
+
+        let (dates, matrix) =
+            frame.to_matrix(&[DailyField::MinimumTemperature, DailyField::WindDirection])?;
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 1, 2).unwrap(),
+            ]
+        );
+        assert_eq!(matrix.shape(), &[2, 2]);
+        assert!((matrix[[0, 0]] + 1.5).abs() < f64::EPSILON);
+        assert!(matrix[[1, 0]].is_nan());
+        assert!((matrix[[1, 1]] - 270.0).abs() < f64::EPSILON);
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_sorted_by_month_is_chronological() {
+        let days = vec![
+            test_day("2023-01-02"),
+            test_day("2022-12-31"),
+            test_day("2023-01-01"),
+            test_day("2022-12-30"),
+        ];
+
+        let groups = DailyLazyFrame::group_sorted_by(days, |d| (d.date.year(), d.date.month()));
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, (2022, 12));
+        assert_eq!(groups[1].0, (2023, 1));
+        let dates: Vec<_> = groups[1].1.iter().map(|d| d.date.day()).collect();
+        assert_eq!(dates, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_open_ended_ranges() -> Result<(), MeteostatError> {
+        let csv = "2019-12-31,1.0,,,,,,,,,\n\
+                   2020-01-01,2.0,,,,,,,,,\n\
+                   2021-06-15,3.0,,,,,,,,,\n";
+        let frame = crate::parse_daily_csv(csv.as_bytes())?;
+
+        let from: Vec<_> = frame
+            .get_range_from(Year(2020))?
+            .collect_daily()?
+            .average_temperatures()
+            .collect();
+        assert_eq!(from, vec![Some(2.0), Some(3.0)]);
+        let until: Vec<_> = frame
+            .get_range_until(Year(2020))?
+            .collect_daily()?
+            .average_temperatures()
+            .collect();
+        assert_eq!(until, vec![Some(1.0), Some(2.0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_for_periods_combines_disjoint_periods() -> Result<(), MeteostatError> {
+        let csv = "2010-07-01,1.0,,,,,,,,,\n\
+                   2011-07-01,2.0,,,,,,,,,\n\
+                   2015-07-31,3.0,,,,,,,,,\n\
+                   2015-08-01,4.0,,,,,,,,,\n";
+        let frame = crate::parse_daily_csv(csv.as_bytes())?;
+
+        let julys: Vec<_> = frame
+            .get_for_periods([Month(2015, 7), Month(2010, 7), Month(2010, 7)])?
+            .collect_daily()?
+            .average_temperatures()
+            .collect();
+        assert_eq!(julys, vec![Some(1.0), Some(3.0)]);
+        assert_eq!(frame.get_for_periods(Vec::<Year>::new())?.row_count()?, 0);
+        assert!(matches!(
+            frame.get_for_periods([Month(2015, 13)]),
+            Err(MeteostatError::DateParsingError)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_daily_climatology_pools_leap_days_and_wraps_around() -> Result<(), MeteostatError> {
+        // date,tavg,tmin,tmax,prcp,snow,wdir,wspd,wpgt,pres,tsun
+        let csv = "2023-01-01,,,10.0,,,,,,,\n\
+                   2024-01-01,,,20.0,,,,,,,\n\
+                   2023-02-28,,,3.0,,,,,,,\n\
+                   2024-02-29,,,5.0,,,,,,,\n\
+                   2024-03-01,,,7.0,,,,,,,\n\
+                   2023-12-31,,,1.0,,,,,,,\n";
+        let frame = crate::parse_daily_csv(csv.as_bytes())?;
+
+        let raw = frame.daily_climatology(0)?;
+        assert_eq!(raw.height(), 365);
+        let tmax = raw.column("tmax")?.f64()?;
+        assert_eq!(tmax.get(0), Some(15.0));
+        assert_eq!(tmax.get(58), Some(4.0));
+        assert_eq!(tmax.get(59), Some(7.0));
+        assert_eq!(tmax.get(1), None);
+        assert_eq!(raw.column("month")?.i64()?.get(59), Some(3));
+        assert_eq!(raw.column("day")?.i64()?.get(59), Some(1));
+        assert!(raw.column("wdir").is_err());
+
+        let smoothed = frame.daily_climatology(3)?;
+        let tmax = smoothed.column("tmax")?.f64()?;
+        // January 1 averages December 31 and January 1, January 2 has no data of its own
+        assert_eq!(tmax.get(0), Some(8.0));
+        assert_eq!(tmax.get(1), Some(15.0));
+        assert_eq!(tmax.get(364), Some(8.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_quantile_skips_nulls() -> Result<(), MeteostatError> {
+        // date,tavg,tmin,tmax,prcp,snow,wdir,wspd,wpgt,pres,tsun
+        let csv = "2023-01-01,,,,0.0,,,,,,\n\
+                   2023-01-02,,,,,,,,,,\n\
+                   2023-01-03,,,,10.0,,,,,,\n\
+                   2023-01-04,,,,20.0,,,,,,\n";
+        let frame = crate::parse_daily_csv(csv.as_bytes())?;
+        let field = DailyField::Precipitation;
+
+        assert_eq!(
+            frame.quantile(field, 0.5, QuantileMethod::Linear)?,
+            Some(10.0)
+        );
+        assert_eq!(
+            frame.quantile(field, 0.75, QuantileMethod::Linear)?,
+            Some(15.0)
+        );
+        assert_eq!(
+            frame.quantile(field, 0.75, QuantileMethod::Lower)?,
+            Some(10.0)
+        );
+        assert_eq!(
+            frame.quantile(DailyField::Snow, 0.5, QuantileMethod::Linear)?,
+            None
+        );
+        assert!(matches!(
+            frame.quantile(field, 95.0, QuantileMethod::Linear),
+            Err(MeteostatError::InvalidQuantile(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_extremes_ignore_nulls_and_prefer_earliest_date() -> Result<(), MeteostatError> {
+        // date,tavg,tmin,tmax,prcp,snow,wdir,wspd,wpgt,pres,tsun
+        let csv = "2023-07-02,20.0,12.0,31.5,0.0,,,10.0,,1015.0,\n\
+                   2023-07-01,22.0,14.0,31.5,12.4,,,8.0,,1008.0,\n\
+                   2023-07-03,,10.5,,,,,,,1020.0,\n";
+        let extremes = crate::parse_daily_csv(csv.as_bytes())?.extremes()?;
+
+        let date = |day| NaiveDate::from_ymd_opt(2023, 7, day).unwrap_or_default();
+        assert_eq!(
+            extremes.highest_maximum_temperature,
+            Some(DailyExtreme {
+                date: date(1),
+                value: 31.5
+            })
+        );
+        assert_eq!(
+            extremes.lowest_minimum_temperature.map(|e| e.date),
+            Some(date(3))
+        );
+        assert_eq!(
+            extremes.lowest_average_temperature.map(|e| e.value),
+            Some(20.0)
+        );
+        assert_eq!(extremes.highest_precipitation.map(|e| e.value), Some(12.4));
+        assert_eq!(extremes.lowest_pressure.map(|e| e.date), Some(date(1)));
+        assert_eq!(extremes.highest_peak_wind_gust, None);
+        assert_eq!(extremes.highest_snow, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_exceedance_count_against_baseline() -> Result<(), MeteostatError> {
+        // date,tavg,tmin,tmax,... with tmax 1..=10 in the baseline year
+        let baseline_csv: String = (1..=10)
+            .map(|day| format!("2000-01-{day:02},,,{day}.0,,,,,,,\n"))
+            .collect();
+        let target_csv = "2023-01-01,,,9.0,,,,,,,\n\
+                          2023-01-02,,,9.5,,,,,,,\n\
+                          2023-01-03,,,12.0,,,,,,,\n\
+                          2023-01-04,,,,,,,,,,\n";
+        let baseline = crate::parse_daily_csv(baseline_csv.as_bytes())?;
+        let target = crate::parse_daily_csv(target_csv.as_bytes())?;
+
+        // The 90th percentile of 1..=10 with linear interpolation is 9.1
+        let result = target.exceedance_count(DailyField::MaximumTemperature, 90.0, &baseline)?;
+        assert!((result.threshold.unwrap_or_default() - 9.1).abs() < 1e-9);
+        assert_eq!(result.exceeding_days, 2);
+        assert_eq!(result.valid_days, 3);
+        assert!((result.percentage().unwrap_or_default() - 200.0 / 3.0).abs() < 1e-9);
+
+        assert!(matches!(
+            target.exceedance_count(DailyField::MaximumTemperature, 101.0, &baseline),
+            Err(MeteostatError::InvalidPercentile(_))
+        ));
+        let empty = target.exceedance_count(DailyField::Snow, 90.0, &baseline)?;
+        assert_eq!(empty.threshold, None);
+        assert_eq!(empty.percentage(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fit_and_apply_linear_correction() -> Result<(), MeteostatError> {
+        // date,tavg,tmin,tmax,prcp,snow,... with the reference at 2 * tmax + 1 on shared days
+        let station_csv: String = (1..=6)
+            .map(|day| format!("2023-01-{day:02},,,{day}.0,,{},,,,,\n", day * 10))
+            .chain(["2023-01-07,,,,,,,,,,\n".to_string()])
+            .collect();
+        let reference_csv: String = (2..=9)
+            .map(|day| format!("2023-01-{day:02},,,{}.0,,,,,,,\n", 2 * day + 1))
+            .collect();
+        let station = crate::parse_daily_csv(station_csv.as_bytes())?;
+        let reference = crate::parse_daily_csv(reference_csv.as_bytes())?;
+
+        let field = DailyField::MaximumTemperature;
+        let (slope, intercept) = station.fit_linear_correction(&reference, field)?;
+        assert!((slope - 2.0).abs() < 1e-9);
+        assert!((intercept - 1.0).abs() < 1e-9);
+
+        let corrected = station
+            .apply_linear_correction(slope, intercept, field)
+            .collect_daily()?;
+        assert!((corrected[0].maximum_temperature.unwrap_or_default() - 3.0).abs() < 1e-9);
+        assert_eq!(corrected[6].maximum_temperature, None);
+
+        // Integer fields are rounded and keep their type
+        let snow = station
+            .apply_linear_correction(0.25, 0.0, DailyField::Snow)
+            .collect_daily()?;
+        let depths: Vec<Option<i32>> = snow.iter().map(|d| d.snow).collect();
+        assert_eq!(
+            depths,
+            vec![
+                Some(3),
+                Some(5),
+                Some(8),
+                Some(10),
+                Some(13),
+                Some(15),
+                None
+            ]
+        );
+
+        assert!(matches!(
+            station.fit_linear_correction(&reference, DailyField::Pressure),
+            Err(MeteostatError::InsufficientOverlap(0))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_snow_days_and_season_bounds() -> Result<(), MeteostatError> {
+        // date,tavg,tmin,tmax,prcp,snow,...
+        let csv = "2022-11-30,,,,,,,,,,\n\
+                   2022-12-01,,,,,0,,,,,\n\
+                   2022-12-02,,,,,40,,,,,\n\
+                   2022-12-03,,,,,,,,,,\n\
+                   2022-12-04,,,,,120,,,,,\n\
+                   2022-12-05,,,,,5,,,,,\n\
+                   2022-12-06,,,,,0,,,,,\n";
+        let daily_lazy = crate::parse_daily_csv(csv.as_bytes())?;
+
+        let skip = daily_lazy.snow_days(0, MissingSnow::Skip)?;
+        assert_eq!(skip.snow_days, 3);
+        assert_eq!(skip.valid_days, 5);
+        let as_zero = daily_lazy.snow_days(40, MissingSnow::TreatAsZero)?;
+        assert_eq!(as_zero.snow_days, 2);
+        assert_eq!(as_zero.valid_days, 7);
+
+        let bounds = daily_lazy.snow_season_bounds()?;
+        let date = |day| NaiveDate::from_ymd_opt(2022, 12, day).unwrap_or_default();
+        assert_eq!(bounds, Some((date(2), date(5))));
+        assert_eq!(daily_lazy.get_at(date(6))?.snow_season_bounds()?, None);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_group_by_month_for_year() -> Result<(), Box<dyn std::error::Error>> {
+        let daily_lazy = get_test_daily_frame().await?;
+        let groups = daily_lazy.get_for_period(Year(2022))?.group_by_month()?;
+
+        assert_eq!(groups.len(), 12, "Expected a group for every month of 2022");
+        for (i, (year, month, days)) in groups.iter().enumerate() {
+            assert_eq!(*year, 2022);
+            assert_eq!(*month as usize, i + 1);
+            assert!(days.iter().all(|d| d.date.month() == *month));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_since_and_last_n_days() -> Result<(), MeteostatError> {
+        let csv = "2023-02-26,1.0,,,,,,,,,\n\
+                   2023-02-27,2.0,,,,,,,,,\n\
+                   2023-02-28,3.0,,,,,,,,,\n\
+                   2023-03-01,4.0,,,,,,,,,\n\
+                   2023-03-02,5.0,,,,,,,,,\n";
+        let daily_lazy = crate::parse_daily_csv(csv.as_bytes())?;
+        let now = chrono::TimeZone::with_ymd_and_hms(&Utc, 2023, 3, 1, 6, 0, 0).unwrap();
+
+        // 36 hours before now is Feb 27 18:00, so Feb 27 counts as a whole day
+        assert_eq!(
+            daily_lazy
+                .since_at(now, chrono::Duration::hours(36))
+                .row_count()?,
+            3
+        );
+        assert_eq!(daily_lazy.last_n_days_at(now, 2).row_count()?, 2);
+        assert_eq!(daily_lazy.last_n_days_at(now, 0).row_count()?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_units_scientific() -> Result<(), MeteostatError> {
+        // date,tavg,tmin,tmax,prcp,snow,wdir,wspd,wpgt,pres,tsun
+        let csv = "2023-01-01,10.0,5.0,15.0,2.5,,180,36.0,72.0,1013.0,60\n";
+        let daily_lazy = crate::parse_daily_csv(csv.as_bytes())?;
+
+        let metric = daily_lazy.collect_single_daily()?;
+        let from_frame = daily_lazy
+            .with_units(UnitSystem::Scientific)
+            .collect_single_daily()?;
+        let from_struct = metric.clone().with_units(UnitSystem::Scientific);
+        assert_eq!(from_frame, from_struct);
+
+        let close = |value: Option<f64>, expected: f64| {
+            assert!((value.unwrap_or_default() - expected).abs() < 1e-9);
+        };
+        close(from_frame.average_temperature, 283.15);
+        close(from_frame.minimum_temperature, 278.15);
+        close(from_frame.wind_speed, 10.0);
+        close(from_frame.peak_wind_gust, 20.0);
+        close(from_frame.pressure, 101_300.0);
+        close(from_frame.precipitation, 2.5);
+        assert_eq!(from_frame.wind_direction, Some(180));
+        assert_eq!(metric.clone().with_units(UnitSystem::Metric), metric);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_async_collect_matches_sync() -> Result<(), MeteostatError> {
+        let csv = "2023-01-01,1.0,,,,,,,,,\n\
+                   2023-01-02,2.0,,,,,,,,,\n";
+        let frame = crate::parse_daily_csv(csv.as_bytes())?;
+
+        assert_eq!(frame.collect_daily_async().await?, frame.collect_daily()?);
+        assert_eq!(frame.collect_frame_async().await?.height(), 2);
+        assert!(matches!(
+            frame.collect_single_daily_async().await,
+            Err(MeteostatError::ExpectedSingleRow { actual: 2 })
+        ));
+        Ok(())
+    }
 }
@@ -2,12 +2,28 @@
 
 //! Contains the `HourlyLazyFrame` structure for handling lazy operations on Meteostat hourly weather data.
 
+use crate::meteo_calc::circular_mean_degrees;
+use crate::types::frequency_frames::concat_frames;
+use crate::types::frequency_frames::meteostat_frame::{run_blocking, MeteostatFrame};
+use crate::types::frequency_frames::period_filter::PeriodFilter;
+use crate::types::precip_phase::PrecipPhaseRules;
+use crate::types::records::HourlyRecords;
 use crate::types::traits::any::any_datetime::AnyDateTime;
+use crate::types::traits::period::date_period::DatePeriod;
 use crate::types::traits::period::datetime_period::DateTimePeriod;
+use crate::types::unit_system::UnitSystem;
+use crate::types::wind_rose::WindRose;
+use crate::utils::{column_f64, column_i64, lttb_indices, Measurement};
+use crate::weather_data::data_loader::SOURCE_COLUMN;
 use crate::{MeteostatError, WeatherCondition};
 use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Timelike, Utc};
-use polars::prelude::{col, lit, DataFrame, Expr, LazyFrame};
+use polars::prelude::{
+    col, lit, when, Column, DataFrame, DataType, Expr, Float64Chunked, IdxCa, IdxSize, IntoColumn,
+    LazyFrame, SortMultipleOptions, TimeUnit,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
 
 /// Represents a row of hourly weather data, suitable for collecting results.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -34,8 +50,153 @@ pub struct Hourly {
     pub pressure: Option<f64>,
     /// Sunshine duration in minutes.
     pub sunshine_minutes: Option<i32>,
-    /// Weather condition code mapped to an enum.
+    /// Weather condition code mapped to an enum. Codes without a named variant map to
+    /// [`WeatherCondition::Unknown`]; `None` if the code is missing.
     pub condition: Option<WeatherCondition>,
+    /// The raw Meteostat weather condition code (`coco`).
+    pub condition_code: Option<i64>,
+    /// Provenance or quality flag for this record (e.g. measured vs. model-derived), if the
+    /// source file provides one. `None` when the column is absent.
+    pub source: Option<String>,
+}
+
+impl Hourly {
+    /// Returns this record with its temperatures, precipitation, wind speeds and pressure
+    /// converted from Meteostat's metric units into `units`.
+    ///
+    /// The fields don't carry their unit, so only convert a record once. The `Display` output
+    /// keeps labelling the metric units.
+    #[must_use]
+    pub fn with_units(self, units: UnitSystem) -> Self {
+        Self {
+            temperature: self.temperature.map(|value| units.temperature(value)),
+            dew_point: self.dew_point.map(|value| units.temperature(value)),
+            precipitation: self.precipitation.map(|value| units.precipitation(value)),
+            wind_speed: self.wind_speed.map(|value| units.wind_speed(value)),
+            peak_wind_gust: self.peak_wind_gust.map(|value| units.wind_speed(value)),
+            pressure: self.pressure.map(|value| units.pressure(value)),
+            ..self
+        }
+    }
+
+    /// The sunshine duration within the hour as a [`chrono::Duration`], or `None` if missing.
+    #[must_use]
+    pub fn sunshine_duration(&self) -> Option<Duration> {
+        self.sunshine_minutes
+            .map(|minutes| Duration::minutes(i64::from(minutes)))
+    }
+}
+
+/// The numeric fields of an [`Hourly`] record, used to pick the column to aggregate in
+/// methods like [`HourlyLazyFrame::diurnal_mean`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HourlyField {
+    /// Air temperature in Celsius (`temp`).
+    Temperature,
+    /// Dew point in Celsius (`dwpt`).
+    DewPoint,
+    /// Relative humidity in percent (`rhum`).
+    RelativeHumidity,
+    /// Precipitation amount in mm (`prcp`).
+    Precipitation,
+    /// Snow depth in mm (`snow`).
+    Snow,
+    /// Wind direction in degrees (`wdir`).
+    WindDirection,
+    /// Average wind speed in km/h (`wspd`).
+    WindSpeed,
+    /// Peak wind gust speed in km/h (`wpgt`).
+    PeakWindGust,
+    /// Sea-level air pressure in hPa (`pres`).
+    Pressure,
+    /// Sunshine duration in minutes (`tsun`).
+    SunshineMinutes,
+}
+
+impl HourlyField {
+    /// All hourly fields, in the column order of the hourly data frame.
+    pub const ALL: [Self; 10] = [
+        Self::Temperature,
+        Self::DewPoint,
+        Self::RelativeHumidity,
+        Self::Precipitation,
+        Self::Snow,
+        Self::WindDirection,
+        Self::WindSpeed,
+        Self::PeakWindGust,
+        Self::Pressure,
+        Self::SunshineMinutes,
+    ];
+
+    /// The name of the column holding this field in the hourly data frame.
+    #[must_use]
+    pub const fn column_name(self) -> &'static str {
+        match self {
+            Self::Temperature => "temp",
+            Self::DewPoint => "dwpt",
+            Self::RelativeHumidity => "rhum",
+            Self::Precipitation => "prcp",
+            Self::Snow => "snow",
+            Self::WindDirection => "wdir",
+            Self::WindSpeed => "wspd",
+            Self::PeakWindGust => "wpgt",
+            Self::Pressure => "pres",
+            Self::SunshineMinutes => "tsun",
+        }
+    }
+}
+
+/// An irregularity in the `datetime` column of an hourly frame, as found by
+/// [`HourlyLazyFrame::detect_time_anomalies`].
+///
+/// Such irregularities usually point to timestamps recorded in a local timezone (or with a
+/// wrong offset), which shows up as repeated or backwards hours around a DST switch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum TimeAnomaly {
+    /// A record is earlier than the record before it.
+    BackwardsJump {
+        /// The datetime of the preceding record.
+        previous: DateTime<Utc>,
+        /// The datetime that jumps back in time.
+        datetime: DateTime<Utc>,
+    },
+    /// Two consecutive records more than [`HourlyLazyFrame::LARGE_GAP_HOURS`] hours apart.
+    Gap {
+        /// The datetime of the last record before the gap.
+        from: DateTime<Utc>,
+        /// The datetime of the first record after the gap.
+        to: DateTime<Utc>,
+    },
+    /// A datetime that already occurred in an earlier record.
+    DuplicateHour {
+        /// The repeated datetime.
+        datetime: DateTime<Utc>,
+    },
+}
+
+/// Formats the record as a one-line summary with units, rendering missing values as "n/a".
+///
+/// For example: `2023-01-01 06:00 UTC: 5.1 °C, dew point 2 °C, humidity 80 %, precipitation 0 mm,
+/// wind 12 km/h from 240°, pressure 1012.3 hPa, Overcast`.
+impl fmt::Display for Hourly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} UTC: {}, dew point {}, humidity {}, precipitation {}, wind {} from {}, pressure {}, ",
+            self.datetime.format("%Y-%m-%d %H:%M"),
+            Measurement(&self.temperature, " °C"),
+            Measurement(&self.dew_point, " °C"),
+            Measurement(&self.relative_humidity, " %"),
+            Measurement(&self.precipitation, " mm"),
+            Measurement(&self.wind_speed, " km/h"),
+            Measurement(&self.wind_direction, "°"),
+            Measurement(&self.pressure, " hPa"),
+        )?;
+        match self.condition {
+            Some(condition) => write!(f, "{condition}"),
+            None => f.write_str("condition n/a"),
+        }
+    }
 }
 
 /// A wrapper around a Polars `LazyFrame` specifically for Meteostat hourly weather data.
@@ -77,6 +238,10 @@ pub struct HourlyLazyFrame {
 }
 
 impl HourlyLazyFrame {
+    /// Steps between consecutive records longer than this many hours are reported as a
+    /// [`TimeAnomaly::Gap`] by [`HourlyLazyFrame::detect_time_anomalies`].
+    pub const LARGE_GAP_HOURS: i64 = 24;
+
     /// Creates a new `HourlyLazyFrame` wrapping the given Polars `LazyFrame`.
     ///
     /// This is typically called internally by the [`crate::Meteostat`] client methods.
@@ -89,6 +254,17 @@ impl HourlyLazyFrame {
         Self { frame }
     }
 
+    /// Vertically stacks several `HourlyLazyFrame`s into one, e.g. data from several stations.
+    /// See [stacking frames](MeteostatFrame#stacking-frames) for the rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::EmptyConcat`] or [`MeteostatError::SchemaMismatch`] if the
+    /// frames can't be stacked, or [`MeteostatError::PolarsError`] if a schema can't be resolved.
+    pub fn concat(frames: Vec<Self>) -> Result<Self, MeteostatError> {
+        concat_frames(frames.into_iter().map(|lazy| lazy.frame).collect()).map(Self::new)
+    }
+
     /// Filters the hourly data based on a Polars predicate expression.
     ///
     /// This method allows applying arbitrary filtering logic supported by Polars.
@@ -119,155 +295,1009 @@ impl HourlyLazyFrame {
     /// // Filter for hours where temperature was below freezing (0 degrees Celsius)
     /// let freezing_hours = hourly_lazy.filter(col("temp").lt(lit(0.0f64)));
     ///
-    /// // Collect the results into a DataFrame
-    /// let df = freezing_hours.frame.collect()?;
-    /// println!("Freezing hours found:\n{}", df);
+    /// // Collect the results into a DataFrame
+    /// let df = freezing_hours.frame.collect()?;
+    /// println!("Freezing hours found:\n{}", df);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// While this method itself doesn't typically error, subsequent operations like `.collect()`
+    /// might return a [`polars::prelude::PolarsError`].
+    #[must_use]
+    pub fn filter(&self, predicate: Expr) -> Self {
+        Self::new(self.frame.clone().filter(predicate))
+    }
+
+    /// Converts the temperature, wind speed and pressure columns from Meteostat's metric units
+    /// into `units`. Precipitation keeps its values, see [`UnitSystem::Scientific`].
+    ///
+    /// Convert last: methods that compare against metric thresholds, like
+    /// [`HourlyLazyFrame::with_precip_phase`], expect temperatures in °C.
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The unit system to convert into. [`UnitSystem::Metric`] changes nothing.
+    ///
+    /// # Returns
+    ///
+    /// A new `HourlyLazyFrame` with the conversion applied lazily.
+    #[must_use]
+    pub fn with_units(&self, units: UnitSystem) -> Self {
+        let exprs = units.column_exprs(&["temp", "dwpt"], &["wspd", "wpgt"], &["pres"]);
+        if exprs.is_empty() {
+            return self.clone();
+        }
+        Self {
+            frame: self.frame.clone().with_columns(exprs),
+        }
+    }
+
+    /// Filters the hourly data to include only records within the specified datetime range (inclusive).
+    ///
+    /// The `start` and `end` arguments can be any type that implements [`AnyDateTime`],
+    /// such as `DateTime<Utc>`, `NaiveDate`, [`crate::Month`], or [`crate::Year`]. The trait
+    /// resolves these into specific start and end `DateTime<Utc>` values. These are then
+    /// converted to `NaiveDateTime` (representing UTC) for filtering the Polars frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The starting datetime boundary (inclusive), implementing [`AnyDateTime`].
+    /// * `end` - The ending datetime boundary (inclusive), implementing [`AnyDateTime`].
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `HourlyLazyFrame` filtered by the datetime range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if the start or end datetime boundaries
+    /// cannot be resolved into a valid `DateTime<Utc>`.
+    pub fn get_range(
+        &self,
+        start: impl AnyDateTime,
+        end: impl AnyDateTime,
+    ) -> Result<Self, MeteostatError> {
+        PeriodFilter::get_range_from(self, start)?.get_range_until(end)
+    }
+
+    /// Filters the hourly data to include only records at or after `start`, without an end bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The starting datetime boundary (inclusive), implementing [`AnyDateTime`].
+    ///   A range like [`crate::Year`] starts at its first hour.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `HourlyLazyFrame` with the records from `start` onward.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `start` cannot be resolved into a
+    /// valid `DateTime<Utc>`.
+    pub fn get_range_from(&self, start: impl AnyDateTime) -> Result<Self, MeteostatError> {
+        PeriodFilter::get_range_from(self, start)
+    }
+
+    /// Filters the hourly data to include only records at or before `end`, without a start bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - The ending datetime boundary (inclusive), implementing [`AnyDateTime`].
+    ///   A range like [`crate::Year`] ends at its last hour.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `HourlyLazyFrame` with the records up to and including `end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `end` cannot be resolved into a
+    /// valid `DateTime<Utc>`.
+    pub fn get_range_until(&self, end: impl AnyDateTime) -> Result<Self, MeteostatError> {
+        PeriodFilter::get_range_until(self, end)
+    }
+
+    /// Filters the hourly data to the records from `duration` ago up to now.
+    ///
+    /// The range is computed from [`Utc::now`] when this method is called, so a frame built
+    /// once for a long-running dashboard keeps the range of that moment. Meteostat publishes
+    /// data with a delay, so the most recent hours are often not available yet. Negative
+    /// durations are treated as positive.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - How far back to keep records, e.g. `Duration::hours(48)`.
+    ///
+    /// # Returns
+    ///
+    /// A new `HourlyLazyFrame` with the filter applied lazily.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// use chrono::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly_lazy = client.hourly().station("06240").call().await?;
+    ///
+    /// let past_two_days = hourly_lazy.since(Duration::hours(48)).collect_hourly()?;
+    /// println!("{} recent hours", past_two_days.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn since(&self, duration: Duration) -> Self {
+        self.since_at(Utc::now(), duration)
+    }
+
+    /// Filters the hourly data to the last `n` hours, counting the current (UTC) hour as the
+    /// last one. `n = 0` keeps no records.
+    ///
+    /// Like [`HourlyLazyFrame::since`], the range is computed from [`Utc::now`].
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of hours to keep.
+    ///
+    /// # Returns
+    ///
+    /// A new `HourlyLazyFrame` with the filter applied lazily.
+    #[must_use]
+    pub fn last_n_hours(&self, n: u32) -> Self {
+        self.last_n_hours_at(Utc::now(), n)
+    }
+
+    fn since_at(&self, now: DateTime<Utc>, duration: Duration) -> Self {
+        let start = now
+            .checked_sub_signed(duration.abs())
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        self.between(start, now)
+    }
+
+    fn last_n_hours_at(&self, now: DateTime<Utc>, n: u32) -> Self {
+        let current_hour = now
+            .with_minute(0)
+            .and_then(|dt| dt.with_second(0))
+            .and_then(|dt| dt.with_nanosecond(0))
+            .unwrap_or(now);
+        let start = current_hour
+            .checked_sub_signed(Duration::hours(i64::from(n) - 1))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        self.between(start, now)
+    }
+
+    /// Keeps the records from `start` up to and including `end`.
+    fn between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.filter(
+            col("datetime")
+                .gt_eq(lit(start.naive_utc()))
+                .and(col("datetime").lt_eq(lit(end.naive_utc()))),
+        )
+    }
+
+    /// Filters the hourly data to get the record closest to a specific datetime, rounded to the nearest hour.
+    ///
+    /// The `datetime` argument can be any type that implements [`AnyDateTime`]. It is resolved
+    /// to a `DateTime<Utc>`, then rounded to the nearest hour (>= 30 minutes rounds up, < 30 rounds down).
+    /// The filter then looks for an exact match for the *start* of that rounded hour in the frame's
+    /// "datetime" column (as `NaiveDateTime`). To also match timestamps that are slightly off the hour, use
+    /// [`HourlyLazyFrame::get_at_within`].
+    ///
+    /// # Arguments
+    ///
+    /// * `datetime` - The target datetime, implementing [`AnyDateTime`].
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `HourlyLazyFrame` filtered to the specific rounded hour. Collecting
+    /// the frame should yield zero or one row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if the input datetime cannot be
+    /// resolved into a valid `DateTime<Utc>`.
+    ///
+    /// # Panics
+    ///
+    /// * If the datetime can't be truncated to 0 minutes, 0 seconds, 0 nanoseconds.
+    /// * If the (datetime + 1 hour) can't be truncated to 0 minutes, 0 seconds, 0 nanoseconds.
+    pub fn get_at(&self, datetime: impl AnyDateTime) -> Result<Self, MeteostatError> {
+        let date_utc = datetime
+            .get_datetime_range()
+            .ok_or(MeteostatError::DateParsingError)?
+            .start; // Use the start of the range from AnyDateTime
+
+        // Round to the nearest hour
+        let rounded_hour_start_utc = if date_utc.minute() >= 30 {
+            // Round up: Add an hour, then truncate minutes/seconds/nanos
+            (date_utc + Duration::hours(1))
+                .with_minute(0)
+                .and_then(|dt| dt.with_second(0))
+                .and_then(|dt| dt.with_nanosecond(0))
+                .expect("Truncating to start of hour after adding hour failed unexpectedly")
+        } else {
+            // Round down: Truncate minutes/seconds/nanos
+            date_utc
+                .with_minute(0)
+                .and_then(|dt| dt.with_second(0))
+                .and_then(|dt| dt.with_nanosecond(0))
+                .expect("Truncating to start of hour failed unexpectedly")
+        };
+
+        // Filter the LazyFrame for the exact start of the rounded hour (using NaiveDateTime)
+        Ok(self.filter(col("datetime").eq(lit(rounded_hour_start_utc.naive_utc()))))
+    }
+
+    /// Filters the hourly data to the record closest to a specific datetime, within a tolerance.
+    ///
+    /// Unlike [`HourlyLazyFrame::get_at`], the datetime isn't rounded to the hour and no exact
+    /// match is required: any record whose timestamp lies within `tolerance` of `datetime` is a
+    /// candidate, and the closest one is kept. This finds records whose timestamps are slightly
+    /// off the hour. When two records are equally close, the earlier one is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `datetime` - The target datetime, implementing [`AnyDateTime`]. A range like a
+    ///   `NaiveDate` resolves to its start.
+    /// * `tolerance` - The maximum distance between the target and a record's timestamp
+    ///   (inclusive). Negative tolerances are treated as positive.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `HourlyLazyFrame` with at most one row: the record closest
+    /// to `datetime`, or none if no record lies within the tolerance.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if the input datetime cannot be
+    /// resolved into a valid `DateTime<Utc>`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # use chrono::{Duration, TimeZone, Utc};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly = client.hourly().station("06240").call().await?;
+    ///
+    /// let target = Utc.with_ymd_and_hms(2023, 7, 1, 12, 10, 0).unwrap();
+    /// let closest = hourly
+    ///     .get_at_within(target, Duration::minutes(30))?
+    ///     .collect_hourly()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_at_within(
+        &self,
+        datetime: impl AnyDateTime,
+        tolerance: Duration,
+    ) -> Result<Self, MeteostatError> {
+        let target = datetime
+            .get_datetime_range()
+            .ok_or(MeteostatError::DateParsingError)?
+            .start
+            .naive_utc();
+        let tolerance = tolerance.abs();
+        // A huge tolerance would overflow the bounds, which then cover all data anyway
+        let earliest = target
+            .checked_sub_signed(tolerance)
+            .unwrap_or(NaiveDateTime::MIN);
+        let latest = target
+            .checked_add_signed(tolerance)
+            .unwrap_or(NaiveDateTime::MAX);
+
+        let distance = when(col("datetime").gt_eq(lit(target)))
+            .then(col("datetime") - lit(target))
+            .otherwise(lit(target) - col("datetime"));
+        let frame = self
+            .frame
+            .clone()
+            .filter(
+                col("datetime")
+                    .gt_eq(lit(earliest))
+                    .and(col("datetime").lt_eq(lit(latest))),
+            )
+            .sort_by_exprs(
+                [distance],
+                SortMultipleOptions::default().with_maintain_order(true),
+            )
+            .limit(1);
+        Ok(Self::new(frame))
+    }
+
+    /// Filters the hourly data to include only records within a specified datetime period.
+    ///
+    /// This is a convenience method that accepts types implementing [`DateTimePeriod`],
+    /// such as [`crate::Year`], [`crate::Month`], or `NaiveDate`. It resolves the period
+    /// into a start and end `DateTime<Utc>` and filters with [`PeriodFilter::get_for_period`].
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - The time period (e.g., a specific day or year), implementing [`DateTimePeriod`].
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `HourlyLazyFrame` filtered by the period's datetime range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if the given period cannot be
+    /// resolved into a valid start and end `DateTime<Utc>`.
+    pub fn get_for_period(&self, period: impl DateTimePeriod) -> Result<Self, MeteostatError> {
+        let period = period
+            .get_datetime_period()
+            .ok_or(MeteostatError::DateParsingError)?;
+        PeriodFilter::get_for_period(self, period)
+    }
+
+    /// Filters the hourly data to the records within any of several periods.
+    ///
+    /// The periods don't have to be contiguous, e.g. the summer months of several years.
+    /// They are combined into a single predicate, so the frame is scanned only once and
+    /// rows stay in their original order. Overlapping periods don't duplicate rows, and an
+    /// empty list of periods keeps no rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if any of the periods cannot be
+    /// resolved into a valid start and end `DateTime<Utc>`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Month};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly = client.hourly().station("06240").call().await?;
+    ///
+    /// // July of 2010, 2015 and 2020
+    /// let julys = hourly.get_for_periods([2010, 2015, 2020].map(|year| Month(year, 7)))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_for_periods<P: DateTimePeriod>(
+        &self,
+        periods: impl IntoIterator<Item = P>,
+    ) -> Result<Self, MeteostatError> {
+        let periods = periods
+            .into_iter()
+            .map(|period| {
+                period
+                    .get_datetime_period()
+                    .ok_or(MeteostatError::DateParsingError)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        PeriodFilter::get_for_periods(self, periods)
+    }
+
+    /// Filters the hourly data to the local calendar days of a period in a given timezone.
+    ///
+    /// [`HourlyLazyFrame::get_for_period`] treats days as running from midnight to midnight
+    /// UTC. This method instead interprets the period's first and last day in `tz`, so e.g.
+    /// `NaiveDate` 2023-07-01 in `Europe/Amsterdam` (UTC+2) keeps the hours from
+    /// 2023-06-30 22:00 UTC up to 2023-07-01 21:00 UTC. Use it before aggregating hours into
+    /// days for a station that isn't on UTC, to avoid shifting data to the neighbouring day.
+    ///
+    /// Days are resolved with the offsets that apply on them, so DST changes are handled
+    /// when `tz` has DST rules (e.g. `chrono::Local` or a `chrono-tz` timezone).
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - The calendar days to keep (e.g. [`crate::Year`], [`crate::Month`] or
+    ///   `NaiveDate`), implementing [`DatePeriod`].
+    /// * `tz` - The timezone in which the days are interpreted.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `HourlyLazyFrame` filtered to the local days.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if the period cannot be resolved into
+    /// dates, or its days don't exist in `tz`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// use chrono::{FixedOffset, NaiveDate};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly_lazy = client.hourly().station("72502").call().await?; // Newark
+    ///
+    /// let new_york_summer = FixedOffset::west_opt(4 * 3600).unwrap();
+    /// let july_4th = NaiveDate::from_ymd_opt(2023, 7, 4).unwrap();
+    /// let local_day = hourly_lazy.get_for_local_period(july_4th, &new_york_summer)?;
+    /// println!("{}", local_day.frame.collect()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_for_local_period<Tz: TimeZone>(
+        &self,
+        period: impl DatePeriod,
+        tz: &Tz,
+    ) -> Result<Self, MeteostatError> {
+        let local_period = period
+            .get_date_period()
+            .and_then(|dates| dates.in_timezone(tz))
+            .ok_or(MeteostatError::DateParsingError)?;
+        self.get_range(local_period.start, local_period.end)
+    }
+
+    /// Keeps only the rows whose hour of day is a multiple of `n`, e.g. 00, 03, 06, ... UTC
+    /// for `n = 3`.
+    ///
+    /// Counting restarts at 00 UTC each day, so `n` should divide 24 for evenly spaced rows.
+    /// `n = 0` is treated as 1 (no filtering).
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The spacing in hours between kept rows.
+    ///
+    /// # Returns
+    ///
+    /// A new `HourlyLazyFrame` with the filter applied lazily.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly_lazy = client.hourly().station("06240").call().await?;
+    ///
+    /// // 8 rows per day instead of 24
+    /// let three_hourly = hourly_lazy.get_for_period(Year(2023))?.every_n_hours(3);
+    /// println!("{}", three_hourly.frame.collect()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn every_n_hours(&self, n: u32) -> Self {
+        let n = i32::try_from(n.max(1)).unwrap_or(i32::MAX);
+        self.filter((col("datetime").dt().hour().cast(DataType::Int32) % lit(n)).eq(lit(0i32)))
+    }
+
+    /// Keeps only the rows at the main synoptic observation times: 00, 06, 12 and 18 UTC.
+    ///
+    /// Shorthand for [`HourlyLazyFrame::every_n_hours`] with `n = 6`, useful when comparing
+    /// against other data sources that only report at standard times.
+    ///
+    /// # Returns
+    ///
+    /// A new `HourlyLazyFrame` with the filter applied lazily.
+    #[must_use]
+    pub fn at_synoptic_hours(&self) -> Self {
+        self.every_n_hours(6)
+    }
+
+    /// Keeps only the rows where all of the given fields have a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The [`HourlyField`]s that must be non-null. An empty slice keeps all rows.
+    ///
+    /// # Returns
+    ///
+    /// A new `HourlyLazyFrame` with the filter applied lazily.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{HourlyField, Meteostat, MeteostatError, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly_lazy = client.hourly().station("06240").call().await?;
+    ///
+    /// let complete = hourly_lazy.get_for_period(Year(2023))?.require_fields(&[
+    ///     HourlyField::Temperature,
+    ///     HourlyField::RelativeHumidity,
+    ///     HourlyField::WindSpeed,
+    /// ]);
+    /// println!("{} complete hours", complete.row_count()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn require_fields(&self, fields: &[HourlyField]) -> Self {
+        fields
+            .iter()
+            .map(|field| col(field.column_name()).is_not_null())
+            .reduce(Expr::and)
+            .map_or_else(|| self.clone(), |predicate| self.filter(predicate))
+    }
+
+    /// Drops the rows where every measured field is null, i.e. rows that only hold a
+    /// timestamp.
+    ///
+    /// The collection methods keep such rows, so that the result covers every hour present
+    /// in the source data. Apply this filter first when those empty rows are just noise.
+    /// The weather condition code counts as a measured field; the `source` column does not.
+    ///
+    /// # Returns
+    ///
+    /// A new `HourlyLazyFrame` with the filter applied lazily.
+    #[must_use]
+    pub fn drop_empty_rows(&self) -> Self {
+        let has_value = HourlyField::ALL
+            .iter()
+            .map(|field| field.column_name())
+            .chain(["coco"])
+            .map(|name| col(name).is_not_null())
+            .reduce(Expr::or)
+            .unwrap_or_else(|| lit(true));
+        self.filter(has_value)
+    }
+
+    /// Computes the average daily cycle of a field: its mean for each hour of the day (UTC),
+    /// across all days in the frame.
+    ///
+    /// Null values are ignored. Hours without any value are left out of the result.
+    /// [`HourlyField::WindDirection`] is averaged as a circular mean (see
+    /// [`crate::meteo_calc::circular_mean_degrees`]), so directions around north average to
+    /// north; its mean is null for hours whose directions cancel out.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The [`HourlyField`] to average.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DataFrame` with an `hour` column (0-23, `Int32`) and a column
+    /// named after the field ([`HourlyField::column_name`]) holding the mean, sorted by hour.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{HourlyField, Meteostat, MeteostatError, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly_lazy = client.hourly().station("06240").call().await?;
+    ///
+    /// let cycle = hourly_lazy
+    ///     .get_for_period(Year(2023))?
+    ///     .diurnal_mean(HourlyField::Temperature)?;
+    /// println!("{cycle}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diurnal_mean(&self, field: HourlyField) -> Result<DataFrame, MeteostatError> {
+        self.diurnal_aggregate(field, false)
+    }
+
+    /// Like [`HourlyLazyFrame::diurnal_mean`], but computes a separate daily cycle for each
+    /// calendar month, e.g. to compare summer and winter.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The [`HourlyField`] to average.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DataFrame` with `month` (1-12) and `hour` (0-23) columns (both
+    /// `Int32`) and a column named after the field holding the mean, sorted by month and hour.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    pub fn diurnal_mean_by_month(&self, field: HourlyField) -> Result<DataFrame, MeteostatError> {
+        self.diurnal_aggregate(field, true)
+    }
+
+    fn diurnal_aggregate(
+        &self,
+        field: HourlyField,
+        by_month: bool,
+    ) -> Result<DataFrame, MeteostatError> {
+        let mut keys = Vec::with_capacity(2);
+        if by_month {
+            keys.push(
+                col("datetime")
+                    .dt()
+                    .month()
+                    .cast(DataType::Int32)
+                    .alias("month"),
+            );
+        }
+        keys.push(
+            col("datetime")
+                .dt()
+                .hour()
+                .cast(DataType::Int32)
+                .alias("hour"),
+        );
+        let sort_by: &[&str] = if by_month {
+            &["month", "hour"]
+        } else {
+            &["hour"]
+        };
+
+        let name = field.column_name();
+        let is_direction = field == HourlyField::WindDirection;
+        let values = col(name).cast(DataType::Float64);
+        let mut df = self
+            .frame
+            .clone()
+            .filter(col(name).is_not_null())
+            .group_by(keys)
+            // Directions are gathered per group and averaged as angles below
+            .agg([if is_direction { values } else { values.mean() }])
+            .sort(sort_by.to_vec(), SortMultipleOptions::default())
+            .collect()?;
+        if is_direction {
+            let means: Float64Chunked = df
+                .column(name)?
+                .list()?
+                .amortized_iter()
+                .map(|directions| {
+                    circular_mean_degrees(directions?.as_ref().f64().ok()?.into_no_null_iter())
+                })
+                .collect();
+            df.with_column(means.with_name(name.into()).into_column())?;
+        }
+        Ok(df)
+    }
+
+    /// The mean wind direction of all rows, in degrees within `[0, 360)`.
+    ///
+    /// Directions are averaged as unit vectors (see
+    /// [`crate::meteo_calc::circular_mean_degrees`]), so 350° and 10° average to 0° rather than
+    /// the 180° an arithmetic mean gives. Null directions are ignored.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the mean direction, or `None` if there are no directions or
+    /// they cancel out (such as 90° and 270°).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly_lazy = client.hourly().station("06240").call().await?;
+    ///
+    /// if let Some(direction) = hourly_lazy.get_for_period(Year(2023))?.mean_wind_direction()? {
+    ///     println!("Prevailing wind from {direction:.0}°");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mean_wind_direction(&self) -> Result<Option<f64>, MeteostatError> {
+        let df = self
+            .frame
+            .clone()
+            .select([col("wdir").cast(DataType::Float64)])
+            .drop_nulls(None)
+            .collect()?;
+        Ok(circular_mean_degrees(
+            df.column("wdir")?.f64()?.into_no_null_iter(),
+        ))
+    }
+
+    /// Downsamples the series of one field to at most `n_points` rows for plotting, using
+    /// the Largest-Triangle-Three-Buckets algorithm.
+    ///
+    /// Unlike taking every n-th row, this keeps the peaks and dips that define how the series
+    /// looks, so decades of hourly data can be drawn on a fixed-width chart faithfully.
+    /// Rows where the field is null are ignored. The first and last rows are always kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_points` - The maximum number of rows to return. If the series has fewer
+    ///   non-null rows, all of them are returned.
+    /// * `field` - The [`HourlyField`] whose shape should be preserved.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `DataFrame` with the selected rows (all columns), sorted
+    /// by datetime.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{HourlyField, Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly_lazy = client.hourly().station("06240").call().await?;
+    ///
+    /// // One point per pixel column of an 800px wide chart
+    /// let sampled = hourly_lazy.downsample_lttb(800, HourlyField::Temperature)?;
+    /// println!("{sampled}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn downsample_lttb(
+        &self,
+        n_points: usize,
+        field: HourlyField,
+    ) -> Result<DataFrame, MeteostatError> {
+        let name = field.column_name();
+        let df = self
+            .frame
+            .clone()
+            .filter(col(name).is_not_null())
+            .sort(["datetime"], SortMultipleOptions::default())
+            .collect()?;
+
+        let datetimes = df
+            .column("datetime")?
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+        let datetimes = datetimes.datetime()?.physical();
+        let values = df.column(name)?.cast(&DataType::Float64)?;
+        let values = values.f64()?;
+        // Millisecond timestamps are exact in f64 for any realistic date
+        #[allow(clippy::cast_precision_loss)]
+        let x: Vec<f64> = (0..df.height())
+            .map(|i| datetimes.get(i).unwrap_or_default() as f64)
+            .collect();
+        let y: Vec<f64> = (0..df.height())
+            .map(|i| values.get(i).unwrap_or_default())
+            .collect();
+
+        let indices = lttb_indices(&x, &y, n_points)
+            .into_iter()
+            .map(|i| IdxSize::try_from(i).unwrap_or(IdxSize::MAX))
+            .collect::<Vec<_>>();
+        Ok(df.take(&IdxCa::from_vec("idx".into(), indices))?)
+    }
+
+    /// Executes the query and bins the wind observations into a [`WindRose`].
+    ///
+    /// Rows with a missing wind direction or speed are skipped. Winds slower than
+    /// [`crate::CALM_WIND_SPEED_KMH`] have no meaningful direction and are counted separately as calm.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction_bins` - Number of direction sectors, e.g. 8, 16 or 36. 0 is treated as 1.
+    /// * `speed_buckets` - Upper bounds of the speed buckets in km/h. One more bucket is added
+    ///   for speeds at or above the largest bound, so `&[]` gives a single bucket per sector.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the [`WindRose`] on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let rose = client
+    ///     .hourly()
+    ///     .station("06240")
+    ///     .call()
+    ///     .await?
+    ///     .get_for_period(Year(2023))?
+    ///     .wind_rose(16, &[10.0, 20.0, 30.0])?;
+    ///
+    /// for (sector, counts) in rose.counts.iter().enumerate() {
+    ///     println!("{:>5.1}°: {counts:?}", rose.sector_center(sector));
+    /// }
+    /// println!("calm: {}", rose.calm);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wind_rose(
+        &self,
+        direction_bins: usize,
+        speed_buckets: &[f64],
+    ) -> Result<WindRose, MeteostatError> {
+        let df = self
+            .frame
+            .clone()
+            .select([col("wdir").cast(DataType::Float64), col("wspd")])
+            .drop_nulls(None)
+            .collect()?;
+        let directions = df.column("wdir")?.f64()?;
+        let speeds = df.column("wspd")?.f64()?;
+        let observations =
+            (0..df.height()).filter_map(|i| Some((directions.get(i)?, speeds.get(i)?)));
+        Ok(WindRose::from_observations(
+            observations,
+            direction_bins,
+            speed_buckets,
+        ))
+    }
+
+    /// Adds a [`crate::PRECIP_PHASE_COLUMN`] (`precip_phase`) column classifying each hour's
+    /// precipitation as rain, snow or mixed, using the default [`PrecipPhaseRules`].
+    ///
+    /// The column holds the [`crate::PrecipPhase::as_str`] values `"rain"`, `"snow"` and `"mixed"`,
+    /// and is null for hours without precipitation (`prcp` 0 or missing). Otherwise a
+    /// precipitation condition code decides the phase when present; failing that, precipitation
+    /// below 0 °C is snow, above 2 °C rain, and mixed in between. See [`PrecipPhaseRules`] for
+    /// the full rules, and
+    /// [`HourlyLazyFrame::with_precip_phase_rules`] to change them.
+    ///
+    /// # Returns
+    ///
+    /// A new `HourlyLazyFrame` with the column added lazily.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Year};
+    /// use polars::prelude::{col, lit};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly = client.hourly().station("06680").call().await?; // Säntis
+    ///
+    /// let snowfall = hourly
+    ///     .get_for_period(Year(2023))?
+    ///     .with_precip_phase()
+    ///     .frame
+    ///     .filter(col("precip_phase").eq(lit("snow")))
+    ///     .select([col("prcp").sum()])
+    ///     .collect()?;
+    /// println!("Precipitation that fell as snow: {snowfall}");
     /// # Ok(())
     /// # }
     /// ```
-    ///
-    /// # Errors
-    ///
-    /// While this method itself doesn't typically error, subsequent operations like `.collect()`
-    /// might return a [`polars::prelude::PolarsError`].
     #[must_use]
-    pub fn filter(&self, predicate: Expr) -> Self {
-        Self::new(self.frame.clone().filter(predicate))
+    pub fn with_precip_phase(&self) -> Self {
+        let rules = PrecipPhaseRules::default();
+        Self::new(self.frame.clone().with_column(rules.phase_expr()))
     }
 
-    /// Filters the hourly data to include only records within the specified datetime range (inclusive).
-    ///
-    /// The `start` and `end` arguments can be any type that implements [`AnyDateTime`],
-    /// such as `DateTime<Utc>`, `NaiveDate`, [`crate::Month`], or [`crate::Year`]. The trait
-    /// resolves these into specific start and end `DateTime<Utc>` values. These are then
-    /// converted to `NaiveDateTime` (representing UTC) for filtering the Polars frame.
+    /// Like [`HourlyLazyFrame::with_precip_phase`], but classifies with the given rules, e.g.
+    /// with a higher rain/snow temperature threshold for a mountain station.
     ///
     /// # Arguments
     ///
-    /// * `start` - The starting datetime boundary (inclusive), implementing [`AnyDateTime`].
-    /// * `end` - The ending datetime boundary (inclusive), implementing [`AnyDateTime`].
+    /// * `rules` - The [`PrecipPhaseRules`] to classify with.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a new `HourlyLazyFrame` filtered by the datetime range.
+    /// A new `HourlyLazyFrame` with the [`crate::PRECIP_PHASE_COLUMN`] column added lazily.
     ///
     /// # Errors
     ///
-    /// Returns [`MeteostatError::DateParsingError`] if the start or end datetime boundaries
-    /// cannot be resolved into a valid `DateTime<Utc>`.
-    pub fn get_range(
-        &self,
-        start: impl AnyDateTime,
-        end: impl AnyDateTime,
-    ) -> Result<Self, MeteostatError> {
-        // Resolve inputs to UTC DateTimes
-        let start_utc = start
-            .get_datetime_range()
-            .ok_or(MeteostatError::DateParsingError)?
-            .start;
-        let end_utc = end
-            .get_datetime_range()
-            .ok_or(MeteostatError::DateParsingError)?
-            .end;
-
-        // Convert to NaiveDateTime for filtering the Polars frame
-        let start_naive = start_utc.naive_utc();
-        let end_naive = end_utc.naive_utc();
-
-        Ok(self.filter(
-            col("datetime")
-                .gt_eq(lit(start_naive))
-                .and(col("datetime").lt_eq(lit(end_naive))),
+    /// Returns [`MeteostatError::InvalidPrecipPhaseThresholds`] if `snow_below_c` is above
+    /// `rain_above_c` (see [`PrecipPhaseRules::validate`]).
+    pub fn with_precip_phase_rules(&self, rules: PrecipPhaseRules) -> Result<Self, MeteostatError> {
+        rules.validate()?;
+        Ok(Self::new(
+            self.frame.clone().with_column(rules.phase_expr()),
         ))
     }
 
-    /// Filters the hourly data to get the record closest to a specific datetime, rounded to the nearest hour.
-    ///
-    /// The `datetime` argument can be any type that implements [`AnyDateTime`]. It is resolved
-    /// to a `DateTime<Utc>`, then rounded to the nearest hour (>= 30 minutes rounds up, < 30 rounds down).
-    /// The filter then looks for an exact match for the *start* of that rounded hour in the frame's
-    /// "datetime" column (as `NaiveDateTime`).
-    ///
-    /// # Arguments
+    /// Scans the `datetime` column, in its current row order, for timestamps that don't fit
+    /// a regular hourly series.
     ///
-    /// * `datetime` - The target datetime, implementing [`AnyDateTime`].
+    /// Consecutive records are compared: an earlier datetime is reported as a
+    /// [`TimeAnomaly::BackwardsJump`], and a step of more than [`Self::LARGE_GAP_HOURS`] hours
+    /// as a [`TimeAnomaly::Gap`]. Any datetime seen before is reported as a
+    /// [`TimeAnomaly::DuplicateHour`]. Rows without a datetime are skipped.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a new `HourlyLazyFrame` filtered to the specific rounded hour. Collecting
-    /// the frame should yield zero or one row.
+    /// A `Result` containing the anomalies in row order, or an empty `Vec` if the series is
+    /// regular.
     ///
     /// # Errors
     ///
-    /// Returns [`MeteostatError::DateParsingError`] if the input datetime cannot be
-    /// resolved into a valid `DateTime<Utc>`.
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails or the frame
+    /// has no `datetime` column of the expected type.
     ///
-    /// # Panics
+    /// # Example
     ///
-    /// * If the datetime can't be truncated to 0 minutes, 0 seconds, 0 nanoseconds.
-    /// * If the (datetime + 1 hour) can't be truncated to 0 minutes, 0 seconds, 0 nanoseconds.
-    pub fn get_at(&self, datetime: impl AnyDateTime) -> Result<Self, MeteostatError> {
-        let date_utc = datetime
-            .get_datetime_range()
-            .ok_or(MeteostatError::DateParsingError)?
-            .start; // Use the start of the range from AnyDateTime
-
-        // Round to the nearest hour
-        let rounded_hour_start_utc = if date_utc.minute() >= 30 {
-            // Round up: Add an hour, then truncate minutes/seconds/nanos
-            (date_utc + Duration::hours(1))
-                .with_minute(0)
-                .and_then(|dt| dt.with_second(0))
-                .and_then(|dt| dt.with_nanosecond(0))
-                .expect("Truncating to start of hour after adding hour failed unexpectedly")
-        } else {
-            // Round down: Truncate minutes/seconds/nanos
-            date_utc
-                .with_minute(0)
-                .and_then(|dt| dt.with_second(0))
-                .and_then(|dt| dt.with_nanosecond(0))
-                .expect("Truncating to start of hour failed unexpectedly")
-        };
-
-        // Filter the LazyFrame for the exact start of the rounded hour (using NaiveDateTime)
-        Ok(self.filter(col("datetime").eq(lit(rounded_hour_start_utc.naive_utc()))))
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, TimeAnomaly};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let hourly = client.hourly().station("06240").call().await?;
+    ///
+    /// for anomaly in hourly.detect_time_anomalies()? {
+    ///     if let TimeAnomaly::DuplicateHour { datetime } = anomaly {
+    ///         println!("{datetime} occurs more than once");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn detect_time_anomalies(&self) -> Result<Vec<TimeAnomaly>, MeteostatError> {
+        let df = self.frame.clone().select([col("datetime")]).collect()?;
+        let datetimes = df
+            .column("datetime")?
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+        let datetimes = datetimes.datetime()?;
+        let max_step = Duration::hours(Self::LARGE_GAP_HOURS);
+
+        let mut anomalies = Vec::new();
+        let mut seen = HashSet::new();
+        let mut previous: Option<DateTime<Utc>> = None;
+        for datetime in datetimes
+            .phys
+            .iter()
+            .filter_map(|ms| ms.and_then(DateTime::from_timestamp_millis))
+        {
+            if !seen.insert(datetime) {
+                anomalies.push(TimeAnomaly::DuplicateHour { datetime });
+            }
+            if let Some(previous) = previous {
+                if datetime < previous {
+                    anomalies.push(TimeAnomaly::BackwardsJump { previous, datetime });
+                } else if datetime - previous > max_step {
+                    anomalies.push(TimeAnomaly::Gap {
+                        from: previous,
+                        to: datetime,
+                    });
+                }
+            }
+            previous = Some(datetime);
+        }
+        Ok(anomalies)
     }
 
-    /// Filters the hourly data to include only records within a specified datetime period.
-    ///
-    /// This is a convenience method that accepts types implementing [`DateTimePeriod`],
-    /// such as [`crate::Year`], [`crate::Month`], or `NaiveDate`. It resolves the period
-    /// into a start and end `DateTime<Utc>` and then calls `get_range`.
-    ///
-    /// # Arguments
-    ///
-    /// * `period` - The time period (e.g., a specific day or year), implementing [`DateTimePeriod`].
+    /// Counts the rows of the lazy query without collecting all columns, see
+    /// [`MeteostatFrame::row_count`].
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A `Result` containing a new `HourlyLazyFrame` filtered by the period's datetime range.
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    pub fn row_count(&self) -> Result<usize, MeteostatError> {
+        MeteostatFrame::row_count(self)
+    }
+
+    /// Executes the lazy query and returns all columns as a Polars `DataFrame`, see
+    /// [`MeteostatFrame::collect_frame`].
     ///
     /// # Errors
     ///
-    /// Returns [`MeteostatError::DateParsingError`] if the given period cannot be
-    /// resolved into a valid start and end `DateTime<Utc>`.
-    pub fn get_for_period(&self, period: impl DateTimePeriod) -> Result<Self, MeteostatError> {
-        let datetime_period = period
-            .get_datetime_period()
-            .ok_or(MeteostatError::DateParsingError)?;
-        // Delegate to get_range using the resolved start and end datetimes
-        self.get_range(datetime_period.start, datetime_period.end)
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    pub fn collect_frame(&self) -> Result<DataFrame, MeteostatError> {
+        MeteostatFrame::collect_frame(self)
     }
 
-    /// Executes the lazy query and collects the results into a `Vec<Hourly>`.
+    /// Executes the lazy query and collects the results into [`HourlyRecords`].
     ///
     /// This method triggers the computation defined by the `LazyFrame` (including any
     /// previous filtering operations) and maps each resulting row to an `Hourly` struct.
     /// Rows where the essential 'datetime' column is missing or invalid are skipped.
+    /// Rows where all measured fields are null are kept; use
+    /// [`HourlyLazyFrame::collect_hourly_non_empty`] to leave them out.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `Vec<Hourly>` on success.
+    /// A `Result` containing the [`HourlyRecords`] on success.
     ///
     /// # Errors
     ///
@@ -279,7 +1309,7 @@ impl HourlyLazyFrame {
     /// # Example
     ///
     /// ```no_run
-    /// # use meteostat::{Meteostat, MeteostatError, LatLon, Year, Hourly};
+    /// # use meteostat::{Meteostat, MeteostatError, LatLon, Year, Hourly, HourlyRecords};
     /// use polars::prelude::PolarsError;
     ///
     /// # #[tokio::main]
@@ -296,8 +1326,8 @@ impl HourlyLazyFrame {
     /// // Get data for a specific year
     /// let year_data_lazy = hourly_lazy.get_for_period(Year(2022))?;
     ///
-    /// // Collect into Vec<Hourly>
-    /// let hourly_vec: Vec<Hourly> = year_data_lazy.collect_hourly()?;
+    /// // Collect into HourlyRecords
+    /// let hourly_vec: HourlyRecords = year_data_lazy.collect_hourly()?;
     ///
     /// println!("Collected {} hourly records for 2022.", hourly_vec.len());
     /// if let Some(first_hour) = hourly_vec.first() {
@@ -306,14 +1336,22 @@ impl HourlyLazyFrame {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn collect_hourly(&self) -> Result<Vec<Hourly>, MeteostatError> {
-        let df = self
-            .frame
-            .clone() // Clone frame as collect consumes it
-            .collect()
-            .map_err(MeteostatError::PolarsError)?; // Map PolarsError
+    pub fn collect_hourly(&self) -> Result<HourlyRecords, MeteostatError> {
+        self.clone().into_collect_hourly()
+    }
 
-        Self::dataframe_to_hourly_vec(&df) // Use helper function
+    /// Like [`HourlyLazyFrame::collect_hourly`], but leaves out the rows where every measured
+    /// field is null. Shorthand for `.drop_empty_rows().collect_hourly()`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the [`HourlyRecords`] with only the hours that have data.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`HourlyLazyFrame::collect_hourly`].
+    pub fn collect_hourly_non_empty(&self) -> Result<HourlyRecords, MeteostatError> {
+        self.drop_empty_rows().into_collect_hourly()
     }
 
     /// Executes the lazy query, expecting exactly one row, and collects it into an `Hourly` struct.
@@ -368,11 +1406,52 @@ impl HourlyLazyFrame {
     /// # }
     /// ```
     pub fn collect_single_hourly(&self) -> Result<Hourly, MeteostatError> {
-        let df = self
-            .frame
-            .clone()
-            .collect()
-            .map_err(MeteostatError::PolarsError)?;
+        self.clone().into_collect_single_hourly()
+    }
+
+    /// Consuming variant of [`Self::collect_hourly`].
+    ///
+    /// Takes the frame by value, so the query plan doesn't have to be cloned before
+    /// collecting. Prefer this for one-shot queries where the frame isn't reused.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_hourly`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Hourly, HourlyRecords};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let records: HourlyRecords = client
+    ///     .hourly()
+    ///     .station("10637")
+    ///     .call()
+    ///     .await?
+    ///     .into_collect_hourly()?; // No clone of the query plan needed
+    /// println!("Collected {} records.", records.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_collect_hourly(self) -> Result<HourlyRecords, MeteostatError> {
+        let df = self.frame.collect().map_err(MeteostatError::PolarsError)?; // Map PolarsError
+
+        Self::dataframe_to_hourly_vec(&df).map(HourlyRecords::from) // Use helper function
+    }
+
+    /// Consuming variant of [`Self::collect_single_hourly`].
+    ///
+    /// Takes the frame by value, so the query plan doesn't have to be cloned before
+    /// collecting.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_single_hourly`].
+    pub fn into_collect_single_hourly(self) -> Result<Hourly, MeteostatError> {
+        let df = self.frame.collect().map_err(MeteostatError::PolarsError)?;
 
         if df.height() != 1 {
             return Err(MeteostatError::ExpectedSingleRow {
@@ -386,6 +1465,27 @@ impl HourlyLazyFrame {
             .ok_or(MeteostatError::ExpectedSingleRow { actual: 0 })
     }
 
+    /// Async variant of [`Self::collect_hourly`], see [`MeteostatFrame::collect_frame_async`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_hourly`], or [`MeteostatError::TaskJoin`] if the background task fails.
+    pub async fn collect_hourly_async(&self) -> Result<HourlyRecords, MeteostatError> {
+        let data = self.clone();
+        run_blocking(move || data.into_collect_hourly()).await
+    }
+
+    /// Async variant of [`Self::collect_single_hourly`], see [`MeteostatFrame::collect_frame_async`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_single_hourly`], or [`MeteostatError::TaskJoin`] if the background
+    /// task fails.
+    pub async fn collect_single_hourly_async(&self) -> Result<Hourly, MeteostatError> {
+        let data = self.clone();
+        run_blocking(move || data.into_collect_single_hourly()).await
+    }
+
     // --- Helper function to map DataFrame rows to Vec<Hourly> ---
     fn dataframe_to_hourly_vec(df: &DataFrame) -> Result<Vec<Hourly>, MeteostatError> {
         // --- Get required columns as Series ---
@@ -402,20 +1502,21 @@ impl HourlyLazyFrame {
         let tsun_series = df.column("tsun")?; // Integer type
         let coco_series = df.column("coco")?; // Integer type (weather code)
 
-        // --- Get ChunkedArrays (handle potential type variations if needed) ---
-        // We assume default types here. Add specific casting if Polars reads differently.
+        // --- Get ChunkedArrays (numeric columns are cast from whatever type Polars read) ---
         let dt_ca = dt_series.datetime()?; // ChronoNaiveDateTimeChunked - expects NaiveDateTime
-        let temp_ca = temp_series.f64()?;
-        let dwpt_ca = dwpt_series.f64()?;
-        let rhum_ca = rhum_series.i64()?; // Read as i64 initially
-        let prcp_ca = prcp_series.f64()?;
-        let snow_ca = snow_series.i64()?; // Read as i64 initially
-        let wdir_ca = wdir_series.i64()?; // Read as i64 initially
-        let wspd_ca = wspd_series.f64()?;
-        let wpgt_ca = wpgt_series.f64()?;
-        let pres_ca = pres_series.f64()?;
-        let tsun_ca = tsun_series.i64()?; // Read as i64 initially
-        let coco_ca = coco_series.i64()?; // Read as i64 initially
+        let temp_ca = column_f64(temp_series)?;
+        let dwpt_ca = column_f64(dwpt_series)?;
+        let rhum_ca = column_i64(rhum_series)?;
+        let prcp_ca = column_f64(prcp_series)?;
+        let snow_ca = column_i64(snow_series)?;
+        let wdir_ca = column_i64(wdir_series)?;
+        let wspd_ca = column_f64(wspd_series)?;
+        let wpgt_ca = column_f64(wpgt_series)?;
+        let pres_ca = column_f64(pres_series)?;
+        let tsun_ca = column_i64(tsun_series)?;
+        let coco_ca = column_i64(coco_series)?;
+        // Optional provenance column, only present in some source files
+        let source_ca = df.column(SOURCE_COLUMN).ok().map(Column::str).transpose()?;
 
         let mut hourly_vec = Vec::with_capacity(df.height());
 
@@ -437,7 +1538,8 @@ impl HourlyLazyFrame {
             let datetime_utc = Utc.from_utc_datetime(&naive_dt);
 
             // Get weather condition code and map to enum
-            let condition = coco_ca.get(i).and_then(WeatherCondition::from_i64);
+            let condition_code = coco_ca.get(i);
+            let condition = condition_code.map(WeatherCondition::from_code);
 
             // Construct the struct
             let hourly_record = Hourly {
@@ -453,6 +1555,8 @@ impl HourlyLazyFrame {
                 pressure: pres_ca.get(i),
                 sunshine_minutes: tsun_ca.get(i).and_then(|v| i32::try_from(v).ok()),
                 condition,
+                condition_code,
+                source: source_ca.and_then(|ca| ca.get(i)).map(str::to_string),
             };
 
             hourly_vec.push(hourly_record);
@@ -721,7 +1825,11 @@ mod tests {
                 .i64()?
                 .get(0)
             {
-                assert_eq!(first_record.condition, WeatherCondition::from_i64(code_i64));
+                assert_eq!(
+                    first_record.condition,
+                    Some(WeatherCondition::from_code(code_i64))
+                );
+                assert_eq!(first_record.condition_code, Some(code_i64));
             }
         }
         Ok(())
@@ -808,4 +1916,467 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_every_n_hours_and_synoptic_hours() -> Result<(), Box<dyn std::error::Error>> {
+        // Two days of hourly timestamps, starting at 2023-01-01 00:00 UTC
+        let start_ms = Utc
+            .with_ymd_and_hms(2023, 1, 1, 0, 0, 0)
+            .unwrap()
+            .timestamp_millis();
+        let timestamps: Vec<i64> = (0..48).map(|h| start_ms + h * 3_600_000).collect();
+        let df = df!("datetime" => timestamps)?
+            .lazy()
+            .with_column(col("datetime").cast(DataType::Datetime(TimeUnit::Milliseconds, None)));
+        let hourly_lazy = HourlyLazyFrame::new(df);
+
+        let synoptic = hourly_lazy.at_synoptic_hours().frame.collect()?;
+        let datetimes = synoptic.column("datetime")?.datetime()?.physical();
+        let hours: Vec<u32> = (0..datetimes.len())
+            .map(|i| ms_to_datetime(datetimes.get(i).unwrap()).hour())
+            .collect();
+        assert_eq!(hours, vec![0, 6, 12, 18, 0, 6, 12, 18]);
+
+        assert_eq!(hourly_lazy.every_n_hours(3).row_count()?, 16);
+        assert_eq!(hourly_lazy.every_n_hours(0).row_count()?, 48);
+        Ok(())
+    }
+
+    #[test]
+    fn test_diurnal_mean_averages_by_hour() -> Result<(), Box<dyn std::error::Error>> {
+        // Two days in January and one in July at 00:00 and 12:00 UTC
+        let datetimes = [
+            (2023, 1, 1, 0),
+            (2023, 1, 1, 12),
+            (2023, 1, 2, 0),
+            (2023, 1, 2, 12),
+            (2023, 7, 1, 0),
+            (2023, 7, 1, 12),
+        ]
+        .map(|(y, m, d, h)| {
+            Utc.with_ymd_and_hms(y, m, d, h, 0, 0)
+                .unwrap()
+                .timestamp_millis()
+        });
+        let df = df!(
+            "datetime" => datetimes,
+            "temp" => [Some(1.0f64), Some(5.0), Some(3.0), None, Some(16.0), Some(25.0)],
+            "wdir" => [Some(350i64), Some(90), Some(10), Some(270), None, Some(180)]
+        )?
+        .lazy()
+        .with_column(col("datetime").cast(DataType::Datetime(TimeUnit::Milliseconds, None)));
+        let hourly_lazy = HourlyLazyFrame::new(df);
+
+        let cycle = hourly_lazy.diurnal_mean(HourlyField::Temperature)?;
+        let hours = cycle.column("hour")?.i32()?;
+        let means = cycle.column("temp")?.f64()?;
+        assert_eq!(cycle.height(), 2);
+        assert_eq!((hours.get(0), means.get(0)), (Some(0), Some(20.0 / 3.0)));
+        assert_eq!((hours.get(1), means.get(1)), (Some(12), Some(15.0)));
+
+        let by_month = hourly_lazy.diurnal_mean_by_month(HourlyField::Temperature)?;
+        let months = by_month.column("month")?.i32()?;
+        let means = by_month.column("temp")?.f64()?;
+        assert_eq!(by_month.height(), 4);
+        assert_eq!((months.get(0), means.get(0)), (Some(1), Some(2.0)));
+        assert_eq!((months.get(1), means.get(1)), (Some(1), Some(5.0)));
+        assert_eq!((months.get(3), means.get(3)), (Some(7), Some(25.0)));
+
+        // Directions are averaged as angles: 350° and 10° give north, not south
+        let close = |a: Option<f64>, b: f64| a.is_some_and(|a| (a - b).abs() < 1e-9);
+        let wind = hourly_lazy.diurnal_mean(HourlyField::WindDirection)?;
+        let means = wind.column("wdir")?.f64()?;
+        assert!(close(means.get(0), 0.0), "{wind}");
+        assert!(close(means.get(1), 180.0), "{wind}");
+        assert!(close(hourly_lazy.mean_wind_direction()?, 0.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_at_within_picks_closest_row() -> Result<(), Box<dyn std::error::Error>> {
+        // Timestamps a few minutes off the hour: 11:58, 12:04 and 13:01
+        let datetimes = [(11, 58), (12, 4), (13, 1)].map(|(h, m)| {
+            Utc.with_ymd_and_hms(2023, 7, 1, h, m, 0)
+                .unwrap()
+                .timestamp_millis()
+        });
+        let df = df!(
+            "datetime" => datetimes,
+            "temp" => [Some(20.0f64), Some(21.0), Some(22.0)]
+        )?
+        .lazy()
+        .with_column(col("datetime").cast(DataType::Datetime(TimeUnit::Milliseconds, None)));
+        let hourly_lazy = HourlyLazyFrame::new(df);
+        let noon = Utc.with_ymd_and_hms(2023, 7, 1, 12, 0, 0).unwrap();
+
+        // The exact-hour lookup misses the shifted timestamps
+        assert_eq!(hourly_lazy.get_at(noon)?.row_count()?, 0);
+
+        let closest = hourly_lazy
+            .get_at_within(noon, chrono::Duration::minutes(5))?
+            .frame
+            .collect()?;
+        assert_eq!(closest.column("temp")?.f64()?.get(0), Some(20.0));
+        assert_eq!(closest.height(), 1);
+
+        let later = hourly_lazy.get_at_within(
+            noon + chrono::Duration::minutes(3),
+            chrono::Duration::minutes(5),
+        )?;
+        assert_eq!(
+            later.frame.collect()?.column("temp")?.f64()?.get(0),
+            Some(21.0)
+        );
+
+        assert_eq!(
+            hourly_lazy
+                .get_at_within(noon, chrono::Duration::milliseconds(-60_000))?
+                .row_count()?,
+            0
+        );
+
+        // A tolerance beyond the representable dates matches the closest row instead of
+        // overflowing
+        let anything = hourly_lazy
+            .get_at_within(noon, chrono::Duration::days(i32::MAX.into()))?
+            .frame
+            .collect()?;
+        assert_eq!(anything.column("temp")?.f64()?.get(0), Some(20.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_time_anomalies() -> Result<(), Box<dyn std::error::Error>> {
+        let at = |d, h| Utc.with_ymd_and_hms(2023, 10, d, h, 0, 0).unwrap();
+        // 01:00 and 02:00 repeat after a switch to local time, then three days are missing
+        let datetimes = [at(1, 0), at(1, 1), at(1, 2), at(1, 1), at(1, 2), at(4, 3)]
+            .map(|dt| dt.timestamp_millis());
+        let df = df!("datetime" => datetimes)?
+            .lazy()
+            .with_column(col("datetime").cast(DataType::Datetime(TimeUnit::Milliseconds, None)));
+        let hourly_lazy = HourlyLazyFrame::new(df);
+
+        assert_eq!(
+            hourly_lazy.detect_time_anomalies()?,
+            vec![
+                TimeAnomaly::DuplicateHour { datetime: at(1, 1) },
+                TimeAnomaly::BackwardsJump {
+                    previous: at(1, 2),
+                    datetime: at(1, 1)
+                },
+                TimeAnomaly::DuplicateHour { datetime: at(1, 2) },
+                TimeAnomaly::Gap {
+                    from: at(1, 2),
+                    to: at(4, 3)
+                },
+            ]
+        );
+
+        // Other time units are read as the same instants
+        let microseconds =
+            HourlyLazyFrame::new(hourly_lazy.frame.clone().with_column(
+                col("datetime").cast(DataType::Datetime(TimeUnit::Microseconds, None)),
+            ));
+        assert_eq!(
+            microseconds.detect_time_anomalies()?,
+            hourly_lazy.detect_time_anomalies()?
+        );
+
+        let regular = HourlyLazyFrame::new(hourly_lazy.frame.limit(3));
+        assert!(regular.detect_time_anomalies()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_drop_empty_rows_keeps_rows_with_any_value() -> Result<(), MeteostatError> {
+        let csv = "2023-01-01,0,5.0,,80,,,,,,,,\n\
+                   2023-01-01,1,,,,,,,,,,,\n\
+                   2023-01-01,2,,,,,,,,,,,3\n";
+        let hourly_lazy = crate::parse_hourly_csv(csv.as_bytes())?;
+
+        assert_eq!(hourly_lazy.collect_hourly()?.len(), 3);
+        let non_empty = hourly_lazy.collect_hourly_non_empty()?;
+        let hours: Vec<u32> = non_empty.iter().map(|h| h.datetime.hour()).collect();
+        assert_eq!(hours, vec![0, 2]);
+        assert_eq!(hourly_lazy.drop_empty_rows().row_count()?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_for_local_period_shifts_day_boundaries() -> Result<(), MeteostatError> {
+        // Hours 20:00 on Dec 31 through 03:00 on Jan 2 (UTC)
+        let csv: String = (20..52)
+            .map(|h| {
+                let date = NaiveDate::from_ymd_opt(2022, 12, 31)
+                    .unwrap_or_default()
+                    .checked_add_days(chrono::Days::new(h / 24))
+                    .unwrap_or_default();
+                format!("{date},{},1.0,,,,,,,,,,\n", h % 24)
+            })
+            .collect();
+        let hourly_lazy = crate::parse_hourly_csv(csv.as_bytes())?;
+        let jan_1 = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap_or_default();
+
+        // UTC+2: Jan 1 runs from Dec 31 22:00 up to Jan 1 22:00 UTC
+        let east = chrono::FixedOffset::east_opt(2 * 3600).unwrap_or_else(|| unreachable!());
+        let local_day = hourly_lazy
+            .get_for_local_period(jan_1, &east)?
+            .collect_hourly()?;
+        assert_eq!(local_day.len(), 24);
+        assert_eq!(
+            local_day[0].datetime,
+            Utc.with_ymd_and_hms(2022, 12, 31, 22, 0, 0).unwrap()
+        );
+        assert_eq!(local_day[23].datetime.hour(), 21);
+
+        // UTC itself matches `get_for_period`
+        assert_eq!(
+            hourly_lazy.get_for_local_period(jan_1, &Utc)?.row_count()?,
+            hourly_lazy.get_for_period(jan_1)?.row_count()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_checks_schemas() -> Result<(), MeteostatError> {
+        // date,hour,temp,dwpt,rhum,prcp,snow,wdir,wspd,wpgt,pres,tsun,coco
+        let first = crate::parse_hourly_csv(b"2023-01-01,0,1.0,,,,,,,,,,\n")?;
+        let second =
+            crate::parse_hourly_csv(b"2023-01-01,0,2.0,,,,,,,,,,\n2023-01-01,1,3.0,,,,,,,,,,\n")?;
+        let tag = |lazy: &HourlyLazyFrame, id: &str| {
+            HourlyLazyFrame::new(
+                lazy.frame
+                    .clone()
+                    .with_column(lit(id.to_string()).alias("station_id")),
+            )
+        };
+        // Column order may differ between frames
+        let reordered = HourlyLazyFrame::new(
+            second
+                .frame
+                .clone()
+                .select([lit("B").alias("station_id"), col("*")]),
+        );
+
+        let combined = HourlyLazyFrame::concat(vec![tag(&first, "A"), reordered])?;
+        let temperatures: Vec<Option<f64>> = combined
+            .collect_hourly()?
+            .iter()
+            .map(|h| h.temperature)
+            .collect();
+        assert_eq!(temperatures, vec![Some(1.0), Some(2.0), Some(3.0)]);
+
+        let mismatch = HourlyLazyFrame::concat(vec![tag(&first, "A"), second.clone()]);
+        assert!(matches!(
+            mismatch,
+            Err(MeteostatError::SchemaMismatch { index: 1, ref reason }) if reason.contains("station_id")
+        ));
+        assert!(matches!(
+            HourlyLazyFrame::concat(vec![]),
+            Err(MeteostatError::EmptyConcat)
+        ));
+
+        // Only some frames have the optional source column
+        let with_source = HourlyLazyFrame::new(
+            second
+                .frame
+                .clone()
+                .with_column(lit("a,b").alias(SOURCE_COLUMN)),
+        );
+        let combined = HourlyLazyFrame::concat(vec![first, with_source])?
+            .frame
+            .collect()?;
+        let sources: Vec<Option<&str>> = combined.column(SOURCE_COLUMN)?.str()?.iter().collect();
+        assert_eq!(sources, vec![None, Some("a,b"), Some("a,b")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_keeps_unknown_condition_codes() -> Result<(), MeteostatError> {
+        // date,hour,temp,dwpt,rhum,prcp,snow,wdir,wspd,wpgt,pres,tsun,coco
+        let csv = "2023-01-01,0,5.0,,,,,,,,,,3\n\
+                   2023-01-01,1,5.0,,,,,,,,,,99\n\
+                   2023-01-01,2,5.0,,,,,,,,,,\n";
+        let hourly = crate::parse_hourly_csv(csv.as_bytes())?.collect_hourly()?;
+
+        assert_eq!(hourly[0].condition, Some(WeatherCondition::Cloudy));
+        assert_eq!(hourly[0].condition_code, Some(3));
+        assert_eq!(hourly[1].condition, Some(WeatherCondition::Unknown(99)));
+        assert_eq!(hourly[1].condition_code, Some(99));
+        assert_eq!(hourly[2].condition_code, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_require_fields_drops_rows_missing_any_field() -> Result<(), MeteostatError> {
+        // date,hour,temp,dwpt,rhum,prcp,snow,wdir,wspd,wpgt,pres,tsun,coco
+        let csv = "2023-01-01,0,5.0,,80,,,,12.0,,,,\n\
+                   2023-01-01,1,6.0,,,,,,12.0,,,,\n\
+                   2023-01-01,2,,,85,,,,10.0,,,,\n";
+        let hourly_lazy = crate::parse_hourly_csv(csv.as_bytes())?;
+
+        let both = hourly_lazy.require_fields(&[HourlyField::Temperature, HourlyField::WindSpeed]);
+        assert_eq!(both.row_count()?, 2);
+        let all = both.require_fields(&[HourlyField::RelativeHumidity]);
+        assert_eq!(all.collect_single_hourly()?.datetime.hour(), 0);
+        assert_eq!(hourly_lazy.require_fields(&[]).row_count()?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_downsample_lttb_keeps_extremes() -> Result<(), MeteostatError> {
+        // Two days of flat temperatures with one spike, plus a row without a temperature
+        let mut csv: String = (0..48)
+            .map(|h| {
+                let temp = if h == 30 { 25.0 } else { 10.0 };
+                format!("2023-01-{:02},{},{temp},,,,,,,,,,\n", 1 + h / 24, h % 24)
+            })
+            .collect();
+        csv.push_str("2023-01-03,0,,,,,,,,,,,\n");
+        let hourly_lazy = crate::parse_hourly_csv(csv.as_bytes())?;
+
+        let sampled = hourly_lazy.downsample_lttb(6, HourlyField::Temperature)?;
+        assert_eq!(sampled.height(), 6);
+        let temps = sampled.column("temp")?.f64()?;
+        assert!((0..6).any(|i| temps.get(i) == Some(25.0)));
+        assert_eq!(
+            sampled.width(),
+            hourly_lazy.frame.clone().collect()?.width()
+        );
+
+        let all = hourly_lazy.downsample_lttb(100, HourlyField::Temperature)?;
+        assert_eq!(all.height(), 48);
+        Ok(())
+    }
+
+    #[test]
+    fn test_wind_rose_skips_rows_with_missing_wind() -> Result<(), Box<dyn std::error::Error>> {
+        let df = df!(
+            "wdir" => [Some(0i64), Some(90), None, Some(270), Some(180)],
+            "wspd" => [Some(12.0f64), Some(5.0), Some(30.0), None, Some(0.0)]
+        )?;
+        let rose = HourlyLazyFrame::new(df.lazy()).wind_rose(4, &[10.0])?;
+
+        assert_eq!(
+            rose.counts,
+            vec![vec![0, 1], vec![1, 0], vec![0, 0], vec![0, 0]]
+        );
+        assert_eq!(rose.calm, 1);
+        assert_eq!(rose.total(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_precip_phase_uses_condition_then_temperature() -> Result<(), MeteostatError> {
+        // temp, prcp and coco: frozen, around freezing, warm, dry, snow code, rain code,
+        // snow code without precipitation amount, snow code on a dry hour
+        let csv = "2023-01-01,0,-3.0,,,1.0,,,,,,,\n\
+                   2023-01-01,1,1.0,,,0.5,,,,,,,\n\
+                   2023-01-01,2,5.0,,,2.0,,,,,,,\n\
+                   2023-01-01,3,5.0,,,0.0,,,,,,,\n\
+                   2023-01-01,4,3.0,,,0.3,,,,,,,15\n\
+                   2023-01-01,5,-2.0,,,0.4,,,,,,,8\n\
+                   2023-01-01,6,3.0,,,,,,,,,,15\n\
+                   2023-01-01,7,-1.0,,,0.0,,,,,,,15\n";
+        let hourly_lazy = crate::parse_hourly_csv(csv.as_bytes())?;
+        let phases = |lazy: HourlyLazyFrame| -> Result<Vec<Option<String>>, MeteostatError> {
+            let df = lazy.frame.collect()?;
+            Ok(df
+                .column(crate::PRECIP_PHASE_COLUMN)?
+                .str()?
+                .iter()
+                .map(|phase| phase.map(str::to_string))
+                .collect())
+        };
+        let phase = |value: &str| Some(value.to_string());
+
+        assert_eq!(
+            phases(hourly_lazy.with_precip_phase())?,
+            vec![
+                phase("snow"),
+                phase("mixed"),
+                phase("rain"),
+                None,
+                phase("snow"),
+                phase("rain"),
+                None,
+                None
+            ]
+        );
+
+        let rules = PrecipPhaseRules {
+            snow_below_c: 2.0,
+            rain_above_c: 4.0,
+            use_condition: false,
+        };
+        assert_eq!(
+            phases(hourly_lazy.with_precip_phase_rules(rules)?)?,
+            vec![
+                phase("snow"),
+                phase("snow"),
+                phase("rain"),
+                None,
+                phase("mixed"),
+                phase("snow"),
+                None,
+                None
+            ]
+        );
+
+        let inverted = PrecipPhaseRules {
+            snow_below_c: 3.0,
+            rain_above_c: 1.0,
+            ..PrecipPhaseRules::default()
+        };
+        assert!(matches!(
+            hourly_lazy.with_precip_phase_rules(inverted),
+            Err(MeteostatError::InvalidPrecipPhaseThresholds { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_since_and_last_n_hours() -> Result<(), MeteostatError> {
+        let csv = "2023-01-01,21,1.0,,,,,,,,,,\n\
+                   2023-01-01,22,2.0,,,,,,,,,,\n\
+                   2023-01-01,23,3.0,,,,,,,,,,\n\
+                   2023-01-02,0,4.0,,,,,,,,,,\n\
+                   2023-01-02,1,5.0,,,,,,,,,,\n";
+        let hourly_lazy = crate::parse_hourly_csv(csv.as_bytes())?;
+        let now = Utc.with_ymd_and_hms(2023, 1, 2, 0, 30, 0).unwrap();
+
+        assert_eq!(
+            hourly_lazy
+                .since_at(now, chrono::Duration::hours(2))
+                .row_count()?,
+            2
+        );
+        assert_eq!(
+            hourly_lazy
+                .since_at(now, chrono::Duration::hours(-2))
+                .row_count()?,
+            2
+        );
+        assert_eq!(hourly_lazy.last_n_hours_at(now, 3).row_count()?, 3);
+        assert_eq!(hourly_lazy.last_n_hours_at(now, 1).row_count()?, 1);
+        assert_eq!(hourly_lazy.last_n_hours_at(now, 0).row_count()?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_hourly_tolerates_float_integer_columns() -> Result<(), MeteostatError> {
+        let csv = "2023-01-01,0,1.0,,85,,,270,,,,,8\n";
+        let parsed = crate::parse_hourly_csv(csv.as_bytes())?;
+        let hourly_lazy = HourlyLazyFrame::new(parsed.frame.with_columns([
+            col("coco").cast(DataType::Float64),
+            col("rhum").cast(DataType::Float64),
+            col("wdir").cast(DataType::Int32),
+        ]));
+
+        let hourly = hourly_lazy.collect_single_hourly()?;
+        assert_eq!(hourly.condition_code, Some(8));
+        assert_eq!(hourly.relative_humidity, Some(85));
+        assert_eq!(hourly.wind_direction, Some(270));
+        Ok(())
+    }
 }
@@ -0,0 +1,229 @@
+//! Defines the `MeteostatFrame` trait shared by the hourly, daily, monthly and climate frame
+//! wrappers.
+
+use crate::types::frequency::Frequency;
+use crate::{ClimateLazyFrame, DailyLazyFrame, HourlyLazyFrame, MeteostatError, MonthlyLazyFrame};
+use polars::prelude::{len, CsvWriter, DataFrame, LazyFrame, SerWriter};
+use std::fs::File;
+use std::future::Future;
+use std::path::Path;
+
+/// Frame-level operations shared by [`HourlyLazyFrame`], [`DailyLazyFrame`],
+/// [`MonthlyLazyFrame`] and [`ClimateLazyFrame`].
+///
+/// Use this trait to write code that accepts the data of any frequency. Filtering and
+/// collecting into typed rows stay on the wrappers themselves, since their periods and row
+/// types differ per frequency.
+///
+/// # Stacking frames
+///
+/// Each wrapper's `concat`, like [`HourlyLazyFrame::concat`], vertically stacks frames of its
+/// frequency, e.g. data from several stations. All frames must have the same columns with the
+/// same types; the column order may differ. Only the optional `source` column may be missing
+/// from some frames, which get nulls. Build the client with `.tag_station_id(true)` to keep
+/// track of which station each row came from. Rows are kept in the order of the input frames.
+///
+/// ```no_run
+/// # use meteostat::{HourlyLazyFrame, Meteostat, MeteostatError, Year};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), MeteostatError> {
+/// let client = Meteostat::builder().tag_station_id(true).build().await?;
+///
+/// let mut frames = Vec::new();
+/// for station in ["06240", "06260", "06280"] {
+///     let hourly = client.hourly().station(station).call().await?;
+///     frames.push(hourly.get_for_period(Year(2023))?);
+/// }
+/// let combined = HourlyLazyFrame::concat(frames)?;
+/// println!("{}", combined.frame.collect()?);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```no_run
+/// use meteostat::{Meteostat, MeteostatError, MeteostatFrame};
+///
+/// fn summarize(data: &impl MeteostatFrame) -> Result<String, MeteostatError> {
+///     Ok(format!("{}: {} rows", data.frequency(), data.row_count()?))
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), MeteostatError> {
+/// let client = Meteostat::new().await?;
+/// println!("{}", summarize(&client.daily().station("06240").call().await?)?);
+/// println!("{}", summarize(&client.monthly().station("06240").call().await?)?);
+/// # Ok(())
+/// # }
+/// ```
+pub trait MeteostatFrame {
+    /// The frequency of the data held by this wrapper.
+    const FREQUENCY: Frequency;
+
+    /// Returns a reference to the underlying Polars `LazyFrame`.
+    fn frame(&self) -> &LazyFrame;
+
+    /// Consumes the wrapper and returns the underlying Polars `LazyFrame`.
+    fn into_frame(self) -> LazyFrame;
+
+    /// The frequency of the data held by this wrapper, see [`MeteostatFrame::FREQUENCY`].
+    fn frequency(&self) -> Frequency {
+        Self::FREQUENCY
+    }
+
+    /// Counts the rows of the lazy query without collecting all columns.
+    ///
+    /// Only the row count is computed, which lets Polars skip reading most of the data.
+    /// This is much cheaper than `collect()?.height()` when you only want to know if a
+    /// station and range are worth fetching in full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    fn row_count(&self) -> Result<usize, MeteostatError> {
+        let df = self.frame().clone().select([len()]).collect()?;
+        let count = df.column("len")?.idx()?.get(0).unwrap_or(0);
+        Ok(count as usize)
+    }
+
+    /// Executes the lazy query and returns all columns as a Polars `DataFrame`.
+    ///
+    /// Shorthand for `.frame().clone().collect()`, for code that doesn't need the lazy API.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    fn collect_frame(&self) -> Result<DataFrame, MeteostatError> {
+        Ok(self.frame().clone().collect()?)
+    }
+
+    /// Async variant of [`MeteostatFrame::collect_frame`] that runs the query on tokio's
+    /// blocking thread pool, so a large collection doesn't stall other tasks on the runtime.
+    /// The wrappers' typed `collect_*_async` methods do the same for their rows.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`MeteostatFrame::collect_frame`], or [`MeteostatError::TaskJoin`] if the
+    /// background task panics or is cancelled.
+    fn collect_frame_async(
+        &self,
+    ) -> impl Future<Output = Result<DataFrame, MeteostatError>> + Send {
+        let frame = self.frame().clone();
+        run_blocking(move || Ok(frame.collect()?))
+    }
+
+    /// Describes the optimized Polars query plan, without executing it.
+    ///
+    /// Useful for debugging slow queries, e.g. to check whether filters are pushed down
+    /// into the scan of the cached file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the plan cannot be optimized.
+    fn explain(&self) -> Result<String, MeteostatError> {
+        Ok(self.frame().explain(true)?)
+    }
+
+    /// Executes the lazy query and writes all columns to a CSV file with a header row,
+    /// creating or truncating the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::ExportIo`] if the file cannot be created, or
+    /// [`MeteostatError::PolarsError`] if the lazy computation or writing fails.
+    fn write_csv(&self, path: impl AsRef<Path>) -> Result<(), MeteostatError> {
+        let path = path.as_ref();
+        let mut df = self.collect_frame()?;
+        let file =
+            File::create(path).map_err(|e| MeteostatError::ExportIo(path.to_path_buf(), e))?;
+        CsvWriter::new(file).finish(&mut df)?;
+        Ok(())
+    }
+}
+
+/// Runs `collect` on tokio's blocking thread pool, see [`MeteostatFrame::collect_frame_async`].
+pub(crate) async fn run_blocking<T: Send + 'static>(
+    collect: impl FnOnce() -> Result<T, MeteostatError> + Send + 'static,
+) -> Result<T, MeteostatError> {
+    tokio::task::spawn_blocking(collect).await?
+}
+
+impl MeteostatFrame for HourlyLazyFrame {
+    const FREQUENCY: Frequency = Frequency::Hourly;
+
+    fn frame(&self) -> &LazyFrame {
+        &self.frame
+    }
+
+    fn into_frame(self) -> LazyFrame {
+        self.frame
+    }
+}
+
+impl MeteostatFrame for DailyLazyFrame {
+    const FREQUENCY: Frequency = Frequency::Daily;
+
+    fn frame(&self) -> &LazyFrame {
+        &self.frame
+    }
+
+    fn into_frame(self) -> LazyFrame {
+        self.frame
+    }
+}
+
+impl MeteostatFrame for MonthlyLazyFrame {
+    const FREQUENCY: Frequency = Frequency::Monthly;
+
+    fn frame(&self) -> &LazyFrame {
+        &self.frame
+    }
+
+    fn into_frame(self) -> LazyFrame {
+        self.frame
+    }
+}
+
+impl MeteostatFrame for ClimateLazyFrame {
+    const FREQUENCY: Frequency = Frequency::Climate;
+
+    fn frame(&self) -> &LazyFrame {
+        &self.frame
+    }
+
+    fn into_frame(self) -> LazyFrame {
+        self.frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_daily_csv, parse_monthly_csv, Year};
+
+    fn describe(data: &impl MeteostatFrame) -> Result<(Frequency, usize), MeteostatError> {
+        Ok((data.frequency(), data.row_count()?))
+    }
+
+    #[test]
+    fn test_generic_over_frequencies() -> Result<(), Box<dyn std::error::Error>> {
+        let daily = parse_daily_csv(b"2023-01-01,5.1,,,,,,,,,\n2023-01-02,4.0,,,,,,,,,\n")?;
+        let monthly = parse_monthly_csv(b"2023,1,2.0,,,,,,\n")?;
+        assert_eq!(describe(&daily)?, (Frequency::Daily, 2));
+        assert_eq!(describe(&monthly)?, (Frequency::Monthly, 1));
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("daily.csv");
+        daily.write_csv(&path)?;
+        let written = std::fs::read_to_string(&path)?;
+        assert!(written.starts_with("date,tavg,"));
+        assert_eq!(written.lines().count(), 3);
+
+        let plan = daily.get_range(Year(2023), Year(2023))?.explain()?;
+        assert!(plan.starts_with("FILTER"), "{plan}");
+
+        assert_eq!(daily.into_frame().collect()?.height(), 2);
+        Ok(())
+    }
+}
@@ -1,4 +1,79 @@
+use crate::weather_data::data_loader::SOURCE_COLUMN;
+use crate::MeteostatError;
+use polars::prelude::{col, concat, lit, DataType, Expr, LazyFrame, Schema, UnionArgs, NULL};
+
 pub mod climate_frame;
 pub mod daily_frame;
 pub mod hourly_frame;
+pub mod meteostat_frame;
 pub mod monthly_frame;
+pub mod period_filter;
+
+/// Vertically stacks lazy frames, after checking that they all have the columns and types of
+/// the first frame. Columns are put in the order of the first frame, so frames with the same
+/// columns in another order can still be stacked. The optional `source` column may be present
+/// in only some of the frames; the others get a null `source` column.
+///
+/// # Errors
+///
+/// Returns [`MeteostatError::EmptyConcat`] if `frames` is empty,
+/// [`MeteostatError::SchemaMismatch`] if a frame's columns differ from the first frame's, or
+/// [`MeteostatError::PolarsError`] if a schema cannot be resolved.
+pub fn concat_frames(frames: Vec<LazyFrame>) -> Result<LazyFrame, MeteostatError> {
+    let mut frames = frames
+        .into_iter()
+        .map(|mut frame| Ok((frame.collect_schema()?, frame)))
+        .collect::<Result<Vec<_>, MeteostatError>>()?;
+
+    // Pad frames without the source column with nulls, so they match the ones that have it
+    if frames
+        .iter()
+        .any(|(schema, _)| schema.contains(SOURCE_COLUMN))
+    {
+        for (schema, frame) in &mut frames {
+            if !schema.contains(SOURCE_COLUMN) {
+                *frame = frame
+                    .clone()
+                    .with_column(lit(NULL).cast(DataType::String).alias(SOURCE_COLUMN));
+                *schema = frame.collect_schema()?;
+            }
+        }
+    }
+
+    let mut frames = frames.into_iter().enumerate();
+    let Some((_, (expected, first))) = frames.next() else {
+        return Err(MeteostatError::EmptyConcat);
+    };
+    let columns: Vec<Expr> = expected
+        .iter_names()
+        .map(|name| col(name.clone()))
+        .collect();
+
+    let mut aligned = vec![first];
+    for (index, (schema, frame)) in frames {
+        if let Some(reason) = schema_difference(&expected, &schema) {
+            return Err(MeteostatError::SchemaMismatch { index, reason });
+        }
+        aligned.push(frame.select(columns.clone()));
+    }
+    Ok(concat(aligned, UnionArgs::default())?)
+}
+
+/// Describes the first difference between two schemas, ignoring column order.
+fn schema_difference(expected: &Schema, actual: &Schema) -> Option<String> {
+    for (name, dtype) in expected.iter() {
+        match actual.get(name) {
+            None => return Some(format!("column `{name}` is missing")),
+            Some(found) if found != dtype => {
+                return Some(format!(
+                    "column `{name}` has type {found} instead of {dtype}"
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+    actual
+        .iter_names()
+        .find(|name| !expected.contains(name))
+        .map(|name| format!("column `{name}` is not in the first frame"))
+}
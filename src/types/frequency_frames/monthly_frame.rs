@@ -2,11 +2,20 @@
 
 //! Contains the `MonthlyLazyFrame` structure for handling lazy operations on Meteostat monthly weather data.
 
+use crate::types::frequency_frames::concat_frames;
+use crate::types::frequency_frames::meteostat_frame::{run_blocking, MeteostatFrame};
+use crate::types::frequency_frames::period_filter::PeriodFilter;
+use crate::types::records::MonthlyRecords;
 use crate::types::traits::any::any_month::AnyMonth;
 use crate::types::traits::period::month_period::MonthPeriod;
-use crate::MeteostatError;
-use polars::prelude::{col, lit, DataFrame, Expr, LazyFrame};
+use crate::types::unit_system::UnitSystem;
+use crate::utils::{column_f64, column_i64, Measurement};
+use crate::weather_data::data_loader::SOURCE_COLUMN;
+use crate::{Daily, MeteostatError, Month};
+use chrono::{DateTime, Datelike, Duration, Utc};
+use polars::prelude::{col, lit, Column, DataFrame, Expr, LazyFrame};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents a row of monthly weather data, suitable for collecting results.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -29,6 +38,167 @@ pub struct Monthly {
     pub pressure: Option<f64>, // pres
     /// Total sunshine duration in minutes.
     pub sunshine_minutes: Option<i32>, // tsun (read as i64, store as i32)
+    /// Provenance or quality flag for this record (e.g. measured vs. model-derived), if the
+    /// source file provides one. `None` when the column is absent.
+    pub source: Option<String>,
+}
+
+/// Formats the record as a one-line summary with units, rendering missing values as "n/a".
+///
+/// For example: `2023-01: avg 3.2 °C (min 0.1 °C, max 6 °C), precipitation 54 mm,
+/// wind 14 km/h, pressure 1015 hPa, sunshine 2100 min`.
+impl fmt::Display for Monthly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{:02}: avg {} (min {}, max {}), precipitation {}, wind {}, pressure {}, sunshine {}",
+            self.year,
+            self.month,
+            Measurement(&self.average_temperature, " °C"),
+            Measurement(&self.minimum_temperature, " °C"),
+            Measurement(&self.maximum_temperature, " °C"),
+            Measurement(&self.precipitation, " mm"),
+            Measurement(&self.wind_speed, " km/h"),
+            Measurement(&self.pressure, " hPa"),
+            Measurement(&self.sunshine_minutes, " min"),
+        )
+    }
+}
+
+impl Monthly {
+    /// Returns this record with its temperatures, precipitation, wind speeds and pressure
+    /// converted from Meteostat's metric units into `units`.
+    ///
+    /// The fields don't carry their unit, so only convert a record once. The `Display` output
+    /// keeps labelling the metric units.
+    #[must_use]
+    pub fn with_units(self, units: UnitSystem) -> Self {
+        Self {
+            average_temperature: self
+                .average_temperature
+                .map(|value| units.temperature(value)),
+            minimum_temperature: self
+                .minimum_temperature
+                .map(|value| units.temperature(value)),
+            maximum_temperature: self
+                .maximum_temperature
+                .map(|value| units.temperature(value)),
+            precipitation: self.precipitation.map(|value| units.precipitation(value)),
+            wind_speed: self.wind_speed.map(|value| units.wind_speed(value)),
+            pressure: self.pressure.map(|value| units.pressure(value)),
+            ..self
+        }
+    }
+
+    /// The total sunshine duration of the month as a [`chrono::Duration`], or `None` if missing.
+    #[must_use]
+    pub fn sunshine_duration(&self) -> Option<Duration> {
+        self.sunshine_minutes
+            .map(|minutes| Duration::minutes(i64::from(minutes)))
+    }
+
+    /// The spread between the maximum and minimum temperature of the month in °C, or
+    /// `None` if either is missing.
+    #[must_use]
+    pub fn temperature_range(&self) -> Option<f64> {
+        Some(self.maximum_temperature? - self.minimum_temperature?)
+    }
+
+    /// Aggregates the daily records of a single calendar month into a `Monthly` record.
+    ///
+    /// Temperatures, wind speed and pressure are averaged, while precipitation and sunshine
+    /// duration are summed. Missing (`None`) values are skipped; a field is only `None` in the
+    /// result if it was missing on every day. The `source` flag is kept only if all days share it.
+    ///
+    /// # Arguments
+    ///
+    /// * `days` - The daily records to aggregate. Order does not matter.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Monthly)` if `days` is non-empty and every record falls in the same year and month,
+    /// otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, Month, Monthly};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Meteostat::new().await?;
+    /// let days = client
+    ///     .daily()
+    ///     .station("10384")
+    ///     .call()
+    ///     .await?
+    ///     .get_for_period(Month::new(7, 2022))?
+    ///     .collect_daily()?;
+    ///
+    /// if let Some(july) = Monthly::from_daily(&days) {
+    ///     println!("July 2022 precipitation: {:?} mm", july.precipitation);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn from_daily(days: &[Daily]) -> Option<Self> {
+        let first = days.first()?;
+        let (year, month) = (first.date.year(), first.date.month());
+        if days
+            .iter()
+            .any(|d| d.date.year() != year || d.date.month() != month)
+        {
+            return None;
+        }
+
+        Some(Self {
+            year,
+            month,
+            average_temperature: mean(days.iter().map(|d| d.average_temperature)),
+            minimum_temperature: mean(days.iter().map(|d| d.minimum_temperature)),
+            maximum_temperature: mean(days.iter().map(|d| d.maximum_temperature)),
+            precipitation: sum(days.iter().map(|d| d.precipitation)),
+            wind_speed: mean(days.iter().map(|d| d.wind_speed)),
+            pressure: mean(days.iter().map(|d| d.pressure)),
+            sunshine_minutes: days
+                .iter()
+                .filter_map(|d| d.sunshine_minutes)
+                .reduce(i32::saturating_add),
+            source: first
+                .source
+                .clone()
+                .filter(|s| days.iter().all(|d| d.source.as_ref() == Some(s))),
+        })
+    }
+}
+
+/// Attempts to aggregate a slice of daily records into a single monthly record.
+///
+/// See [`Monthly::from_daily`] for the aggregation rules.
+///
+/// # Errors
+///
+/// Returns [`MeteostatError::InvalidDailyAggregation`] if the slice is empty or spans
+/// more than one calendar month.
+impl TryFrom<&[Daily]> for Monthly {
+    type Error = MeteostatError;
+
+    fn try_from(days: &[Daily]) -> Result<Self, Self::Error> {
+        Self::from_daily(days).ok_or(MeteostatError::InvalidDailyAggregation)
+    }
+}
+
+// --- Helpers for null-aware aggregation ---
+#[allow(clippy::cast_precision_loss)]
+fn mean(values: impl Iterator<Item = Option<f64>>) -> Option<f64> {
+    let (total, count) = values
+        .flatten()
+        .fold((0.0, 0usize), |(total, count), v| (total + v, count + 1));
+    (count > 0).then(|| total / count as f64)
+}
+
+fn sum(values: impl Iterator<Item = Option<f64>>) -> Option<f64> {
+    values.flatten().reduce(|a, b| a + b)
 }
 
 /// A wrapper around a Polars `LazyFrame` specifically for Meteostat monthly weather data.
@@ -73,6 +243,17 @@ impl MonthlyLazyFrame {
         Self { frame }
     }
 
+    /// Vertically stacks several `MonthlyLazyFrame`s into one, e.g. data from several stations.
+    /// See [stacking frames](MeteostatFrame#stacking-frames) for the rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::EmptyConcat`] or [`MeteostatError::SchemaMismatch`] if the
+    /// frames can't be stacked, or [`MeteostatError::PolarsError`] if a schema can't be resolved.
+    pub fn concat(frames: Vec<Self>) -> Result<Self, MeteostatError> {
+        concat_frames(frames.into_iter().map(|lazy| lazy.frame).collect()).map(Self::new)
+    }
+
     /// Filters the monthly data based on a Polars predicate expression.
     ///
     /// This method allows applying arbitrary filtering logic supported by Polars.
@@ -119,6 +300,27 @@ impl MonthlyLazyFrame {
         Self::new(self.frame.clone().filter(predicate))
     }
 
+    /// Converts the temperature, wind speed and pressure columns from Meteostat's metric units
+    /// into `units`. Precipitation keeps its values, see [`UnitSystem::Scientific`].
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The unit system to convert into. [`UnitSystem::Metric`] changes nothing.
+    ///
+    /// # Returns
+    ///
+    /// A new `MonthlyLazyFrame` with the conversion applied lazily.
+    #[must_use]
+    pub fn with_units(&self, units: UnitSystem) -> Self {
+        let exprs = units.column_exprs(&["tavg", "tmin", "tmax"], &["wspd"], &["pres"]);
+        if exprs.is_empty() {
+            return self.clone();
+        }
+        Self {
+            frame: self.frame.clone().with_columns(exprs),
+        }
+    }
+
     /// Filters the monthly data to include only records within the specified month range (inclusive).
     ///
     /// The `start` and `end` arguments can be any type that implements [`AnyMonth`],
@@ -145,35 +347,142 @@ impl MonthlyLazyFrame {
         start: impl AnyMonth,
         end: impl AnyMonth,
     ) -> Result<Self, MeteostatError> {
-        // Resolve inputs to start and end Months
-        let start_month_obj = start
-            .get_month_range()
-            .ok_or(MeteostatError::DateParsingError)?
-            .start;
-        let end_month_obj = end
-            .get_month_range()
-            .ok_or(MeteostatError::DateParsingError)?
-            .end;
+        PeriodFilter::get_range_from(self, start)?.get_range_until(end)
+    }
 
+    /// Filters the monthly data to include only months on or after `start`, without an end bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The starting month boundary (inclusive), implementing [`AnyMonth`].
+    ///   A [`crate::Year`] starts at its January.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `MonthlyLazyFrame` with the months from `start` onward.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `start` cannot be resolved into a
+    /// valid year and month.
+    pub fn get_range_from(&self, start: impl AnyMonth) -> Result<Self, MeteostatError> {
+        PeriodFilter::get_range_from(self, start)
+    }
+
+    /// Filters the monthly data to include only months on or before `end`, without a start bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - The ending month boundary (inclusive), implementing [`AnyMonth`].
+    ///   A [`crate::Year`] ends at its December.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a new `MonthlyLazyFrame` with the months up to and including `end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `end` cannot be resolved into a
+    /// valid year and month.
+    pub fn get_range_until(&self, end: impl AnyMonth) -> Result<Self, MeteostatError> {
+        PeriodFilter::get_range_until(self, end)
+    }
+
+    // Condition: (year > start_year) OR (year == start_year AND month >= start_month)
+    pub(crate) fn on_or_after(start: Month) -> Expr {
         // Use i64 literals for comparison as Polars often reads CSV integers as i64
-        let start_year = i64::from(start_month_obj.year());
-        let end_year = i64::from(end_month_obj.year());
-        let start_month_num = i64::from(start_month_obj.month());
-        let end_month_num = i64::from(end_month_obj.month());
+        let year = i64::from(start.year());
+        let month = i64::from(start.month());
+        col("year").gt(lit(year)).or(col("year")
+            .eq(lit(year))
+            .and(col("month").gt_eq(lit(month))))
+    }
+
+    // Condition: (year < end_year) OR (year == end_year AND month <= end_month)
+    pub(crate) fn on_or_before(end: Month) -> Expr {
+        let year = i64::from(end.year());
+        let month = i64::from(end.month());
+        col("year").lt(lit(year)).or(col("year")
+            .eq(lit(year))
+            .and(col("month").lt_eq(lit(month))))
+    }
+
+    /// Filters the monthly data to the months from `duration` ago up to the current month
+    /// (UTC).
+    ///
+    /// Both ends are whole months: the month containing the moment `duration` before now and
+    /// the current month are included. The range is computed from [`Utc::now`] when this
+    /// method is called. Negative durations are treated as positive.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - How far back to keep records, e.g. `Duration::days(365)`.
+    ///
+    /// # Returns
+    ///
+    /// A new `MonthlyLazyFrame` with the filter applied lazily.
+    #[must_use]
+    pub fn since(&self, duration: Duration) -> Self {
+        self.since_at(Utc::now(), duration)
+    }
 
-        // Build the filter expression
-        // Condition: (year > start_year) OR (year == start_year AND month >= start_month_num)
-        let after_start_cond = col("year").gt(lit(start_year)).or(col("year")
-            .eq(lit(start_year))
-            .and(col("month").gt_eq(lit(start_month_num))));
+    /// Filters the monthly data to the last `n` months, counting the current month (UTC) as
+    /// the last one. `n = 0` keeps no records.
+    ///
+    /// Meteostat only publishes a month once it is complete, so e.g. `last_n_months(13)`
+    /// holds at most the previous 12 months.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of months to keep.
+    ///
+    /// # Returns
+    ///
+    /// A new `MonthlyLazyFrame` with the filter applied lazily.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let monthly_lazy = client.monthly().station("06240").call().await?;
+    ///
+    /// let past_year = monthly_lazy.last_n_months(13).collect_monthly()?;
+    /// println!("{} months", past_year.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn last_n_months(&self, n: u32) -> Self {
+        self.last_n_months_at(Utc::now(), n)
+    }
 
-        // Condition: (year < end_year) OR (year == end_year AND month <= end_month_num)
-        let before_end_cond = col("year").lt(lit(end_year)).or(col("year")
-            .eq(lit(end_year))
-            .and(col("month").lt_eq(lit(end_month_num))));
+    fn since_at(&self, now: DateTime<Utc>, duration: Duration) -> Self {
+        let start = now
+            .checked_sub_signed(duration.abs())
+            .map_or(Month(i32::MIN, 1), |start| {
+                Month(start.year(), start.month())
+            });
+        self.filter(
+            Self::on_or_after(start).and(Self::on_or_before(Month(now.year(), now.month()))),
+        )
+    }
 
-        // Combine the conditions: Must be after start AND before end
-        Ok(self.filter(after_start_cond.and(before_end_cond)))
+    fn last_n_months_at(&self, now: DateTime<Utc>, n: u32) -> Self {
+        let current = i64::from(now.year()) * 12 + i64::from(now.month0());
+        let start_index = current - i64::from(n) + 1;
+        let start = i32::try_from(start_index.div_euclid(12)).map_or(Month(i32::MIN, 1), |year| {
+            // rem_euclid(12) is in 0..12, so the month always fits
+            Month(
+                year,
+                u32::try_from(start_index.rem_euclid(12)).unwrap_or(0) + 1,
+            )
+        });
+        self.filter(
+            Self::on_or_after(start).and(Self::on_or_before(Month(now.year(), now.month()))),
+        )
     }
 
     /// Filters the monthly data to get the record for a specific year and month.
@@ -219,7 +528,7 @@ impl MonthlyLazyFrame {
     ///
     /// This is a convenience method that accepts types implementing [`MonthPeriod`],
     /// such as [`crate::Year`]. It resolves the period into a start and end `Month`
-    /// and then calls `get_range`.
+    /// and filters with [`PeriodFilter::get_for_period`].
     ///
     /// # Arguments
     ///
@@ -234,14 +543,73 @@ impl MonthlyLazyFrame {
     /// Returns [`MeteostatError::DateParsingError`] if the given period cannot be
     /// resolved into a valid start and end month.
     pub fn get_for_period(&self, period: impl MonthPeriod) -> Result<Self, MeteostatError> {
-        let month_period = period
+        let period = period
             .get_month_period()
             .ok_or(MeteostatError::DateParsingError)?;
-        // Delegate to get_range using the resolved start and end months
-        self.get_range(month_period.start, month_period.end)
+        PeriodFilter::get_for_period(self, period)
     }
 
-    /// Executes the lazy query and collects the results into a `Vec<Monthly>`.
+    /// Filters the monthly data to the months within any of several periods.
+    ///
+    /// The periods don't have to be contiguous, e.g. the summer months of several years.
+    /// They are combined into a single predicate, so the frame is scanned only once and
+    /// rows stay in their original order. Overlapping periods don't duplicate rows, and an
+    /// empty list of periods keeps no rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if any of the periods cannot be
+    /// resolved into a valid start and end month.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Year};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    /// let monthly = client.monthly().station("06240").call().await?;
+    ///
+    /// let years = monthly.get_for_periods([Year(2010), Year(2015), Year(2020)])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_for_periods<P: MonthPeriod>(
+        &self,
+        periods: impl IntoIterator<Item = P>,
+    ) -> Result<Self, MeteostatError> {
+        let periods = periods
+            .into_iter()
+            .map(|period| {
+                period
+                    .get_month_period()
+                    .ok_or(MeteostatError::DateParsingError)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        PeriodFilter::get_for_periods(self, periods)
+    }
+
+    /// Counts the rows of the lazy query without collecting all columns, see
+    /// [`MeteostatFrame::row_count`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    pub fn row_count(&self) -> Result<usize, MeteostatError> {
+        MeteostatFrame::row_count(self)
+    }
+
+    /// Executes the lazy query and returns all columns as a Polars `DataFrame`, see
+    /// [`MeteostatFrame::collect_frame`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::PolarsError`] if the lazy computation fails.
+    pub fn collect_frame(&self) -> Result<DataFrame, MeteostatError> {
+        MeteostatFrame::collect_frame(self)
+    }
+
+    /// Executes the lazy query and collects the results into [`MonthlyRecords`].
     ///
     /// This method triggers the computation defined by the `LazyFrame` (including any
     /// previous filtering operations) and maps each resulting row to a `Monthly` struct.
@@ -249,7 +617,7 @@ impl MonthlyLazyFrame {
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `Vec<Monthly>` on success.
+    /// A `Result` containing the [`MonthlyRecords`] on success.
     ///
     /// # Errors
     ///
@@ -261,7 +629,7 @@ impl MonthlyLazyFrame {
     /// # Example
     ///
     /// ```no_run
-    /// # use meteostat::{Meteostat, MeteostatError, LatLon, Year, Monthly};
+    /// # use meteostat::{Meteostat, MeteostatError, LatLon, Year, Monthly, MonthlyRecords};
     /// use polars::prelude::PolarsError;
     ///
     /// # #[tokio::main]
@@ -278,8 +646,8 @@ impl MonthlyLazyFrame {
     /// // Get data for a specific year
     /// let year_data_lazy = monthly_lazy.get_for_period(Year(2022))?;
     ///
-    /// // Collect into Vec<Monthly>
-    /// let monthly_vec: Vec<Monthly> = year_data_lazy.collect_monthly()?;
+    /// // Collect into MonthlyRecords
+    /// let monthly_vec: MonthlyRecords = year_data_lazy.collect_monthly()?;
     ///
     /// println!("Collected {} monthly records for 2022.", monthly_vec.len());
     /// if let Some(first_month) = monthly_vec.first() {
@@ -288,14 +656,8 @@ impl MonthlyLazyFrame {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn collect_monthly(&self) -> Result<Vec<Monthly>, MeteostatError> {
-        let df = self
-            .frame
-            .clone() // Clone frame as collect consumes it
-            .collect()
-            .map_err(MeteostatError::PolarsError)?; // Map PolarsError
-
-        Self::dataframe_to_monthly_vec(&df) // Use helper function
+    pub fn collect_monthly(&self) -> Result<MonthlyRecords, MeteostatError> {
+        self.clone().into_collect_monthly()
     }
 
     /// Executes the lazy query, expecting exactly one row, and collects it into a `Monthly` struct.
@@ -349,11 +711,52 @@ impl MonthlyLazyFrame {
     /// # }
     /// ```
     pub fn collect_single_monthly(&self) -> Result<Monthly, MeteostatError> {
-        let df = self
-            .frame
-            .clone()
-            .collect()
-            .map_err(MeteostatError::PolarsError)?;
+        self.clone().into_collect_single_monthly()
+    }
+
+    /// Consuming variant of [`Self::collect_monthly`].
+    ///
+    /// Takes the frame by value, so the query plan doesn't have to be cloned before
+    /// collecting. Prefer this for one-shot queries where the frame isn't reused.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_monthly`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meteostat::{Meteostat, MeteostatError, Monthly, MonthlyRecords};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), MeteostatError> {
+    /// let client = Meteostat::new().await?;
+    ///
+    /// let records: MonthlyRecords = client
+    ///     .monthly()
+    ///     .station("10637")
+    ///     .call()
+    ///     .await?
+    ///     .into_collect_monthly()?; // No clone of the query plan needed
+    /// println!("Collected {} records.", records.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_collect_monthly(self) -> Result<MonthlyRecords, MeteostatError> {
+        let df = self.frame.collect().map_err(MeteostatError::PolarsError)?; // Map PolarsError
+
+        Self::dataframe_to_monthly_vec(&df).map(MonthlyRecords::from) // Use helper function
+    }
+
+    /// Consuming variant of [`Self::collect_single_monthly`].
+    ///
+    /// Takes the frame by value, so the query plan doesn't have to be cloned before
+    /// collecting.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_single_monthly`].
+    pub fn into_collect_single_monthly(self) -> Result<Monthly, MeteostatError> {
+        let df = self.frame.collect().map_err(MeteostatError::PolarsError)?;
 
         if df.height() != 1 {
             return Err(MeteostatError::ExpectedSingleRow {
@@ -367,6 +770,27 @@ impl MonthlyLazyFrame {
             .ok_or(MeteostatError::ExpectedSingleRow { actual: 0 }) // Should be unreachable
     }
 
+    /// Async variant of [`Self::collect_monthly`], see [`MeteostatFrame::collect_frame_async`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_monthly`], or [`MeteostatError::TaskJoin`] if the background task fails.
+    pub async fn collect_monthly_async(&self) -> Result<MonthlyRecords, MeteostatError> {
+        let data = self.clone();
+        run_blocking(move || data.into_collect_monthly()).await
+    }
+
+    /// Async variant of [`Self::collect_single_monthly`], see [`MeteostatFrame::collect_frame_async`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::collect_single_monthly`], or [`MeteostatError::TaskJoin`] if the background
+    /// task fails.
+    pub async fn collect_single_monthly_async(&self) -> Result<Monthly, MeteostatError> {
+        let data = self.clone();
+        run_blocking(move || data.into_collect_single_monthly()).await
+    }
+
     // --- Helper function to map DataFrame rows to Vec<Monthly> ---
     fn dataframe_to_monthly_vec(df: &DataFrame) -> Result<Vec<Monthly>, MeteostatError> {
         // --- Get required columns as Series ---
@@ -380,16 +804,18 @@ impl MonthlyLazyFrame {
         let pres_series = df.column("pres")?;
         let tsun_series = df.column("tsun")?; // Integer type
 
-        // --- Get ChunkedArrays (assuming Polars read them as i64/f64) ---
-        let year_ca = year_series.i64()?;
-        let month_ca = month_series.i64()?;
-        let tavg_ca = tavg_series.f64()?;
-        let tmin_ca = tmin_series.f64()?;
-        let tmax_ca = tmax_series.f64()?;
-        let prcp_ca = prcp_series.f64()?;
-        let wspd_ca = wspd_series.f64()?;
-        let pres_ca = pres_series.f64()?;
-        let tsun_ca = tsun_series.i64()?; // Read as i64 initially
+        // --- Get ChunkedArrays (numeric columns are cast from whatever type Polars read) ---
+        let year_ca = column_i64(year_series)?;
+        let month_ca = column_i64(month_series)?;
+        let tavg_ca = column_f64(tavg_series)?;
+        let tmin_ca = column_f64(tmin_series)?;
+        let tmax_ca = column_f64(tmax_series)?;
+        let prcp_ca = column_f64(prcp_series)?;
+        let wspd_ca = column_f64(wspd_series)?;
+        let pres_ca = column_f64(pres_series)?;
+        let tsun_ca = column_i64(tsun_series)?;
+        // Optional provenance column, only present in some source files
+        let source_ca = df.column(SOURCE_COLUMN).ok().map(Column::str).transpose()?;
 
         let mut monthly_vec = Vec::with_capacity(df.height());
 
@@ -418,6 +844,7 @@ impl MonthlyLazyFrame {
                 wind_speed: wspd_ca.get(i),
                 pressure: pres_ca.get(i),
                 sunshine_minutes: tsun_ca.get(i).and_then(|v| i32::try_from(v).ok()), // Convert Option<i64> to Option<i32>
+                source: source_ca.and_then(|ca| ca.get(i)).map(str::to_string),
             };
 
             monthly_vec.push(monthly_record);
@@ -791,4 +1218,98 @@ mod tests {
 
         Ok(())
     }
+
+    // --- Aggregation from Daily ---
+
+    fn test_day(date: &str, tavg: Option<f64>, prcp: Option<f64>, tsun: Option<i32>) -> Daily {
+        Daily {
+            date: chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            average_temperature: tavg,
+            minimum_temperature: tavg.map(|t| t - 5.0),
+            maximum_temperature: tavg.map(|t| t + 5.0),
+            precipitation: prcp,
+            snow: None,
+            wind_direction: None,
+            wind_speed: None,
+            peak_wind_gust: None,
+            pressure: None,
+            sunshine_minutes: tsun,
+            source: None,
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_monthly_from_daily_aggregates_and_skips_nulls() {
+        let days = [
+            test_day("2022-07-01", Some(20.0), Some(1.5), Some(300)),
+            test_day("2022-07-02", None, Some(2.5), None),
+            test_day("2022-07-03", Some(24.0), None, Some(120)),
+        ];
+
+        let monthly = Monthly::from_daily(&days).expect("Expected a monthly record");
+
+        assert_eq!(monthly.year, 2022);
+        assert_eq!(monthly.month, 7);
+        assert_eq!(monthly.average_temperature, Some(22.0));
+        assert_eq!(monthly.minimum_temperature, Some(17.0));
+        assert_eq!(monthly.maximum_temperature, Some(27.0));
+        assert_eq!(monthly.precipitation, Some(4.0));
+        assert_eq!(monthly.sunshine_minutes, Some(420));
+        assert_eq!(monthly.wind_speed, None);
+        assert_eq!(monthly.pressure, None);
+    }
+
+    #[test]
+    fn test_monthly_from_daily_rejects_empty_and_mixed_months() {
+        assert!(Monthly::from_daily(&[]).is_none());
+
+        let days = [
+            test_day("2022-07-31", Some(20.0), None, None),
+            test_day("2022-08-01", Some(21.0), None, None),
+        ];
+        assert!(Monthly::from_daily(&days).is_none());
+        assert!(matches!(
+            Monthly::try_from(&days[..]),
+            Err(MeteostatError::InvalidDailyAggregation)
+        ));
+    }
+
+    #[test]
+    fn test_open_ended_ranges() -> Result<(), MeteostatError> {
+        // year,month,tavg,tmin,tmax,prcp,wspd,pres,tsun
+        let csv = "2019,12,1.0,,,,,,\n2020,1,2.0,,,,,,\n2020,11,3.0,,,,,,\n";
+        let frame = crate::parse_monthly_csv(csv.as_bytes())?;
+
+        let from = frame.get_range_from(Month(2020, 1))?.collect_monthly()?;
+        assert_eq!(
+            from.average_temperatures().collect::<Vec<_>>(),
+            vec![Some(2.0), Some(3.0)]
+        );
+        let until = frame.get_range_until(Month(2020, 1))?.collect_monthly()?;
+        assert_eq!(
+            until.average_temperatures().collect::<Vec<_>>(),
+            vec![Some(1.0), Some(2.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_since_and_last_n_months() -> Result<(), MeteostatError> {
+        // year,month,tavg,tmin,tmax,prcp,wspd,pres,tsun
+        let csv = "2022,11,1.0,,,,,,\n2022,12,2.0,,,,,,\n2023,1,3.0,,,,,,\n2023,2,4.0,,,,,,\n";
+        let frame = crate::parse_monthly_csv(csv.as_bytes())?;
+        let now = chrono::TimeZone::with_ymd_and_hms(&Utc, 2023, 1, 15, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            frame
+                .since_at(now, chrono::Duration::days(40))
+                .row_count()?,
+            2
+        );
+        assert_eq!(frame.last_n_months_at(now, 3).row_count()?, 3);
+        assert_eq!(frame.last_n_months_at(now, 1).row_count()?, 1);
+        assert_eq!(frame.last_n_months_at(now, 0).row_count()?, 0);
+        Ok(())
+    }
 }
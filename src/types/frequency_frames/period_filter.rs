@@ -0,0 +1,221 @@
+//! Defines the `PeriodFilter` trait for filtering any time-series frame wrapper by a period.
+
+use crate::types::frequency_frames::meteostat_frame::MeteostatFrame;
+use crate::{
+    AnyDate, AnyDateTime, AnyMonth, DailyLazyFrame, HourlyLazyFrame, MeteostatError,
+    MonthlyLazyFrame,
+};
+use polars::prelude::{col, lit, Expr};
+
+/// Period filtering shared by [`HourlyLazyFrame`], [`DailyLazyFrame`] and
+/// [`MonthlyLazyFrame`], parameterized by the period type `P`.
+///
+/// The filters are implemented once here, on top of [`PeriodFilter::period_bounds`], and the
+/// wrappers' inherent `get_for_period`, `get_range`, `get_range_from`, `get_range_until` and
+/// `get_for_periods` methods delegate to them. Use the trait to write generic code that
+/// filters any wrapper by a period type all of them understand, such as [`crate::Year`] or
+/// [`crate::Month`].
+///
+/// [`crate::ClimateLazyFrame`] does not implement this trait, since climate normals are not
+/// a time series.
+///
+/// # Example
+///
+/// ```no_run
+/// use meteostat::{Meteostat, MeteostatError, MeteostatFrame, PeriodFilter, Year};
+///
+/// fn rows_in_year<F: PeriodFilter<Year>>(data: &F, year: i32) -> Result<usize, MeteostatError> {
+///     data.get_for_period(Year(year))?.row_count()
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), MeteostatError> {
+/// let client = Meteostat::new().await?;
+/// let hourly = client.hourly().station("06240").call().await?;
+/// let daily = client.daily().station("06240").call().await?;
+/// println!("{} hours, {} days", rows_in_year(&hourly, 2023)?, rows_in_year(&daily, 2023)?);
+/// # Ok(())
+/// # }
+/// ```
+pub trait PeriodFilter<P>: MeteostatFrame + Sized {
+    /// The predicates keeping the rows from the start of `period` onward, and the rows up to
+    /// the end of `period` (inclusive), on the wrapper's time columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `period` cannot be resolved.
+    fn period_bounds(period: P) -> Result<(Expr, Expr), MeteostatError>;
+
+    /// Keeps only the rows matching `predicate`, like the wrappers' inherent `filter`.
+    #[must_use]
+    fn filter(&self, predicate: Expr) -> Self;
+
+    /// Keeps only the data within `period`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `period` cannot be resolved.
+    fn get_for_period(&self, period: P) -> Result<Self, MeteostatError> {
+        let (from, until) = Self::period_bounds(period)?;
+        Ok(self.filter(from.and(until)))
+    }
+
+    /// Keeps only the data from the start of `start` up to the end of `end` (inclusive).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `start` or `end` cannot be resolved.
+    fn get_range(&self, start: P, end: P) -> Result<Self, MeteostatError> {
+        let (from, _) = Self::period_bounds(start)?;
+        let (_, until) = Self::period_bounds(end)?;
+        Ok(self.filter(from.and(until)))
+    }
+
+    /// Keeps only the data from the start of `start` onward.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `start` cannot be resolved.
+    fn get_range_from(&self, start: P) -> Result<Self, MeteostatError> {
+        let (from, _) = Self::period_bounds(start)?;
+        Ok(self.filter(from))
+    }
+
+    /// Keeps only the data up to the end of `end` (inclusive).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if `end` cannot be resolved.
+    fn get_range_until(&self, end: P) -> Result<Self, MeteostatError> {
+        let (_, until) = Self::period_bounds(end)?;
+        Ok(self.filter(until))
+    }
+
+    /// Keeps only the data within any of `periods`, in a single predicate. Overlapping
+    /// periods don't duplicate rows, and no periods keep no rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::DateParsingError`] if any of the periods cannot be resolved.
+    fn get_for_periods(
+        &self,
+        periods: impl IntoIterator<Item = P>,
+    ) -> Result<Self, MeteostatError> {
+        let mut predicate = lit(false);
+        for period in periods {
+            let (from, until) = Self::period_bounds(period)?;
+            predicate = predicate.or(from.and(until));
+        }
+        Ok(self.filter(predicate))
+    }
+}
+
+/// Keeps only the data within each of `periods`, e.g. the periods resolved by a client's
+/// `.for_period()` and `.for_local_period()` builder methods, if they were set.
+///
+/// # Errors
+///
+/// Returns [`MeteostatError::DateParsingError`] if a period cannot be resolved.
+pub(crate) fn with_resolved_periods<P, F: PeriodFilter<P>>(
+    frame: F,
+    periods: impl IntoIterator<Item = P>,
+) -> Result<F, MeteostatError> {
+    periods
+        .into_iter()
+        .try_fold(frame, |frame, period| frame.get_for_period(period))
+}
+
+impl<P: AnyDateTime> PeriodFilter<P> for HourlyLazyFrame {
+    fn period_bounds(period: P) -> Result<(Expr, Expr), MeteostatError> {
+        let range = period
+            .get_datetime_range()
+            .ok_or(MeteostatError::DateParsingError)?;
+        Ok((
+            col("datetime").gt_eq(lit(range.start.naive_utc())),
+            col("datetime").lt_eq(lit(range.end.naive_utc())),
+        ))
+    }
+
+    fn filter(&self, predicate: Expr) -> Self {
+        Self::filter(self, predicate)
+    }
+}
+
+impl<P: AnyDate> PeriodFilter<P> for DailyLazyFrame {
+    fn period_bounds(period: P) -> Result<(Expr, Expr), MeteostatError> {
+        let range = period
+            .get_date_range()
+            .ok_or(MeteostatError::DateParsingError)?;
+        Ok((
+            col("date").gt_eq(lit(range.start)),
+            col("date").lt_eq(lit(range.end)),
+        ))
+    }
+
+    fn filter(&self, predicate: Expr) -> Self {
+        Self::filter(self, predicate)
+    }
+}
+
+impl<P: AnyMonth> PeriodFilter<P> for MonthlyLazyFrame {
+    fn period_bounds(period: P) -> Result<(Expr, Expr), MeteostatError> {
+        let range = period
+            .get_month_range()
+            .ok_or(MeteostatError::DateParsingError)?;
+        Ok((
+            Self::on_or_after(range.start),
+            Self::on_or_before(range.end),
+        ))
+    }
+
+    fn filter(&self, predicate: Expr) -> Self {
+        Self::filter(self, predicate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_daily_csv, parse_hourly_csv, parse_monthly_csv, Month, Year};
+
+    fn rows_in<P, F: PeriodFilter<P>>(data: &F, period: P) -> Result<usize, MeteostatError> {
+        data.get_for_period(period)?.row_count()
+    }
+
+    #[test]
+    fn test_generic_period_filter() -> Result<(), MeteostatError> {
+        let hourly = parse_hourly_csv(
+            b"2022-12-31,23,1.0,,,,,,,,,,\n2023-01-01,0,2.0,,,,,,,,,,\n2023-02-01,0,3.0,,,,,,,,,,\n",
+        )?;
+        let daily = parse_daily_csv(b"2022-12-31,1.0,,,,,,,,,\n2023-01-15,2.0,,,,,,,,,\n")?;
+        let monthly =
+            parse_monthly_csv(b"2022,12,1.0,,,,,,\n2023,1,2.0,,,,,,\n2023,2,3.0,,,,,,\n")?;
+
+        assert_eq!(rows_in(&hourly, Year(2023))?, 2);
+        assert_eq!(rows_in(&daily, Year(2023))?, 1);
+        assert_eq!(rows_in(&monthly, Year(2023))?, 2);
+        assert_eq!(rows_in(&hourly, Month(2023, 1))?, 1);
+        assert_eq!(rows_in(&monthly, Month(2023, 1))?, 1);
+
+        let range = PeriodFilter::get_range(&monthly, Month(2022, 12), Month(2023, 1))?;
+        assert_eq!(range.row_count()?, 2);
+        assert_eq!(
+            PeriodFilter::get_range_from(&daily, Year(2023))?.row_count()?,
+            1
+        );
+        assert_eq!(
+            PeriodFilter::get_range_until(&hourly, Year(2022))?.row_count()?,
+            1
+        );
+        let months = [Month(2022, 12), Month(2023, 2)];
+        assert_eq!(
+            PeriodFilter::get_for_periods(&monthly, months)?.row_count()?,
+            2
+        );
+        assert_eq!(
+            PeriodFilter::get_for_periods(&daily, Vec::<Year>::new())?.row_count()?,
+            0
+        );
+        Ok(())
+    }
+}
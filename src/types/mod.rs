@@ -1,6 +1,16 @@
+pub mod csv_options;
+pub mod data_format;
+pub mod distance_metric;
+#[cfg(feature = "export")]
+pub mod export_format;
 pub mod frequency;
 pub mod frequency_frames;
+pub mod precip_phase;
+pub mod records;
 pub mod rkyv_datetime;
 pub mod station;
 pub mod traits;
+pub mod unit_system;
 pub mod weather_condition;
+pub mod wind_rose;
+pub mod with_warnings;
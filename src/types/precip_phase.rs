@@ -0,0 +1,194 @@
+//! Defines the `PrecipPhase` classification of hourly precipitation into rain, snow or a mix,
+//! and the `PrecipPhaseRules` used to derive it.
+
+use crate::{MeteostatError, WeatherCondition};
+use polars::prelude::{col, lit, when, DataType, Expr, NULL};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Name of the column added by [`crate::HourlyLazyFrame::with_precip_phase`].
+pub const PRECIP_PHASE_COLUMN: &str = "precip_phase";
+
+/// Condition codes reporting liquid precipitation, including freezing rain and rain showers.
+const RAIN_CODES: [WeatherCondition; 7] = [
+    WeatherCondition::LightRain,
+    WeatherCondition::Rain,
+    WeatherCondition::HeavyRain,
+    WeatherCondition::FreezingRain,
+    WeatherCondition::HeavyFreezingRain,
+    WeatherCondition::RainShower,
+    WeatherCondition::HeavyRainShower,
+];
+/// Condition codes reporting sleet.
+const MIXED_CODES: [WeatherCondition; 4] = [
+    WeatherCondition::Sleet,
+    WeatherCondition::HeavySleet,
+    WeatherCondition::SleetShower,
+    WeatherCondition::HeavySleetShower,
+];
+/// Condition codes reporting snowfall or snow showers.
+const SNOW_CODES: [WeatherCondition; 5] = [
+    WeatherCondition::LightSnowfall,
+    WeatherCondition::Snowfall,
+    WeatherCondition::HeavySnowfall,
+    WeatherCondition::SnowShower,
+    WeatherCondition::HeavySnowShower,
+];
+
+/// The phase in which precipitation fell during an hour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum PrecipPhase {
+    /// Liquid precipitation, including freezing rain.
+    Rain,
+    /// Solid precipitation.
+    Snow,
+    /// A mix of rain and snow, such as sleet.
+    Mixed,
+}
+
+impl PrecipPhase {
+    /// All phases.
+    pub const ALL: [Self; 3] = [Self::Rain, Self::Snow, Self::Mixed];
+
+    /// The value stored for this phase in the [`PRECIP_PHASE_COLUMN`] column.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Rain => "rain",
+            Self::Snow => "snow",
+            Self::Mixed => "mixed",
+        }
+    }
+
+    /// Parses a value of the [`PRECIP_PHASE_COLUMN`] column, or returns `None` if it isn't
+    /// one of the phases.
+    #[must_use]
+    pub fn from_column_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|phase| phase.as_str() == value)
+    }
+
+    /// The phase reported by a weather condition, or `None` if the condition isn't a form
+    /// of rain, sleet or snow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meteostat::{PrecipPhase, WeatherCondition};
+    ///
+    /// assert_eq!(PrecipPhase::from_condition(WeatherCondition::SnowShower), Some(PrecipPhase::Snow));
+    /// assert_eq!(PrecipPhase::from_condition(WeatherCondition::Sleet), Some(PrecipPhase::Mixed));
+    /// assert_eq!(PrecipPhase::from_condition(WeatherCondition::Fog), None);
+    /// ```
+    #[must_use]
+    pub fn from_condition(condition: WeatherCondition) -> Option<Self> {
+        [
+            (Self::Rain, &RAIN_CODES[..]),
+            (Self::Mixed, &MIXED_CODES[..]),
+            (Self::Snow, &SNOW_CODES[..]),
+        ]
+        .into_iter()
+        .find(|(_, codes)| codes.contains(&condition))
+        .map(|(phase, _)| phase)
+    }
+}
+
+impl fmt::Display for PrecipPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The rules [`crate::HourlyLazyFrame::with_precip_phase_rules`] uses to classify an hour's
+/// precipitation as a [`PrecipPhase`].
+///
+/// An hour is classified in this order:
+///
+/// 1.  If precipitation (`prcp`) is 0 or missing, the phase is null.
+/// 2.  Otherwise, if `use_condition` is set and the condition code (`coco`) reports rain,
+///     sleet or snow, the phase follows from the code (see [`PrecipPhase::from_condition`]).
+///     Freezing rain counts as rain and sleet as mixed.
+/// 3.  Otherwise, the phase follows from the air temperature (`temp`): snow below
+///     `snow_below_c`, rain above `rain_above_c`, and mixed in between (inclusive). If the
+///     temperature is missing, the phase is null.
+///
+/// `snow_below_c` must not be above `rain_above_c`.
+///
+/// The default thresholds of 0 °C and 2 °C bracket the near-surface temperatures at which
+/// the snow fraction of precipitation drops from nearly all to nearly none. Raise them for
+/// dry climates or high elevations, where snow falls at higher air temperatures.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct PrecipPhaseRules {
+    /// Precipitation below this air temperature in °C is snow. Defaults to 0 °C.
+    pub snow_below_c: f64,
+    /// Precipitation above this air temperature in °C is rain. Defaults to 2 °C.
+    pub rain_above_c: f64,
+    /// Whether a precipitation condition code takes precedence over the temperature.
+    /// Defaults to `true`.
+    pub use_condition: bool,
+}
+
+impl Default for PrecipPhaseRules {
+    fn default() -> Self {
+        Self {
+            snow_below_c: 0.0,
+            rain_above_c: 2.0,
+            use_condition: true,
+        }
+    }
+}
+
+impl PrecipPhaseRules {
+    /// Checks that the thresholds are ordered, i.e. `snow_below_c` is not above `rain_above_c`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::InvalidPrecipPhaseThresholds`] if they aren't, or if either
+    /// is NaN.
+    pub fn validate(&self) -> Result<(), MeteostatError> {
+        if self.snow_below_c <= self.rain_above_c {
+            Ok(())
+        } else {
+            Err(MeteostatError::InvalidPrecipPhaseThresholds {
+                snow_below_c: self.snow_below_c,
+                rain_above_c: self.rain_above_c,
+            })
+        }
+    }
+
+    /// Builds the expression computing the phase as a string column named
+    /// [`PRECIP_PHASE_COLUMN`]. Expects validated rules.
+    pub(crate) fn phase_expr(&self) -> Expr {
+        let by_temperature = when(col("temp").lt(lit(self.snow_below_c)))
+            .then(lit(PrecipPhase::Snow.as_str()))
+            .when(col("temp").gt(lit(self.rain_above_c)))
+            .then(lit(PrecipPhase::Rain.as_str()))
+            .when(col("temp").is_not_null())
+            .then(lit(PrecipPhase::Mixed.as_str()))
+            .otherwise(lit(NULL).cast(DataType::String));
+
+        let by_phase = if self.use_condition {
+            when(any_code(&SNOW_CODES))
+                .then(lit(PrecipPhase::Snow.as_str()))
+                .when(any_code(&MIXED_CODES))
+                .then(lit(PrecipPhase::Mixed.as_str()))
+                .when(any_code(&RAIN_CODES))
+                .then(lit(PrecipPhase::Rain.as_str()))
+                .otherwise(by_temperature)
+        } else {
+            by_temperature
+        };
+        when(col("prcp").gt(lit(0.0)))
+            .then(by_phase)
+            .otherwise(lit(NULL).cast(DataType::String))
+            .alias(PRECIP_PHASE_COLUMN)
+    }
+}
+
+/// Whether the condition code is one of `conditions`.
+fn any_code(conditions: &[WeatherCondition]) -> Expr {
+    conditions
+        .iter()
+        .map(|condition| col("coco").eq(lit(condition.code())))
+        .reduce(Expr::or)
+        .unwrap_or_else(|| lit(false))
+}
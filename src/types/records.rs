@@ -0,0 +1,216 @@
+use crate::types::frequency_frames::climate_frame::Climate;
+use crate::types::frequency_frames::daily_frame::Daily;
+use crate::types::frequency_frames::hourly_frame::Hourly;
+use crate::types::frequency_frames::monthly_frame::Monthly;
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, Index};
+use std::slice::SliceIndex;
+
+/// Records collected from a frame, e.g. by [`crate::HourlyLazyFrame::collect_hourly`].
+///
+/// Dereferences to a slice, so it can be used like a `Vec` for reading (`len`, `first`,
+/// `iter`, indexing), and can be iterated directly with `for`. The aliases
+/// [`HourlyRecords`], [`DailyRecords`], [`MonthlyRecords`] and [`ClimateRecords`] add helpers
+/// to extract a single field, e.g. [`HourlyRecords::temperatures`].
+///
+/// # Example
+///
+/// ```
+/// # use meteostat::{parse_hourly_csv, MeteostatError};
+/// # fn main() -> Result<(), MeteostatError> {
+/// let records = parse_hourly_csv(b"2023-01-01,0,5.1,,,,,,,,,,\n2023-01-01,1,6.3,,,,,,,,,,\n")?
+///     .collect_hourly()?;
+///
+/// let warmest = records.temperatures().flatten().fold(f64::NEG_INFINITY, f64::max);
+/// assert_eq!(warmest, 6.3);
+///
+/// for hour in records {
+///     println!("{}: {:?}", hour.datetime, hour.temperature);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Records<T>(Vec<T>);
+
+/// Hourly records, returned by [`crate::HourlyLazyFrame::collect_hourly`].
+pub type HourlyRecords = Records<Hourly>;
+/// Daily records, returned by [`crate::DailyLazyFrame::collect_daily`].
+pub type DailyRecords = Records<Daily>;
+/// Monthly records, returned by [`crate::MonthlyLazyFrame::collect_monthly`].
+pub type MonthlyRecords = Records<Monthly>;
+/// Climate normals, returned by [`crate::ClimateLazyFrame::collect_climate`].
+pub type ClimateRecords = Records<Climate>;
+
+impl<T> Records<T> {
+    /// Returns the underlying `Vec` of records.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<Vec<T>> for Records<T> {
+    fn from(records: Vec<T>) -> Self {
+        Self(records)
+    }
+}
+
+impl<T> From<Records<T>> for Vec<T> {
+    fn from(records: Records<T>) -> Self {
+        records.0
+    }
+}
+
+impl<T> FromIterator<T> for Records<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T> Deref for Records<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, I: SliceIndex<[T]>> Index<I> for Records<T> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T> IntoIterator for Records<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Records<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl Records<Hourly> {
+    /// The temperature (°C) of each hour, in order.
+    pub fn temperatures(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|hour| hour.temperature)
+    }
+
+    /// The precipitation (mm) of each hour, in order.
+    pub fn precipitation(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|hour| hour.precipitation)
+    }
+
+    /// The average wind speed (km/h) of each hour, in order.
+    pub fn wind_speeds(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|hour| hour.wind_speed)
+    }
+}
+
+impl Records<Daily> {
+    /// The average temperature (°C) of each day, in order.
+    pub fn average_temperatures(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|day| day.average_temperature)
+    }
+
+    /// The minimum temperature (°C) of each day, in order.
+    pub fn minimum_temperatures(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|day| day.minimum_temperature)
+    }
+
+    /// The maximum temperature (°C) of each day, in order.
+    pub fn maximum_temperatures(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|day| day.maximum_temperature)
+    }
+
+    /// The total precipitation (mm) of each day, in order.
+    pub fn precipitation(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|day| day.precipitation)
+    }
+}
+
+impl Records<Monthly> {
+    /// The average temperature (°C) of each month, in order.
+    pub fn average_temperatures(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|month| month.average_temperature)
+    }
+
+    /// The average minimum temperature (°C) of each month, in order.
+    pub fn minimum_temperatures(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|month| month.minimum_temperature)
+    }
+
+    /// The average maximum temperature (°C) of each month, in order.
+    pub fn maximum_temperatures(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|month| month.maximum_temperature)
+    }
+
+    /// The total precipitation (mm) of each month, in order.
+    pub fn precipitation(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|month| month.precipitation)
+    }
+}
+
+impl Records<Climate> {
+    /// The average minimum temperature (°C) of each month, in order.
+    pub fn minimum_temperatures(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|month| month.minimum_temperature)
+    }
+
+    /// The average maximum temperature (°C) of each month, in order.
+    pub fn maximum_temperatures(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|month| month.maximum_temperature)
+    }
+
+    /// The average precipitation (mm) of each month, in order.
+    pub fn precipitation(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.iter().map(|month| month.precipitation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_daily_csv;
+
+    #[test]
+    fn test_daily_records() {
+        let records = parse_daily_csv(
+            b"2023-01-01,5.1,2.0,8.3,1.2,,,,,,\n\
+              2023-01-02,,1.1,6.9,0.0,,,,,,\n",
+        )
+        .unwrap()
+        .collect_daily()
+        .unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].minimum_temperature, Some(1.1));
+        assert_eq!(
+            records.average_temperatures().collect::<Vec<_>>(),
+            vec![Some(5.1), None]
+        );
+        let wettest = records
+            .iter()
+            .max_by(|a, b| a.precipitation.partial_cmp(&b.precipitation).unwrap())
+            .unwrap();
+        assert_eq!(wettest.date.to_string(), "2023-01-01");
+
+        let dates: Vec<_> = (&records).into_iter().map(|day| day.date).collect();
+        assert_eq!(dates.len(), 2);
+        let owned: Vec<Daily> = records.clone().into_iter().collect();
+        assert_eq!(owned, records.into_vec());
+    }
+}
@@ -3,12 +3,13 @@
 //! necessary for spatial indexing using the `rstar` crate.
 
 use crate::types::rkyv_datetime::ChronoDateOption;
-use crate::LatLon;
-use chrono::NaiveDate;
+use crate::{Frequency, LatLon, LocateStationError, MeteostatError, RequiredData};
+use chrono::{Datelike, NaiveDate};
 use rkyv::{Archive, Deserialize as ArchiveDeserialize, Serialize as ArchiveSerialize};
 use rstar::{PointDistance, RTreeObject, AABB};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 // --- Data Structures ---
 
@@ -44,7 +45,7 @@ pub struct Station {
     /// Geographical location details (latitude, longitude, elevation).
     pub location: StationLocation,
     /// Information about the availability periods for different data frequencies.
-    pub inventory: Inventory,
+    pub inventory: StationInventory,
 }
 
 /// Stores the data availability ranges for different [`crate::Frequency`] types for a station.
@@ -63,7 +64,7 @@ pub struct Station {
     ArchiveSerialize,
     ArchiveDeserialize,
 )]
-pub struct Inventory {
+pub struct StationInventory {
     /// The reported start and end dates for daily data.
     pub daily: DateRange,
     /// The reported start and end dates for hourly data.
@@ -76,9 +77,96 @@ pub struct Inventory {
     pub normals: YearRange,
 }
 
+impl StationInventory {
+    /// The reported coverage of `frequency` as a `(first year, last year)` pair, or `None`
+    /// if the station reports no range for it.
+    ///
+    /// Gives the same shape for every frequency, e.g. to show a coverage overview like
+    /// "hourly 1951-2023, daily 1940-2023, normals 1991-2020".
+    #[must_use]
+    pub fn years(&self, frequency: Frequency) -> Option<(i32, i32)> {
+        match frequency {
+            Frequency::Hourly => self.hourly.years(),
+            Frequency::Daily => self.daily.years(),
+            Frequency::Monthly => self.monthly.years(),
+            Frequency::Climate => self.normals.years(),
+        }
+    }
+
+    /// Whether the station reports a data range for `frequency`.
+    #[must_use]
+    pub fn has(&self, frequency: Frequency) -> bool {
+        self.years(frequency).is_some()
+    }
+
+    /// How much of the period in `required` the reported range for `frequency` covers.
+    ///
+    /// Monthly and climate normals ranges are reported in whole years, and are compared as if
+    /// they span January 1st of the first year to December 31st of the last.
+    /// [`RequiredData::Any`] is fully covered by any reported range. An invalid period, such
+    /// as a month outside 1-12, or a start after the end, is not covered.
+    #[must_use]
+    pub fn coverage(&self, frequency: Frequency, required: &RequiredData) -> Coverage {
+        let Some((reported_start, reported_end)) = self.reported_dates(frequency) else {
+            return Coverage::None;
+        };
+        if *required == RequiredData::Any {
+            return Coverage::Full;
+        }
+        let Some((start, end)) = required.date_bounds().filter(|(start, end)| start <= end) else {
+            return Coverage::None;
+        };
+        if reported_start <= start && end <= reported_end {
+            Coverage::Full
+        } else if reported_start <= end && start <= reported_end {
+            Coverage::Partial
+        } else {
+            Coverage::None
+        }
+    }
+
+    /// The reported range for `frequency` as dates, with year ranges spanning whole years.
+    fn reported_dates(&self, frequency: Frequency) -> Option<(NaiveDate, NaiveDate)> {
+        match frequency {
+            Frequency::Hourly => Some((self.hourly.start?, self.hourly.end?)),
+            Frequency::Daily => Some((self.daily.start?, self.daily.end?)),
+            Frequency::Monthly | Frequency::Climate => {
+                let (start, end) = self.years(frequency)?;
+                Some((
+                    NaiveDate::from_ymd_opt(start, 1, 1)?,
+                    NaiveDate::from_ymd_opt(end, 12, 31)?,
+                ))
+            }
+        }
+    }
+}
+
+/// How much of a requested period a station's reported inventory covers, see
+/// [`StationInventory::coverage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Coverage {
+    /// The reported range spans the whole period.
+    Full,
+    /// The reported range overlaps part of the period.
+    Partial,
+    /// The reported range doesn't overlap the period, or no range is reported.
+    None,
+}
+
+/// Formats the coverage for messages, e.g. "reports partial coverage".
+impl fmt::Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full => write!(f, "full"),
+            Self::Partial => write!(f, "partial"),
+            Self::None => write!(f, "no"),
+        }
+    }
+}
+
 /// Represents a date range with optional start and end dates.
 ///
-/// Used within [`Inventory`] for frequencies where day-level precision is relevant (daily, hourly).
+/// Used within [`StationInventory`] for frequencies where day-level precision is relevant (daily, hourly).
 #[derive(
     Debug,
     Serialize,
@@ -97,9 +185,17 @@ pub struct DateRange {
     pub end: Option<NaiveDate>,
 }
 
+impl DateRange {
+    /// The years of the start and end date, or `None` if either is unknown.
+    #[must_use]
+    pub fn years(&self) -> Option<(i32, i32)> {
+        Some((self.start?.year(), self.end?.year()))
+    }
+}
+
 /// Represents a year range with optional start and end years.
 ///
-/// Used within [`Inventory`] for frequencies where year-level precision is sufficient (monthly, climate normals).
+/// Used within [`StationInventory`] for frequencies where year-level precision is sufficient (monthly, climate normals).
 #[derive(
     Debug,
     Serialize,
@@ -118,6 +214,17 @@ pub struct YearRange {
     pub end: Option<i32>,
 }
 
+impl YearRange {
+    /// The start and end year, or `None` if either is unknown.
+    #[must_use]
+    pub const fn years(&self) -> Option<(i32, i32)> {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => Some((start, end)),
+            _ => None,
+        }
+    }
+}
+
 /// Holds various alternative identifiers that might be associated with a weather station.
 #[derive(
     Debug,
@@ -152,6 +259,119 @@ pub struct StationLocation {
     pub elevation: Option<i32>,
 }
 
+impl Station {
+    /// Parses a station list in the JSON format Meteostat publishes (the decompressed
+    /// `stations/lite.json.gz`): an array of station objects.
+    ///
+    /// Together with [`crate::Meteostat::with_stations`], this allows using a pinned or
+    /// custom station list. Stations serialized with `serde_json` can be read back as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MeteostatError::LocateStation`] with a JSON parse error if `json` isn't a
+    /// valid station list.
+    pub fn from_json(json: &str) -> Result<Vec<Self>, MeteostatError> {
+        serde_json::from_str(json).map_err(|e| LocateStationError::from(e).into())
+    }
+
+    /// The reported data availability of the station for every frequency.
+    #[must_use]
+    pub const fn inventory(&self) -> &StationInventory {
+        &self.inventory
+    }
+
+    /// The frequencies the station reports a data range for in its inventory, from finest
+    /// to coarsest, e.g. to only offer those in a UI instead of fetching frequencies the
+    /// station lacks (many stations have daily data but no climate normals).
+    ///
+    /// This only reads the loaded station metadata. Newly added stations may not have an
+    /// inventory yet, and then report no frequencies.
+    #[must_use]
+    pub fn available_frequencies(&self) -> Vec<Frequency> {
+        Frequency::ALL
+            .into_iter()
+            .filter(|frequency| self.inventory.has(*frequency))
+            .collect()
+    }
+
+    /// Returns the ICAO airport code of the station (e.g. `"EGLL"`), if it has one.
+    #[must_use]
+    pub fn icao(&self) -> Option<&str> {
+        self.identifiers.icao.as_deref()
+    }
+
+    /// Returns the WMO identifier of the station (e.g. `"03772"`), if it has one.
+    #[must_use]
+    pub fn wmo(&self) -> Option<&str> {
+        self.identifiers.wmo.as_deref()
+    }
+
+    /// Builds a human-readable label for the station, e.g. `"London Heathrow, ENG, GB"`.
+    ///
+    /// Combines the station name (English if available), the region code and the country
+    /// code. Missing or empty parts are left out; if nothing is available the station ID
+    /// is returned instead.
+    #[must_use]
+    pub fn region_label(&self) -> String {
+        // Fall back to the alphabetically first language so the label is deterministic
+        let name = self.name.get("en").or_else(|| {
+            self.name
+                .iter()
+                .min_by(|a, b| a.0.cmp(b.0))
+                .map(|(_, name)| name)
+        });
+
+        let label = [name, self.region.as_ref(), Some(&self.country)]
+            .into_iter()
+            .flatten()
+            .map(|part| part.trim())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        if label.is_empty() {
+            self.id.clone()
+        } else {
+            label
+        }
+    }
+
+    /// Converts the station into a GeoJSON `Feature` with a `Point` geometry.
+    ///
+    /// The coordinates are `[longitude, latitude]`, plus the elevation in meters if known.
+    /// The properties hold the station's ID, names, country, region, timezone, identifiers,
+    /// elevation and inventory, using the same field names as the serialized [`Station`].
+    #[must_use]
+    pub fn to_geojson_feature(&self) -> serde_json::Value {
+        let mut coordinates = vec![
+            serde_json::Value::from(self.location.longitude),
+            serde_json::Value::from(self.location.latitude),
+        ];
+        if let Some(elevation) = self.location.elevation {
+            coordinates.push(elevation.into());
+        }
+
+        serde_json::json!({
+            "type": "Feature",
+            "id": self.id,
+            "geometry": {
+                "type": "Point",
+                "coordinates": coordinates,
+            },
+            "properties": {
+                "id": self.id,
+                "name": self.name,
+                "country": self.country,
+                "region": self.region,
+                "timezone": self.timezone,
+                "identifiers": self.identifiers,
+                "elevation": self.location.elevation,
+                "inventory": self.inventory,
+            },
+        })
+    }
+}
+
 // --- R-Tree Implementations ---
 
 /// Implementation required by `rstar` to treat a `Station` as an object within an R-Tree.
@@ -197,3 +417,219 @@ impl PointDistance for Station {
         dx.mul_add(dx, dy * dy)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_station() -> Station {
+        Station {
+            id: "03772".to_string(),
+            country: "GB".to_string(),
+            region: Some("ENG".to_string()),
+            timezone: Some("Europe/London".to_string()),
+            name: HashMap::from([
+                ("en".to_string(), "London Heathrow".to_string()),
+                ("de".to_string(), "London-Heathrow".to_string()),
+            ]),
+            identifiers: Identifiers {
+                national: None,
+                wmo: Some("03772".to_string()),
+                icao: Some("EGLL".to_string()),
+            },
+            location: StationLocation {
+                latitude: 51.4833,
+                longitude: -0.45,
+                elevation: Some(25),
+            },
+            inventory: StationInventory {
+                daily: DateRange {
+                    start: None,
+                    end: None,
+                },
+                hourly: DateRange {
+                    start: None,
+                    end: None,
+                },
+                model: DateRange {
+                    start: None,
+                    end: None,
+                },
+                monthly: YearRange {
+                    start: None,
+                    end: None,
+                },
+                normals: YearRange {
+                    start: None,
+                    end: None,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn test_identifier_accessors() {
+        let mut station = test_station();
+        assert_eq!(station.icao(), Some("EGLL"));
+        assert_eq!(station.wmo(), Some("03772"));
+
+        station.identifiers.icao = None;
+        assert_eq!(station.icao(), None);
+    }
+
+    #[test]
+    fn test_inventory_years_per_frequency() {
+        let mut station = test_station();
+        station.inventory.hourly = DateRange {
+            start: NaiveDate::from_ymd_opt(1951, 3, 1),
+            end: NaiveDate::from_ymd_opt(2023, 12, 31),
+        };
+        station.inventory.daily.start = NaiveDate::from_ymd_opt(1940, 1, 1);
+        station.inventory.normals = YearRange {
+            start: Some(1991),
+            end: Some(2020),
+        };
+
+        let inventory = station.inventory();
+        assert_eq!(inventory.years(Frequency::Hourly), Some((1951, 2023)));
+        assert_eq!(inventory.years(Frequency::Climate), Some((1991, 2020)));
+        // A range without an end is not reported as coverage
+        assert_eq!(inventory.years(Frequency::Daily), None);
+        assert!(inventory.has(Frequency::Hourly));
+        assert!(!inventory.has(Frequency::Monthly));
+    }
+
+    #[test]
+    fn test_available_frequencies() {
+        let mut station = test_station();
+        assert!(station.available_frequencies().is_empty());
+
+        station.inventory.daily = DateRange {
+            start: NaiveDate::from_ymd_opt(1940, 1, 1),
+            end: NaiveDate::from_ymd_opt(2023, 12, 31),
+        };
+        station.inventory.normals = YearRange {
+            start: Some(1991),
+            end: Some(2020),
+        };
+        assert_eq!(
+            station.available_frequencies(),
+            vec![Frequency::Daily, Frequency::Climate]
+        );
+    }
+
+    #[test]
+    fn test_inventory_coverage() {
+        let mut inventory = test_station().inventory;
+        inventory.daily = DateRange {
+            start: NaiveDate::from_ymd_opt(2012, 6, 1),
+            end: NaiveDate::from_ymd_opt(2023, 12, 31),
+        };
+        inventory.monthly = YearRange {
+            start: Some(2012),
+            end: Some(2023),
+        };
+        let range = |start: i32, end: i32| RequiredData::DateRange {
+            start: NaiveDate::from_ymd_opt(start, 1, 1).unwrap_or_default(),
+            end: NaiveDate::from_ymd_opt(end, 12, 31).unwrap_or_default(),
+        };
+
+        assert_eq!(
+            inventory.coverage(Frequency::Daily, &range(2013, 2020)),
+            Coverage::Full
+        );
+        assert_eq!(
+            inventory.coverage(Frequency::Daily, &range(2010, 2020)),
+            Coverage::Partial
+        );
+        assert_eq!(
+            inventory.coverage(Frequency::Monthly, &range(2012, 2020)),
+            Coverage::Full
+        );
+        assert_eq!(
+            inventory.coverage(Frequency::Daily, &range(2000, 2005)),
+            Coverage::None
+        );
+        assert_eq!(
+            inventory.coverage(Frequency::Daily, &RequiredData::Any),
+            Coverage::Full
+        );
+        assert_eq!(
+            inventory.coverage(Frequency::Hourly, &RequiredData::Any),
+            Coverage::None
+        );
+        assert_eq!(
+            inventory.coverage(Frequency::Daily, &range(2020, 2013)),
+            Coverage::None
+        );
+    }
+
+    #[test]
+    fn test_region_label() {
+        let mut station = test_station();
+        assert_eq!(station.region_label(), "London Heathrow, ENG, GB");
+
+        station.name.remove("en");
+        station.region = None;
+        assert_eq!(station.region_label(), "London-Heathrow, GB");
+
+        station.name.clear();
+        station.country = String::new();
+        assert_eq!(station.region_label(), "03772");
+    }
+
+    #[test]
+    fn test_from_json_reads_station_list() {
+        let json = r#"[{
+            "id": "10637",
+            "country": "DE",
+            "region": "HE",
+            "timezone": "Europe/Berlin",
+            "name": {"en": "Frankfurt Airport"},
+            "identifiers": {"national": "1420", "wmo": "10637", "icao": "EDDF"},
+            "location": {"latitude": 50.05, "longitude": 8.6, "elevation": 111},
+            "inventory": {
+                "daily": {"start": "1949-01-01", "end": "2024-05-01"},
+                "hourly": {"start": "1949-01-01", "end": "2024-05-01"},
+                "model": {"start": null, "end": null},
+                "monthly": {"start": 1949, "end": 2023},
+                "normals": {"start": 1961, "end": 2020}
+            }
+        }]"#;
+
+        let stations = Station::from_json(json).unwrap();
+        assert_eq!(stations.len(), 1);
+        assert_eq!(stations[0].icao(), Some("EDDF"));
+        assert_eq!(
+            stations[0].inventory().years(Frequency::Daily),
+            Some((1949, 2024))
+        );
+
+        let round_trip = Station::from_json(&serde_json::to_string(&stations).unwrap()).unwrap();
+        assert_eq!(round_trip, stations);
+        assert!(Station::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_to_geojson_feature() {
+        let mut station = test_station();
+        let feature = station.to_geojson_feature();
+
+        assert_eq!(feature["type"], "Feature");
+        assert_eq!(feature["geometry"]["type"], "Point");
+        assert_eq!(
+            feature["geometry"]["coordinates"],
+            serde_json::json!([-0.45, 51.4833, 25])
+        );
+        assert_eq!(feature["properties"]["name"]["en"], "London Heathrow");
+        assert_eq!(feature["properties"]["identifiers"]["icao"], "EGLL");
+
+        station.location.elevation = None;
+        let feature = station.to_geojson_feature();
+        assert_eq!(
+            feature["geometry"]["coordinates"],
+            serde_json::json!([-0.45, 51.4833])
+        );
+        assert!(feature["properties"]["elevation"].is_null());
+    }
+}
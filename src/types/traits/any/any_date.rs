@@ -50,3 +50,9 @@ impl AnyDate for Month {
         })
     }
 }
+
+impl AnyDate for StartEndDate {
+    fn get_date_range(self) -> Option<StartEndDate> {
+        Some(self)
+    }
+}
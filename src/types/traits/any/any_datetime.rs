@@ -102,3 +102,9 @@ impl AnyDateTime for Month {
         Some(StartEndDateTime { start, end })
     }
 }
+
+impl AnyDateTime for StartEndDateTime {
+    fn get_datetime_range(self) -> Option<StartEndDateTime> {
+        Some(self)
+    }
+}
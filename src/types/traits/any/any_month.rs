@@ -21,3 +21,9 @@ impl AnyMonth for Year {
         })
     }
 }
+
+impl AnyMonth for StartEndMonth {
+    fn get_month_range(self) -> Option<StartEndMonth> {
+        Some(self)
+    }
+}
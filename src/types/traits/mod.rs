@@ -2,3 +2,4 @@ pub mod any;
 pub mod period;
 pub mod types;
 mod utils;
+pub mod weather_comparison;
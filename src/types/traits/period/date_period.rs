@@ -25,3 +25,12 @@ impl DatePeriod for Month {
         })
     }
 }
+
+impl DatePeriod for NaiveDate {
+    fn get_date_period(self) -> Option<StartEndDate> {
+        Some(StartEndDate {
+            start: self,
+            end: self,
+        })
+    }
+}
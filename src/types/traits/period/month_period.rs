@@ -12,3 +12,12 @@ impl MonthPeriod for Year {
         })
     }
 }
+
+impl MonthPeriod for Month {
+    fn get_month_period(self) -> Option<StartEndMonth> {
+        Some(StartEndMonth {
+            start: self,
+            end: self,
+        })
+    }
+}
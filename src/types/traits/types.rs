@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
@@ -46,16 +46,53 @@ impl Display for Month {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct StartEndDateTime {
     pub start: DateTime<Utc>,
     pub end: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct StartEndDate {
     pub start: NaiveDate,
     pub end: NaiveDate,
 }
 
+impl StartEndDate {
+    /// Resolves the calendar days of this period in `tz` to the UTC instants they span,
+    /// from local midnight of `start` up to (but excluding) local midnight after `end`.
+    pub(crate) fn in_timezone<Tz: TimeZone>(self, tz: &Tz) -> Option<StartEndDateTime> {
+        let start = local_day_start(self.start, tz)?;
+        let next_day_start = local_day_start(self.end.succ_opt()?, tz)?;
+        Some(StartEndDateTime {
+            start,
+            end: next_day_start - Duration::microseconds(1),
+        })
+    }
+
+    /// Resolves the calendar days of this period in `tz`, see [`Self::in_timezone`], and
+    /// returns the UTC calendar days those instants fall on.
+    pub(crate) fn utc_days_in_timezone<Tz: TimeZone>(self, tz: &Tz) -> Option<Self> {
+        let instants = self.in_timezone(tz)?;
+        Some(Self {
+            start: instants.start.date_naive(),
+            end: instants.end.date_naive(),
+        })
+    }
+}
+
+/// The first instant of a local calendar day. If a DST change skips midnight, the day
+/// starts at the first hour that exists.
+fn local_day_start<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> Option<DateTime<Utc>> {
+    (0..24).find_map(|hour| {
+        let local = date.and_hms_opt(hour, 0, 0)?;
+        tz.from_local_datetime(&local)
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc))
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
 pub struct StartEndMonth {
     pub start: Month,
     pub end: Month,
@@ -0,0 +1,69 @@
+use crate::types::frequency_frames::daily_frame::Daily;
+use crate::types::frequency_frames::hourly_frame::Hourly;
+use crate::types::frequency_frames::monthly_frame::Monthly;
+
+/// Compares the temperature and precipitation of two weather records of the same frequency.
+///
+/// Implementors only pick the fields to compare; `Hourly` uses its temperature, `Daily` and
+/// `Monthly` their average temperature.
+pub trait WeatherComparison {
+    /// The temperature in °C that records are compared by.
+    fn comparison_temperature(&self) -> Option<f64>;
+
+    /// The precipitation amount in mm that records are compared by.
+    fn comparison_precipitation(&self) -> Option<f64>;
+
+    /// How much warmer this record was than `other`, in °C (negative if colder).
+    /// `None` if either temperature is missing.
+    fn temperature_difference(&self, other: &Self) -> Option<f64> {
+        Some(self.comparison_temperature()? - other.comparison_temperature()?)
+    }
+
+    /// Whether this record was warmer than `other`, or `None` if either temperature is missing.
+    fn is_warmer_than(&self, other: &Self) -> Option<bool> {
+        self.temperature_difference(other)
+            .map(|difference| difference > 0.0)
+    }
+
+    /// Whether this record was colder than `other`, or `None` if either temperature is missing.
+    fn is_colder_than(&self, other: &Self) -> Option<bool> {
+        self.temperature_difference(other)
+            .map(|difference| difference < 0.0)
+    }
+
+    /// Whether this record had more precipitation than `other`, or `None` if either
+    /// value is missing.
+    fn is_wetter_than(&self, other: &Self) -> Option<bool> {
+        Some(self.comparison_precipitation()? > other.comparison_precipitation()?)
+    }
+}
+
+impl WeatherComparison for Hourly {
+    fn comparison_temperature(&self) -> Option<f64> {
+        self.temperature
+    }
+
+    fn comparison_precipitation(&self) -> Option<f64> {
+        self.precipitation
+    }
+}
+
+impl WeatherComparison for Daily {
+    fn comparison_temperature(&self) -> Option<f64> {
+        self.average_temperature
+    }
+
+    fn comparison_precipitation(&self) -> Option<f64> {
+        self.precipitation
+    }
+}
+
+impl WeatherComparison for Monthly {
+    fn comparison_temperature(&self) -> Option<f64> {
+        self.average_temperature
+    }
+
+    fn comparison_precipitation(&self) -> Option<f64> {
+        self.precipitation
+    }
+}
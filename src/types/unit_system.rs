@@ -0,0 +1,147 @@
+//! Defines the `UnitSystem` used to convert Meteostat's measurements into other units.
+
+use polars::prelude::{col, lit, Expr};
+use serde::{Deserialize, Serialize};
+
+/// Offset between degrees Celsius and Kelvin.
+const ZERO_CELSIUS_IN_KELVIN: f64 = 273.15;
+/// Kilometres per hour in one metre per second.
+const KMH_PER_MS: f64 = 3.6;
+/// Pascal in one hectopascal.
+const PA_PER_HPA: f64 = 100.0;
+
+/// The units that measurements are expressed in.
+///
+/// Meteostat reports temperatures in °C, wind speeds in km/h, pressure in hPa and
+/// precipitation in mm, which is [`UnitSystem::Metric`]. Converting applies to the
+/// struct-level records (e.g. [`crate::Daily::with_units`]) as well as to the lazy frames
+/// (e.g. [`crate::DailyLazyFrame::with_units`]).
+///
+/// Other quantities are unaffected: relative humidity stays in percent, wind direction in
+/// degrees, snow depth in mm and sunshine duration in minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+pub enum UnitSystem {
+    /// Meteostat's own units: °C, km/h, hPa and mm. Converting to it changes nothing.
+    #[default]
+    Metric,
+    /// SI units for physical models: Kelvin, m/s, Pa and kg/m².
+    ///
+    /// One mm of precipitation is 1 kg/m² of water, so precipitation values keep their
+    /// numbers and only change unit.
+    Scientific,
+}
+
+impl UnitSystem {
+    /// Converts a temperature from °C into this unit system.
+    #[must_use]
+    pub fn temperature(self, celsius: f64) -> f64 {
+        match self {
+            Self::Metric => celsius,
+            Self::Scientific => celsius + ZERO_CELSIUS_IN_KELVIN,
+        }
+    }
+
+    /// Converts a wind speed from km/h into this unit system.
+    #[must_use]
+    pub fn wind_speed(self, kmh: f64) -> f64 {
+        match self {
+            Self::Metric => kmh,
+            Self::Scientific => kmh / KMH_PER_MS,
+        }
+    }
+
+    /// Converts a pressure from hPa into this unit system.
+    #[must_use]
+    pub fn pressure(self, hpa: f64) -> f64 {
+        match self {
+            Self::Metric => hpa,
+            Self::Scientific => hpa * PA_PER_HPA,
+        }
+    }
+
+    /// Converts a precipitation amount from mm into this unit system. The value is the same
+    /// in both systems.
+    #[must_use]
+    pub const fn precipitation(self, mm: f64) -> f64 {
+        mm
+    }
+
+    /// The unit of temperatures, `"°C"` or `"K"`.
+    #[must_use]
+    pub const fn temperature_unit(self) -> &'static str {
+        match self {
+            Self::Metric => "°C",
+            Self::Scientific => "K",
+        }
+    }
+
+    /// The unit of wind speeds, `"km/h"` or `"m/s"`.
+    #[must_use]
+    pub const fn wind_speed_unit(self) -> &'static str {
+        match self {
+            Self::Metric => "km/h",
+            Self::Scientific => "m/s",
+        }
+    }
+
+    /// The unit of pressure, `"hPa"` or `"Pa"`.
+    #[must_use]
+    pub const fn pressure_unit(self) -> &'static str {
+        match self {
+            Self::Metric => "hPa",
+            Self::Scientific => "Pa",
+        }
+    }
+
+    /// The unit of precipitation, `"mm"` or `"kg/m²"`.
+    #[must_use]
+    pub const fn precipitation_unit(self) -> &'static str {
+        match self {
+            Self::Metric => "mm",
+            Self::Scientific => "kg/m²",
+        }
+    }
+
+    /// Builds the expressions replacing the given temperature, wind speed and pressure
+    /// columns with their values in this unit system. Empty for [`UnitSystem::Metric`].
+    pub(crate) fn column_exprs(
+        self,
+        temperatures: &[&str],
+        wind_speeds: &[&str],
+        pressures: &[&str],
+    ) -> Vec<Expr> {
+        match self {
+            Self::Metric => Vec::new(),
+            Self::Scientific => {
+                let convert = |names: &[&str], f: fn(Expr) -> Expr| {
+                    names
+                        .iter()
+                        .map(|name| f(col(*name)).alias(*name))
+                        .collect::<Vec<_>>()
+                };
+                let mut exprs = convert(temperatures, |c| c + lit(ZERO_CELSIUS_IN_KELVIN));
+                exprs.extend(convert(wind_speeds, |c| c / lit(KMH_PER_MS)));
+                exprs.extend(convert(pressures, |c| c * lit(PA_PER_HPA)));
+                exprs
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scientific_conversions() {
+        let si = UnitSystem::Scientific;
+        assert!((si.temperature(-10.0) - 263.15).abs() < 1e-9);
+        assert!((si.wind_speed(36.0) - 10.0).abs() < 1e-9);
+        assert!((si.pressure(1013.25) - 101_325.0).abs() < 1e-9);
+        assert!((si.precipitation(2.5) - 2.5).abs() < 1e-9);
+        assert!((UnitSystem::Metric.temperature(-10.0) + 10.0).abs() < 1e-9);
+        assert!(UnitSystem::Metric
+            .column_exprs(&["temp"], &[], &[])
+            .is_empty());
+    }
+}
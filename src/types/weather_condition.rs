@@ -12,69 +12,75 @@ use std::fmt;
 /// for the official code definitions.
 ///
 /// You can convert an integer code (e.g., from a Polars `DataFrame`) into this enum
-/// using the [`WeatherCondition::from_i64`] method.
+/// using [`WeatherCondition::from_code`], which maps unrecognized codes to
+/// [`WeatherCondition::Unknown`], or [`WeatherCondition::from_i64`], which rejects them.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
 pub enum WeatherCondition {
     /// Code 1: Sky is clear.
-    Clear = 1,
+    Clear,
     /// Code 2: Sky is mostly clear (1-3 Oktas).
-    Fair = 2,
+    Fair,
     /// Code 3: Sky is partly cloudy (4-5 Oktas).
-    Cloudy = 3,
+    Cloudy,
     /// Code 4: Sky is mostly cloudy or overcast (6-8 Oktas).
-    Overcast = 4,
+    Overcast,
     /// Code 5: Fog is reducing visibility.
-    Fog = 5,
+    Fog,
     /// Code 6: Freezing fog (rime).
-    FreezingFog = 6,
+    FreezingFog,
     /// Code 7: Light rain.
-    LightRain = 7,
+    LightRain,
     /// Code 8: Moderate rain.
-    Rain = 8,
+    Rain,
     /// Code 9: Heavy rain.
-    HeavyRain = 9,
+    HeavyRain,
     /// Code 10: Light freezing rain (glaze).
-    FreezingRain = 10,
+    FreezingRain,
     /// Code 11: Heavy freezing rain (glaze).
-    HeavyFreezingRain = 11,
+    HeavyFreezingRain,
     /// Code 12: Light sleet (mix of rain and snow).
-    Sleet = 12,
+    Sleet,
     /// Code 13: Heavy sleet.
-    HeavySleet = 13,
+    HeavySleet,
     /// Code 14: Light snowfall.
-    LightSnowfall = 14,
+    LightSnowfall,
     /// Code 15: Moderate snowfall.
-    Snowfall = 15,
+    Snowfall,
     /// Code 16: Heavy snowfall.
-    HeavySnowfall = 16,
+    HeavySnowfall,
     /// Code 17: Light rain shower(s).
-    RainShower = 17,
+    RainShower,
     /// Code 18: Heavy rain shower(s).
-    HeavyRainShower = 18,
+    HeavyRainShower,
     /// Code 19: Light sleet shower(s).
-    SleetShower = 19,
+    SleetShower,
     /// Code 20: Heavy sleet shower(s).
-    HeavySleetShower = 20,
+    HeavySleetShower,
     /// Code 21: Light snow shower(s).
-    SnowShower = 21,
+    SnowShower,
     /// Code 22: Heavy snow shower(s).
-    HeavySnowShower = 22,
+    HeavySnowShower,
     /// Code 23: Lightning observed.
-    Lightning = 23,
+    Lightning,
     /// Code 24: Hail observed.
-    Hail = 24,
+    Hail,
     /// Code 25: Thunderstorm observed.
-    Thunderstorm = 25,
+    Thunderstorm,
     /// Code 26: Heavy thunderstorm observed.
-    HeavyThunderstorm = 26,
+    HeavyThunderstorm,
     /// Code 27: Storm conditions (typically high winds).
-    Storm = 27,
-    // Note: Codes 0 (Unknown) and 28+ are not explicitly defined by Meteostat as standard conditions.
+    Storm,
+    /// A code without a named variant, such as 0 or a code added by Meteostat after this
+    /// version of the crate. Keeps the raw value so it isn't lost.
+    Unknown(i64),
 }
 
 impl fmt::Display for WeatherCondition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::Unknown(code) => write!(f, "Unknown (code {code})"),
+            known => write!(f, "{known:?}"),
+        }
     }
 }
 
@@ -91,7 +97,8 @@ impl WeatherCondition {
     /// # Returns
     ///
     /// * `Some(WeatherCondition)` if the `value` corresponds to a known condition code (1-27).
-    /// * `None` if the `value` is outside the range of known codes (e.g., 0 or > 27).
+    /// * `None` if the `value` is outside the range of known codes (e.g., 0 or > 27). Use
+    ///   [`WeatherCondition::from_code`] to keep such codes as [`WeatherCondition::Unknown`].
     ///
     /// # Examples
     ///
@@ -155,4 +162,88 @@ impl WeatherCondition {
             _ => None, // Return None for invalid values (including 0)
         }
     }
+
+    /// Converts a Meteostat weather condition code into a `WeatherCondition`, keeping codes
+    /// without a named variant as [`WeatherCondition::Unknown`].
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The integer weather condition code (usually from the `coco` column).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meteostat::WeatherCondition;
+    ///
+    /// assert_eq!(WeatherCondition::from_code(8), WeatherCondition::Rain);
+    /// assert_eq!(WeatherCondition::from_code(99), WeatherCondition::Unknown(99));
+    /// assert_eq!(WeatherCondition::from_code(99).code(), 99);
+    /// ```
+    #[must_use]
+    pub const fn from_code(value: i64) -> Self {
+        match Self::from_i64(value) {
+            Some(condition) => condition,
+            None => Self::Unknown(value),
+        }
+    }
+
+    /// Returns the Meteostat weather condition code for this condition.
+    #[must_use]
+    pub const fn code(&self) -> i64 {
+        match self {
+            Self::Clear => 1,
+            Self::Fair => 2,
+            Self::Cloudy => 3,
+            Self::Overcast => 4,
+            Self::Fog => 5,
+            Self::FreezingFog => 6,
+            Self::LightRain => 7,
+            Self::Rain => 8,
+            Self::HeavyRain => 9,
+            Self::FreezingRain => 10,
+            Self::HeavyFreezingRain => 11,
+            Self::Sleet => 12,
+            Self::HeavySleet => 13,
+            Self::LightSnowfall => 14,
+            Self::Snowfall => 15,
+            Self::HeavySnowfall => 16,
+            Self::RainShower => 17,
+            Self::HeavyRainShower => 18,
+            Self::SleetShower => 19,
+            Self::HeavySleetShower => 20,
+            Self::SnowShower => 21,
+            Self::HeavySnowShower => 22,
+            Self::Lightning => 23,
+            Self::Hail => 24,
+            Self::Thunderstorm => 25,
+            Self::HeavyThunderstorm => 26,
+            Self::Storm => 27,
+            Self::Unknown(code) => *code,
+        }
+    }
+
+    /// Returns `true` unless this is [`WeatherCondition::Unknown`].
+    #[must_use]
+    pub const fn is_known(&self) -> bool {
+        !matches!(self, Self::Unknown(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_round_trip() {
+        for code in 0..=30 {
+            let condition = WeatherCondition::from_code(code);
+            assert_eq!(condition.code(), code);
+            assert_eq!(condition.is_known(), (1..=27).contains(&code));
+        }
+        assert_eq!(WeatherCondition::from_i64(0), None);
+        assert_eq!(
+            WeatherCondition::Unknown(42).to_string(),
+            "Unknown (code 42)"
+        );
+    }
 }
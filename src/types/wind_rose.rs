@@ -0,0 +1,165 @@
+//! Defines the `WindRose` structure, a tally of wind observations by direction and speed.
+
+use serde::{Deserialize, Serialize};
+
+/// Wind speeds below this value (1 knot, in km/h) are counted as calm, following the WMO
+/// convention. The direction of such light winds is not meaningful.
+pub const CALM_WIND_SPEED_KMH: f64 = 1.852;
+
+/// Wind observations binned by direction sector and speed bucket, e.g. for a polar plot.
+///
+/// Sectors are centered on the compass points: with 8 sectors, sector 0 covers north
+/// (337.5° up to 22.5°), sector 1 northeast, and so on clockwise. Speed buckets are bounded
+/// by the thresholds passed when building the rose, with one extra open-ended bucket for
+/// speeds at or above the last threshold.
+///
+/// Created with [`crate::HourlyLazyFrame::wind_rose`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct WindRose {
+    /// Upper bounds of the speed buckets in km/h, in ascending order.
+    pub speed_thresholds: Vec<f64>,
+    /// Observation counts indexed as `counts[sector][speed_bucket]`. Each sector has
+    /// `speed_thresholds.len() + 1` buckets.
+    pub counts: Vec<Vec<usize>>,
+    /// Mean wind speed in km/h per sector, or `None` for sectors without observations.
+    /// Calm observations are not included.
+    pub mean_speeds: Vec<Option<f64>>,
+    /// Number of calm observations (below [`CALM_WIND_SPEED_KMH`]), which have no sector.
+    pub calm: usize,
+}
+
+// Counts and sector indices stay far below 2^52, so converting them to f64 is exact.
+#[allow(clippy::cast_precision_loss)]
+impl WindRose {
+    /// Bins `(direction in degrees, speed in km/h)` observations into a wind rose.
+    ///
+    /// A `direction_bins` of 0 is treated as 1. `speed_thresholds` is sorted before use.
+    pub(crate) fn from_observations(
+        observations: impl IntoIterator<Item = (f64, f64)>,
+        direction_bins: usize,
+        speed_thresholds: &[f64],
+    ) -> Self {
+        let direction_bins = direction_bins.max(1);
+        let mut thresholds = speed_thresholds.to_vec();
+        thresholds.sort_by(f64::total_cmp);
+
+        let mut counts = vec![vec![0; thresholds.len() + 1]; direction_bins];
+        let mut speed_sums = vec![0.0; direction_bins];
+        let mut calm = 0;
+        for (direction, speed) in observations {
+            if speed < CALM_WIND_SPEED_KMH {
+                calm += 1;
+                continue;
+            }
+            let sector = sector_index(direction, direction_bins);
+            let bucket = thresholds.partition_point(|&threshold| threshold <= speed);
+            counts[sector][bucket] += 1;
+            speed_sums[sector] += speed;
+        }
+
+        let mean_speeds = counts
+            .iter()
+            .zip(speed_sums)
+            .map(|(sector_counts, sum)| {
+                let n: usize = sector_counts.iter().sum();
+                (n > 0).then(|| sum / n as f64)
+            })
+            .collect();
+
+        Self {
+            speed_thresholds: thresholds,
+            counts,
+            mean_speeds,
+            calm,
+        }
+    }
+
+    /// Number of direction sectors.
+    #[must_use]
+    pub const fn sector_count(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Width of each direction sector in degrees.
+    #[must_use]
+    pub fn sector_width(&self) -> f64 {
+        360.0 / self.sector_count() as f64
+    }
+
+    /// Center direction of a sector in degrees clockwise from north.
+    #[must_use]
+    pub fn sector_center(&self, sector: usize) -> f64 {
+        sector as f64 * self.sector_width()
+    }
+
+    /// Total number of observations, including calm ones.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.calm + self.counts.iter().flatten().sum::<usize>()
+    }
+
+    /// Share of all observations (including calm ones) in each sector and speed bucket,
+    /// in percent. Indexed like [`WindRose::counts`].
+    #[must_use]
+    pub fn percentages(&self) -> Vec<Vec<f64>> {
+        let total = self.total().max(1) as f64;
+        self.counts
+            .iter()
+            .map(|sector| sector.iter().map(|&n| 100.0 * n as f64 / total).collect())
+            .collect()
+    }
+}
+
+/// Maps a direction in degrees to the sector centered nearest to it.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn sector_index(direction: f64, bins: usize) -> usize {
+    let width = 360.0 / bins as f64;
+    let shifted = (direction + width / 2.0).rem_euclid(360.0);
+    // `rem_euclid` can round up to exactly 360.0 for tiny negative inputs
+    ((shifted / width) as usize).min(bins - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wind_rose_bins_by_sector_and_speed() {
+        let observations = [
+            (350.0, 5.0),  // N, slow
+            (10.0, 25.0),  // N, fast
+            (90.0, 10.0),  // E, middle bucket
+            (225.0, 40.0), // SW, fast
+            (180.0, 0.0),  // calm
+            (0.0, 1.0),    // calm, below one knot
+        ];
+        let rose = WindRose::from_observations(observations, 8, &[20.0, 10.0]);
+
+        assert_eq!(rose.speed_thresholds, vec![10.0, 20.0]);
+        assert_eq!(rose.sector_count(), 8);
+        assert_eq!(rose.counts[0], vec![1, 0, 1]);
+        assert_eq!(rose.counts[2], vec![0, 1, 0]);
+        assert_eq!(rose.counts[5], vec![0, 0, 1]);
+        assert_eq!(rose.calm, 2);
+        assert_eq!(rose.total(), 6);
+        assert_eq!(rose.mean_speeds[0], Some(15.0));
+        assert_eq!(rose.mean_speeds[1], None);
+        assert!((rose.sector_center(2) - 90.0).abs() < 1e-9);
+        let percentages = rose.percentages();
+        assert!((percentages[0][0] - 100.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sector_index_wraps_around_north() {
+        assert_eq!(sector_index(359.9, 4), 0);
+        assert_eq!(sector_index(44.9, 4), 0);
+        assert_eq!(sector_index(45.0, 4), 1);
+        assert_eq!(sector_index(360.0, 4), 0);
+        assert_eq!(sector_index(-1e-20, 4), 0);
+        assert_eq!(sector_index(123.0, 1), 0);
+    }
+}
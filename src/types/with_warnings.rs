@@ -0,0 +1,102 @@
+//! Defines `WithWarnings`, a result together with the non-fatal problems met producing it.
+
+use crate::types::station::Coverage;
+use crate::MeteostatError;
+use std::fmt;
+
+/// A problem that didn't stop a request, but that the caller may want to log or act on.
+///
+/// Returned in [`WithWarnings`] by the `try_location(..)` requests of the frequency clients.
+#[derive(Debug)]
+pub enum FetchWarning {
+    /// Fetching data from a candidate station failed, so the next candidate was tried.
+    StationFailed {
+        /// The ID of the station that failed.
+        station: String,
+        /// Why fetching its data failed.
+        error: MeteostatError,
+    },
+    /// The station the data came from doesn't report data for the whole requested period,
+    /// according to its inventory.
+    PeriodNotCovered {
+        /// The ID of the station the data came from.
+        station: String,
+        /// How much of the period the station's inventory covers.
+        coverage: Coverage,
+    },
+}
+
+impl fmt::Display for FetchWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StationFailed { station, error } => {
+                write!(f, "Skipped station '{station}': {error}")
+            }
+            Self::PeriodNotCovered { station, coverage } => {
+                write!(
+                    f,
+                    "Station '{station}' reports {coverage} coverage of the requested period"
+                )
+            }
+        }
+    }
+}
+
+/// A value together with the [`FetchWarning`]s met while producing it.
+///
+/// # Example
+///
+/// ```no_run
+/// use meteostat::{LatLon, Meteostat, MeteostatError, Year};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), MeteostatError> {
+/// let client = Meteostat::new().await?;
+/// let result = client
+///     .daily()
+///     .try_location(LatLon(52.52, 13.40))
+///     .station_limit(3)
+///     .for_period(Year(2023))
+///     .call()
+///     .await?;
+/// for warning in &result.warnings {
+///     eprintln!("warning: {warning}");
+/// }
+/// let daily = result.into_inner();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WithWarnings<T> {
+    /// The value that was produced.
+    pub value: T,
+    /// The problems met producing it, in the order they occurred.
+    pub warnings: Vec<FetchWarning>,
+}
+
+impl<T> WithWarnings<T> {
+    /// Wraps `value` with the given warnings.
+    #[must_use]
+    pub const fn new(value: T, warnings: Vec<FetchWarning>) -> Self {
+        Self { value, warnings }
+    }
+
+    /// Whether any warnings were recorded.
+    #[must_use]
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+
+    /// Discards the warnings and returns the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Transforms the value, keeping the warnings.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> WithWarnings<U> {
+        WithWarnings {
+            value: f(self.value),
+            warnings: self.warnings,
+        }
+    }
+}
@@ -1,12 +1,31 @@
+use crate::MeteostatError;
+use chrono::NaiveDate;
+use polars::prelude::{
+    lit, when, ChunkApply, ChunkCast, Column, DataType, Expr, Float64Chunked, Int64Chunked,
+};
+use std::ffi::OsString;
+use std::fmt;
 use std::io;
 use std::path::{Path, PathBuf};
 
 const CACHE_DIR_NAME: &str = "meteostat_rs_cache";
 
+/// Environment variable that overrides the default cache directory.
+pub const CACHE_DIR_ENV_VAR: &str = "METEOSTAT_CACHE_DIR";
+
+/// Resolves the default cache directory: `METEOSTAT_CACHE_DIR` if set and non-empty,
+/// otherwise a folder in the platform cache directory.
 pub fn get_cache_dir() -> Result<PathBuf, io::Error> {
-    dirs::cache_dir()
-        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Error getting cache dir."))
-        .map(|p| p.join(CACHE_DIR_NAME))
+    resolve_cache_dir(std::env::var_os(CACHE_DIR_ENV_VAR))
+}
+
+fn resolve_cache_dir(env_value: Option<OsString>) -> Result<PathBuf, io::Error> {
+    match env_value {
+        Some(value) if !value.is_empty() => Ok(PathBuf::from(value)),
+        _ => dirs::cache_dir()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Error getting cache dir."))
+            .map(|p| p.join(CACHE_DIR_NAME)),
+    }
 }
 
 pub async fn ensure_cache_dir_exists(path: &Path) -> Result<(), io::Error> {
@@ -24,3 +43,188 @@ pub async fn ensure_cache_dir_exists(path: &Path) -> Result<(), io::Error> {
         Err(e) => Err(e),
     }
 }
+
+/// Displays an optional measurement followed by its unit, or "n/a" if it's missing.
+pub struct Measurement<'a, T>(pub &'a Option<T>, pub &'static str);
+
+impl<T: fmt::Display> fmt::Display for Measurement<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(value) => write!(f, "{value}{}", self.1),
+            None => f.write_str("n/a"),
+        }
+    }
+}
+
+/// Picks the indices of at most `n_points` points that preserve the visual shape of a series,
+/// using the Largest-Triangle-Three-Buckets algorithm (Steinarsson, 2013).
+///
+/// The first and last points are always kept. The points in between are split into
+/// `n_points - 2` buckets, and from each bucket the point forming the largest triangle with
+/// the previously kept point and the average of the next bucket is kept. `x` must be sorted.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn lttb_indices(x: &[f64], y: &[f64], n_points: usize) -> Vec<usize> {
+    let len = x.len().min(y.len());
+    if n_points >= len {
+        return (0..len).collect();
+    }
+    if n_points < 3 {
+        return [0, len - 1].into_iter().take(n_points).collect();
+    }
+
+    let bucket_size = (len - 2) as f64 / (n_points - 2) as f64;
+    let bucket_start = |bucket: usize| ((bucket as f64 * bucket_size) as usize + 1).min(len - 1);
+
+    let mut indices = Vec::with_capacity(n_points);
+    indices.push(0);
+    let mut previous = 0;
+    for bucket in 0..n_points - 2 {
+        // Average of the next bucket, or the last point for the final bucket
+        let next =
+            bucket_start(bucket + 1)..bucket_start(bucket + 2).max(bucket_start(bucket + 1) + 1);
+        let next_len = next.len() as f64;
+        let avg_x = x[next.clone()].iter().sum::<f64>() / next_len;
+        let avg_y = y[next].iter().sum::<f64>() / next_len;
+
+        let (px, py) = (x[previous], y[previous]);
+        let area = |i: usize| {
+            (px - avg_x)
+                .mul_add(y[i] - py, -(px - x[i]) * (avg_y - py))
+                .abs()
+        };
+        let current = bucket_start(bucket)..bucket_start(bucket + 1);
+        // `max_by` keeps the last maximum, so compare reversed indices to keep the first
+        previous = current
+            .rev()
+            .max_by(|&a, &b| area(a).total_cmp(&area(b)))
+            .unwrap_or(previous);
+        indices.push(previous);
+    }
+    indices.push(len - 1);
+    indices
+}
+
+/// Reads a numeric column as `i64` values, whatever integer or float type Polars read it as.
+///
+/// Meteostat's integer columns (e.g. `rhum`, `wdir`, `coco`) are sometimes read as floats,
+/// when a file has a decimal in them. Floats are rounded to the nearest integer; values that
+/// don't fit, and NaN, become null.
+///
+/// # Errors
+///
+/// Returns [`MeteostatError::PolarsError`] if the column isn't numeric.
+pub fn column_i64(column: &Column) -> Result<Int64Chunked, MeteostatError> {
+    let dtype = column.dtype();
+    if dtype.is_float() {
+        let rounded = column
+            .cast(&DataType::Float64)?
+            .f64()?
+            .apply_values(f64::round);
+        Ok(rounded.cast(&DataType::Int64)?.i64()?.clone())
+    } else if dtype.is_primitive_numeric() || dtype.is_null() {
+        Ok(column.cast(&DataType::Int64)?.i64()?.clone())
+    } else {
+        Ok(column.i64()?.clone())
+    }
+}
+
+/// Rounds a numeric expression to the nearest whole number (half away from zero) and casts it
+/// to `Int64`, the lazy counterpart of the rounding in [`column_i64`]. A plain cast would
+/// truncate, turning e.g. `84.9999` into 84.
+pub fn round_to_i64(value: Expr) -> Expr {
+    when(value.clone().gt_eq(lit(0.0)))
+        .then(value.clone() + lit(0.5))
+        .otherwise(value - lit(0.5))
+        .cast(DataType::Int64)
+}
+
+/// Reads a numeric column as `f64` values, whatever integer or float type Polars read it as,
+/// e.g. when every value in a file happens to be a whole number.
+///
+/// # Errors
+///
+/// Returns [`MeteostatError::PolarsError`] if the column isn't numeric.
+pub fn column_f64(column: &Column) -> Result<Float64Chunked, MeteostatError> {
+    let dtype = column.dtype();
+    if dtype.is_primitive_numeric() || dtype.is_null() {
+        Ok(column.cast(&DataType::Float64)?.f64()?.clone())
+    } else {
+        Ok(column.f64()?.clone())
+    }
+}
+
+/// Converts the physical value of a Polars `Date` (days since 1970-01-01) into a `NaiveDate`.
+///
+/// Use this for every `Date` column read, instead of adding days to an epoch by hand.
+/// Returns `None` if the day count is outside the range `NaiveDate` can represent.
+pub fn polars_date_to_naive(days: i32) -> Option<NaiveDate> {
+    NaiveDate::from_epoch_days(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_columns_read_as_either_type() -> Result<(), MeteostatError> {
+        let floats = Column::new("coco".into(), [Some(7.0), Some(2.6), None, Some(f64::NAN)]);
+        assert_eq!(
+            column_i64(&floats)?.iter().collect::<Vec<_>>(),
+            [Some(7), Some(3), None, None]
+        );
+        let ints = Column::new("tmin".into(), [Some(3_i32), None]);
+        assert_eq!(
+            column_f64(&ints)?.iter().collect::<Vec<_>>(),
+            [Some(3.0), None]
+        );
+        assert!(column_i64(&Column::new("name".into(), ["a"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_polars_date_to_naive() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+        assert_eq!(polars_date_to_naive(0), date(1970, 1, 1));
+        assert_eq!(polars_date_to_naive(-1), date(1969, 12, 31));
+        assert_eq!(polars_date_to_naive(19_358), date(2023, 1, 1));
+        assert_eq!(polars_date_to_naive(i32::MAX), None);
+    }
+
+    #[test]
+    fn test_lttb_keeps_endpoints_and_peaks() {
+        let x: Vec<f64> = (0..100).map(f64::from).collect();
+        let mut y = vec![0.0; 100];
+        y[37] = 10.0;
+        y[71] = -8.0;
+
+        let indices = lttb_indices(&x, &y, 10);
+        assert_eq!(indices.len(), 10);
+        assert_eq!(indices.first(), Some(&0));
+        assert_eq!(indices.last(), Some(&99));
+        assert!(indices.contains(&37) && indices.contains(&71));
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+
+        assert_eq!(lttb_indices(&x, &y, 200).len(), 100);
+        assert_eq!(lttb_indices(&x, &y, 2), vec![0, 99]);
+        assert_eq!(lttb_indices(&x, &y, 1), vec![0]);
+        assert!(lttb_indices(&x, &y, 0).is_empty());
+        assert!(lttb_indices(&[], &[], 5).is_empty());
+    }
+
+    #[test]
+    fn test_resolve_cache_dir_prefers_env_value() -> Result<(), io::Error> {
+        assert_eq!(
+            resolve_cache_dir(Some("/data/meteostat".into()))?,
+            PathBuf::from("/data/meteostat")
+        );
+        // An empty value counts as unset
+        let default = resolve_cache_dir(Some(OsString::new()))?;
+        assert!(default.ends_with(CACHE_DIR_NAME));
+        assert_eq!(resolve_cache_dir(None)?, default);
+        Ok(())
+    }
+}
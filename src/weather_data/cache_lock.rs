@@ -0,0 +1,104 @@
+//! Advisory file locks that serialize writes to a cache file across processes.
+
+use crate::weather_data::error::WeatherDataError;
+use std::fs::{File, TryLockError};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+/// How long to wait between attempts to take a lock held by another process.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// An exclusive advisory lock on a cache file, held through a `.lock` file next to it.
+///
+/// The lock is released when the guard is dropped, including on early returns and when the
+/// future holding it is cancelled. The operating system also releases it when the process
+/// exits, so a crashed process doesn't leave it locked. Lock files are left in place, since
+/// removing one another process is waiting on would let two processes lock the same file.
+#[derive(Debug)]
+pub struct CacheFileLock {
+    file: File,
+}
+
+impl CacheFileLock {
+    /// The lock file used for `cache_file`.
+    pub fn lock_path(cache_file: &Path) -> PathBuf {
+        let mut name = cache_file.as_os_str().to_owned();
+        name.push(".lock");
+        PathBuf::from(name)
+    }
+
+    /// Takes the lock on `cache_file`, waiting up to `timeout` for another process to
+    /// release it.
+    ///
+    /// Returns `Ok(None)` if the lock is still held after `timeout`. The holder is then
+    /// assumed to be stuck (e.g. a hung process, or a network filesystem that kept the lock
+    /// of a crashed one) and the caller takes over without the lock, which is logged as a
+    /// warning so a lock that is stuck for good shows up. Cache files are replaced
+    /// atomically, so this can cost a duplicate download but not a corrupt file.
+    pub async fn acquire(
+        cache_file: &Path,
+        timeout: Duration,
+    ) -> Result<Option<Self>, WeatherDataError> {
+        let path = Self::lock_path(cache_file);
+        let lock_error = |e| WeatherDataError::CacheLock(path.clone(), e);
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .await
+            .map_err(lock_error)?
+            .into_std()
+            .await;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            match file.try_lock() {
+                Ok(()) => return Ok(Some(Self { file })),
+                Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                    sleep(POLL_INTERVAL.min(deadline - Instant::now())).await;
+                }
+                Err(TryLockError::WouldBlock) => {
+                    tracing::warn!(
+                        lock_file = %path.display(),
+                        ?timeout,
+                        "cache file lock still held after the timeout, continuing without it"
+                    );
+                    return Ok(None);
+                }
+                Err(TryLockError::Error(e)) => return Err(lock_error(e)),
+            }
+        }
+    }
+}
+
+impl Drop for CacheFileLock {
+    fn drop(&mut self) {
+        // Closing the file releases the lock as well; unlocking first makes it immediate
+        let _ = self.file.unlock();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lock_waits_then_takes_over() -> Result<(), WeatherDataError> {
+        let temp_dir = tempfile::tempdir()?;
+        let cache_file = temp_dir.path().join("daily-10637.parquet");
+
+        let held = CacheFileLock::acquire(&cache_file, Duration::ZERO).await?;
+        assert!(held.is_some());
+        // A second lock (as another process would take) times out while the first is held
+        let blocked = CacheFileLock::acquire(&cache_file, Duration::from_millis(120)).await?;
+        assert!(blocked.is_none());
+
+        drop(held);
+        let after_release = CacheFileLock::acquire(&cache_file, Duration::ZERO).await?;
+        assert!(after_release.is_some());
+        assert!(CacheFileLock::lock_path(&cache_file).exists());
+        Ok(())
+    }
+}
@@ -1,39 +1,261 @@
+use crate::cache_backend::{entry_path, CacheBackend};
+use crate::types::csv_options::CsvOptions;
+use crate::types::data_format::{DataFormat, DataSource};
 use crate::types::frequency::Frequency;
+use crate::weather_data::cache_lock::CacheFileLock;
 use crate::weather_data::error::WeatherDataError;
 use async_compression::tokio::bufread::GzipDecoder;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Utc};
 use futures_util::TryStreamExt;
 use polars::frame::DataFrame;
 use polars::prelude::*;
-use reqwest::Client;
+use reqwest::header::LAST_MODIFIED;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use serde_json::{Map, Value};
 use std::io::Cursor;
-use std::path::{Path, PathBuf};
+use std::io::SeekFrom;
+use std::path::Path;
 use std::sync::Arc;
-use tempfile::NamedTempFile;
-use tokio::io::AsyncReadExt;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio::sync::Semaphore;
 use tokio::{fs, task};
 use tokio_util::io::StreamReader;
 
+/// Official base URL of the bulk weather data files.
+pub const DEFAULT_BULK_DATA_BASE_URL: &str = "https://bulk.meteostat.net/v2";
+
+/// Compression used for cached Parquet files unless configured otherwise.
+pub const DEFAULT_CACHE_COMPRESSION: ParquetCompression = ParquetCompression::Zstd(None);
+
+/// Name of the optional provenance/quality flag column. Only present when the source file has it.
+pub const SOURCE_COLUMN: &str = "source";
+
+/// Rows per Parquet row group in cache files, about one year of hourly data. Each row group
+/// carries min/max statistics, so date range filters can skip the groups outside the range
+/// instead of decoding a station's whole history.
+const CACHE_ROW_GROUP_SIZE: usize = 8_760;
+
+/// Magic bytes at the start and end of every Parquet file.
+const PARQUET_MAGIC: [u8; 4] = *b"PAR1";
+
+/// Prefix of the placeholder columns that read extra CSV fields before they're dropped.
+const EXTRA_COLUMN_PREFIX: &str = "__extra_";
+
+/// Maximum number of characters of CSV content quoted in parse errors.
+const ERROR_SNIPPET_CHARS: usize = 120;
+
+/// Number of comma-separated fields in a CSV line. Commas in quoted fields don't count.
+fn field_count(line: &[u8]) -> usize {
+    let mut quoted = false;
+    let mut count = 1;
+    for b in line {
+        match b {
+            b'"' => quoted = !quoted,
+            b',' if !quoted => count += 1,
+            _ => {}
+        }
+    }
+    count
+}
+
+/// The lines of a CSV file after the first `skip_rows`, split lazily.
+fn csv_lines(bytes: &[u8], skip_rows: usize) -> impl Iterator<Item = &[u8]> {
+    bytes.split(|b| *b == b'\n').skip(skip_rows)
+}
+
+/// Quotes the CSV line a parse error most likely stems from: the first line without
+/// `width` fields, or else the first line. Long lines are cut off.
+///
+/// Only called once parsing failed, so successful parses never scan the file line by line.
+fn offending_line(bytes: &[u8], skip_rows: usize, width: usize) -> String {
+    let first = csv_lines(bytes, skip_rows).next().unwrap_or_default();
+    let line = csv_lines(bytes, skip_rows)
+        .find(|line| !line.is_empty() && field_count(line) != width)
+        .unwrap_or(first);
+    let text = String::from_utf8_lossy(line);
+    let text = text.trim_end_matches('\r');
+    if text.chars().count() > ERROR_SNIPPET_CHARS {
+        format!(
+            "{}...",
+            text.chars().take(ERROR_SNIPPET_CHARS).collect::<String>()
+        )
+    } else {
+        text.to_string()
+    }
+}
+
+/// A downloaded JSON data file: one record per row, keyed by column name.
+#[derive(Debug, Deserialize)]
+struct JsonDataFile {
+    data: Vec<Map<String, Value>>,
+}
+
+/// Reads the values of `name` from JSON records as a column of type `dtype` (one of the
+/// types of [`Frequency::csv_schema`]). Missing keys and `null` are read as null; numbers
+/// are accepted in string columns, but not the other way around.
+fn json_column(
+    records: &[Map<String, Value>],
+    name: &PlSmallStr,
+    dtype: &DataType,
+    station: &str,
+) -> Result<Column, WeatherDataError> {
+    let mismatch = |value: &Value| WeatherDataError::JsonValueType {
+        station: station.to_string(),
+        column: name.to_string(),
+        value: value.to_string(),
+    };
+    let values = records
+        .iter()
+        .map(|record| record.get(name.as_str()).filter(|value| !value.is_null()));
+    Ok(match dtype {
+        DataType::Int64 => Column::new(
+            name.clone(),
+            values
+                .map(|value| {
+                    value
+                        .map(|v| v.as_i64().ok_or_else(|| mismatch(v)))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        DataType::Float64 => Column::new(
+            name.clone(),
+            values
+                .map(|value| {
+                    value
+                        .map(|v| v.as_f64().ok_or_else(|| mismatch(v)))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        _ => Column::new(
+            name.clone(),
+            values
+                .map(|value| match value {
+                    None => Ok(None),
+                    Some(Value::String(text)) => Ok(Some(text.clone())),
+                    Some(number @ Value::Number(_)) => Ok(Some(number.to_string())),
+                    Some(other) => Err(mismatch(other)),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+    })
+}
+
+/// Parses an HTTP date like `Sun, 06 Nov 1994 08:49:37 GMT`.
+fn parse_http_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(value)
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+/// Whether `bytes` start and end with the Parquet magic bytes, as a complete file does.
+fn has_parquet_magic(bytes: &[u8]) -> bool {
+    // Header magic, footer length and footer magic
+    bytes.len() >= 2 * PARQUET_MAGIC.len() + 4
+        && bytes.starts_with(&PARQUET_MAGIC)
+        && bytes.ends_with(&PARQUET_MAGIC)
+}
+
 #[derive(Debug, Clone)]
 pub struct WeatherDataLoader {
-    cache_dir: PathBuf,
+    cache: Arc<dyn CacheBackend>,
     download_client: Client,
+    /// Base URL that `{frequency}/{station}.{extension}` is appended to.
+    base_url: String,
+    /// Where data is loaded from, tried in order until one succeeds.
+    sources: Vec<DataSource>,
+    /// Compression used when writing cache files. Reading detects it from the file itself.
+    compression: ParquetCompression,
+    /// Shared with the station locator to bound the number of simultaneous downloads.
+    download_permits: Arc<Semaphore>,
+    /// How downloaded CSV files that deviate from the format are read.
+    csv_options: CsvOptions,
+    /// How long to wait for another process's lock on a cache file before taking over.
+    /// `None` disables cache file locking.
+    cache_lock_timeout: Option<Duration>,
 }
 
 impl WeatherDataLoader {
-    pub fn new(cache_dir: &Path) -> Self {
+    pub fn new(
+        cache: Arc<dyn CacheBackend>,
+        base_url: &str,
+        compression: ParquetCompression,
+        download_permits: Arc<Semaphore>,
+    ) -> Self {
         let download_client = Client::new();
         Self {
-            cache_dir: cache_dir.to_path_buf(),
+            cache,
             download_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            compression,
+            download_permits,
+            sources: DataSource::DEFAULT_FALLBACK.to_vec(),
+            csv_options: CsvOptions::default(),
+            cache_lock_timeout: None,
+        }
+    }
+
+    /// Sets where data is loaded from, tried in order until one succeeds. An empty list
+    /// restores the default of the cache, then the bulk CSV file.
+    #[must_use]
+    pub fn with_sources(mut self, sources: &[DataSource]) -> Self {
+        self.sources = if sources.is_empty() {
+            DataSource::DEFAULT_FALLBACK.to_vec()
+        } else {
+            sources.to_vec()
+        };
+        self
+    }
+
+    /// The format of the first download source, used for checks and incremental updates
+    /// that address a single remote file.
+    fn primary_format(&self) -> DataFormat {
+        self.sources
+            .iter()
+            .find_map(|source| source.format())
+            .unwrap_or_default()
+    }
+
+    /// Sets how downloaded CSV files that deviate from the format are read.
+    #[must_use]
+    pub fn with_csv_options(mut self, csv_options: CsvOptions) -> Self {
+        self.csv_options = csv_options;
+        self
+    }
+
+    /// Serializes writes to cache files across processes with advisory file locks, waiting
+    /// at most `timeout` for another process. `None` disables locking.
+    #[must_use]
+    pub const fn with_cache_lock_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.cache_lock_timeout = timeout;
+        self
+    }
+
+    /// Takes the cross-process lock on a cache entry if locking is enabled and the backend
+    /// stores entries as local files. The lock is held until the returned guard is dropped.
+    async fn lock_cache_file(&self, key: &str) -> Result<Option<CacheFileLock>, WeatherDataError> {
+        match (self.cache_lock_timeout, self.cache.local_path(key)) {
+            (Some(timeout), Some(path)) => CacheFileLock::acquire(&path, timeout).await,
+            _ => Ok(None),
         }
     }
 
+    /// The cache key of the data of a station at a frequency, e.g. `daily-10637.parquet`.
+    pub fn cache_key(frequency: Frequency, station: &str) -> String {
+        format!("{}{}.parquet", frequency.cache_file_prefix(), station)
+    }
+
+    /// The base URL that `{frequency}/{station}.csv.gz` is appended to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
     /// Gets the last modification time of the cached Parquet file for a given
     /// station and frequency.
     ///
-    /// This function is cross-platform.
-    ///
     /// # Arguments
     ///
     /// * `station` - The ID of the station.
@@ -50,76 +272,388 @@ impl WeatherDataLoader {
         station: &str,
         frequency: Frequency,
     ) -> Result<Option<DateTime<Utc>>, WeatherDataError> {
-        let cache_filename = format!("{}{}.parquet", frequency.cache_file_prefix(), station);
-        let parquet_path = self.cache_dir.join(&cache_filename);
-
-        match fs::metadata(&parquet_path).await {
-            Ok(metadata) => {
-                // File exists, try to get modification time
-                let modified_system_time = metadata
-                    .modified()
-                    .map_err(|e| WeatherDataError::CacheMetadataRead(parquet_path.clone(), e))?;
-
-                // Convert SystemTime to chrono::DateTime<Utc>
-                let modified_datetime_utc: DateTime<Utc> = DateTime::from(modified_system_time);
+        let key = Self::cache_key(frequency, station);
+        self.cache
+            .modified(&key)
+            .await
+            .map_err(|e| WeatherDataError::CacheMetadataRead(entry_path(&*self.cache, &key), e))
+    }
 
-                Ok(Some(modified_datetime_utc))
-            }
-            Err(io_err) => {
-                if io_err.kind() == std::io::ErrorKind::NotFound {
-                    // File doesn't exist, this is a normal cache miss scenario
-                    Ok(None)
-                } else {
-                    // Other error accessing metadata (permissions, etc.)
-                    Err(WeatherDataError::CacheMetadataRead(parquet_path, io_err))
-                }
-            }
-        }
+    /// Gets the `Last-Modified` time of the remote data file for a station with a `HEAD`
+    /// request, without downloading the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(DateTime<Utc>))` - The modification time reported by the server.
+    /// * `Ok(None)` - If the server doesn't report a valid `Last-Modified` header.
+    /// * `Err(WeatherDataError)` - If the request fails or the server answers with an error
+    ///   status (e.g. 404 for stations without data at this frequency).
+    pub async fn get_remote_modification_time(
+        &self,
+        frequency: Frequency,
+        station: &str,
+    ) -> Result<Option<DateTime<Utc>>, WeatherDataError> {
+        let url = self.file_url(frequency, station, self.primary_format());
+        let _permit = self.download_permits.acquire().await.ok();
+        let response = self
+            .download_client
+            .head(&url)
+            .send()
+            .await
+            .map_err(|e| WeatherDataError::NetworkRequest(url.clone(), e))?
+            .error_for_status()
+            .map_err(|e| Self::response_error(url, e))?;
+        Ok(response
+            .headers()
+            .get(LAST_MODIFIED)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_http_date))
     }
 
     /// Generic function to load a `DataFrame` for a given station and data type.
     /// Handles caching and downloading. Returns a `LazyFrame` with schema-specific column names and types.
+    ///
+    /// The configured sources are tried in order. A download source whose cache lock can't
+    /// be taken counts as failed. If all of them fail, a single failure is returned as is,
+    /// and several as [`WeatherDataError::AllSourcesFailed`].
     pub async fn get_frame(
         &self,
         data_type: Frequency,
         station: &str,
     ) -> Result<LazyFrame, WeatherDataError> {
-        let cache_filename = format!("{}{}.parquet", data_type.cache_file_prefix(), station);
-        let parquet_path = self.cache_dir.join(&cache_filename);
+        let key = Self::cache_key(data_type, station);
+        let mut attempts = Vec::new();
+        let mut lock = None;
+        for (index, &source) in self.sources.iter().enumerate() {
+            let result = match source.format() {
+                None => self.read_cache(&key, data_type).await,
+                Some(format) => {
+                    if lock.is_none() {
+                        match self.lock_cache_file(&key).await {
+                            Ok(guard) => lock = Some(guard),
+                            Err(e) => {
+                                attempts.push((source, e));
+                                continue;
+                            }
+                        }
+                        // Another process may have written the file while we waited for the lock
+                        if self.sources[..index].contains(&DataSource::Cache) {
+                            match self.read_cache(&key, data_type).await {
+                                Ok(Some(frame)) => return Ok(frame),
+                                Ok(None) => {}
+                                Err(e) => attempts.push((DataSource::Cache, e)),
+                            }
+                        }
+                    }
+                    self.download_frame(&key, data_type, station, format)
+                        .await
+                        .map(Some)
+                }
+            };
+            match result {
+                Ok(Some(frame)) => return Ok(frame),
+                // A cache miss isn't a failure, the data just has to come from elsewhere
+                Ok(None) => {}
+                Err(e) => attempts.push((source, e)),
+            }
+        }
 
-        if fs::metadata(&parquet_path).await.is_err() {
-            let station_id = station.to_string();
+        Err(match attempts.len() {
+            0 => WeatherDataError::NotCached(entry_path(&*self.cache, &key)),
+            1 => attempts.remove(0).1,
+            _ => WeatherDataError::AllSourcesFailed {
+                station: station.to_string(),
+                attempts,
+            },
+        })
+    }
 
-            let raw_bytes = self.download(data_type, &station_id).await?;
-            let df = Self::csv_to_dataframe(raw_bytes, &station_id, data_type).await?;
+    /// Downloads the data file of a station in `format` and writes it to the cache.
+    async fn download_frame(
+        &self,
+        key: &str,
+        data_type: Frequency,
+        station: &str,
+        format: DataFormat,
+    ) -> Result<LazyFrame, WeatherDataError> {
+        let raw_bytes = self
+            .download_url(self.file_url(data_type, station, format))
+            .await?;
+        let df = self
+            .bytes_to_dataframe(raw_bytes, station, data_type, format)
+            .await?;
+        let written = self.write_cache(key, df.clone()).await;
+        // Local files are scanned lazily rather than kept in memory. A failed write is fine
+        // if another process wrote the file meanwhile (e.g. on Windows, where a file being
+        // read can't be replaced).
+        if self.cache.local_path(key).is_some() || written.is_err() {
+            if let Some(frame) = self.read_cache(key, data_type).await? {
+                return Ok(frame);
+            }
+        }
+        written?;
+        Ok(df.lazy())
+    }
 
-            fs::create_dir_all(&self.cache_dir)
+    /// Reads a cached Parquet file as a `LazyFrame` with the canonical schema of
+    /// `data_type`. Files on the local filesystem are scanned lazily, others are read into
+    /// memory.
+    ///
+    /// Returns `Ok(None)` if the entry is missing, or if it was empty or truncated (e.g.
+    /// left behind by a full disk), in which case it is removed so it can be replaced
+    /// rather than failing on every read.
+    async fn read_cache(
+        &self,
+        key: &str,
+        data_type: Frequency,
+    ) -> Result<Option<LazyFrame>, WeatherDataError> {
+        let frame = if let Some(parquet_path) = self.cache.local_path(key) {
+            if !Self::check_or_remove_cache_file(&parquet_path).await? {
+                return Ok(None);
+            }
+            let pl_path = PlRefPath::try_from_path(&parquet_path)
+                .map_err(|e| WeatherDataError::ParquetScan(parquet_path.clone(), e))?;
+            LazyFrame::scan_parquet(pl_path, ScanArgsParquet::default())
+                .map_err(|e| WeatherDataError::ParquetScan(parquet_path, e))?
+        } else {
+            let path = entry_path(&*self.cache, key);
+            let bytes = self
+                .cache
+                .read(key)
                 .await
-                .map_err(|e| WeatherDataError::CacheDirCreation(self.cache_dir.clone(), e))?;
+                .map_err(|e| WeatherDataError::CacheRead(path.clone(), e))?;
+            let Some(bytes) = bytes else {
+                return Ok(None);
+            };
+            if !has_parquet_magic(&bytes) {
+                let error = WeatherDataError::EmptyCacheFile(path.clone());
+                tracing::warn!(cache_file = %path.display(), %error, "removing invalid cache entry");
+                self.cache
+                    .delete(key)
+                    .await
+                    .map_err(|e| WeatherDataError::CacheDeletionError(path, e))?;
+                return Ok(None);
+            }
+            task::spawn_blocking(move || ParquetReader::new(Cursor::new(bytes)).finish())
+                .await?
+                .map_err(|e| WeatherDataError::ParquetScan(path, e))?
+                .lazy()
+        };
+        // Cache files written by other versions or tools may use other types. Resolving the
+        // schema reads the Parquet metadata, which Polars does on a blocking thread.
+        let frame = task::spawn_blocking(move || data_type.cast_to_schema(frame))
+            .await?
+            .map_err(|e| WeatherDataError::ParquetScan(entry_path(&*self.cache, key), e))?;
+        Ok(Some(frame))
+    }
 
-            // Pass df by value (ownership moves to cache_dataframe)
-            Self::cache_dataframe(df, &parquet_path).await?;
+    /// Encodes `df` as Parquet and stores it under `key`, replacing any existing entry.
+    async fn write_cache(&self, key: &str, df: DataFrame) -> Result<(), WeatherDataError> {
+        let path = entry_path(&*self.cache, key);
+        let compression = self.compression;
+        let bytes = task::spawn_blocking(move || Self::encode_parquet(df, compression))
+            .await?
+            .map_err(|e| WeatherDataError::ParquetWritePolars(path.clone(), e))?;
+        self.cache
+            .write(key, bytes)
+            .await
+            .map_err(|e| WeatherDataError::ParquetWriteIo(path, e))
+    }
+
+    /// Like [`Self::check_cache_file`], but removes an empty or truncated file (e.g. left
+    /// behind by a full disk) and reports it as missing, so it is replaced rather than
+    /// failing on every read.
+    async fn check_or_remove_cache_file(parquet_path: &Path) -> Result<bool, WeatherDataError> {
+        match Self::check_cache_file(parquet_path).await {
+            Ok(exists) => Ok(exists),
+            Err(error @ WeatherDataError::EmptyCacheFile(_)) => {
+                tracing::warn!(
+                    cache_file = %parquet_path.display(),
+                    %error,
+                    "removing invalid cache entry"
+                );
+                fs::remove_file(parquet_path).await.map_err(|e| {
+                    WeatherDataError::CacheDeletionError(parquet_path.to_path_buf(), e)
+                })?;
+                Ok(false)
+            }
+            Err(e) => Err(e),
         }
+    }
 
-        let pl_path = PlRefPath::try_from_path(&parquet_path)
-            .map_err(|e| WeatherDataError::ParquetScan(parquet_path.clone(), e))?;
+    /// Checks whether a cache file exists and looks like a complete Parquet file, without
+    /// decoding it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - If the file exists and starts and ends with the Parquet magic bytes.
+    /// * `Ok(false)` - If the file does not exist.
+    /// * `Err(WeatherDataError::EmptyCacheFile)` - If the file is empty or truncated.
+    /// * `Err(WeatherDataError::CacheMetadataRead)` - For other I/O errors.
+    async fn check_cache_file(path: &Path) -> Result<bool, WeatherDataError> {
+        let read_error = |e| WeatherDataError::CacheMetadataRead(path.to_path_buf(), e);
+        let mut file = match fs::File::open(path).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(read_error(e)),
+        };
+        // Header magic, footer length and footer magic, as in `has_parquet_magic`
+        let min_len = 2 * PARQUET_MAGIC.len() as u64 + 4;
+        if file.metadata().await.map_err(read_error)?.len() < min_len {
+            return Err(WeatherDataError::EmptyCacheFile(path.to_path_buf()));
+        }
 
-        LazyFrame::scan_parquet(pl_path, ScanArgsParquet::default())
-            .map_err(|e| WeatherDataError::ParquetScan(parquet_path, e))
+        let mut head = [0; 4];
+        let mut tail = [0; 4];
+        file.read_exact(&mut head).await.map_err(read_error)?;
+        file.seek(SeekFrom::End(-4)).await.map_err(read_error)?;
+        file.read_exact(&mut tail).await.map_err(read_error)?;
+        if head != PARQUET_MAGIC || tail != PARQUET_MAGIC {
+            return Err(WeatherDataError::EmptyCacheFile(path.to_path_buf()));
+        }
+        Ok(true)
     }
 
-    /// Downloads and decompresses data for a specific type and station.
-    async fn download(
+    /// Updates the cached Parquet file by downloading only the per-year files from the last
+    /// cached year up to the current year, instead of the station's full history.
+    ///
+    /// Returns `Ok(false)` without touching the cache if an incremental update isn't possible
+    /// (the frequency has no per-year files, nothing is cached yet, or no yearly files exist
+    /// for the station). The caller should then fall back to a full download.
+    pub async fn update_frame_incremental(
         &self,
         data_type: Frequency,
         station: &str,
-    ) -> Result<Vec<u8>, WeatherDataError> {
-        let url = format!(
-            "https://bulk.meteostat.net/v2/{}/{}.csv.gz",
+    ) -> Result<bool, WeatherDataError> {
+        if !data_type.has_yearly_files() {
+            return Ok(false);
+        }
+        let key = Self::cache_key(data_type, station);
+        let Some(cached) = self.read_cache(&key, data_type).await? else {
+            return Ok(false);
+        };
+        let _lock = self.lock_cache_file(&key).await?;
+
+        let Some(first_year) = Self::last_cached_year(cached.clone()).await? else {
+            return Ok(false);
+        };
+
+        let format = self.primary_format();
+        let mut recent = Vec::new();
+        for year in first_year..=Utc::now().year() {
+            let url = format!(
+                "{}/{}/{}/{}.{}",
+                self.base_url,
+                data_type.path_segment(),
+                year,
+                station,
+                format.file_extension()
+            );
+            match self.download_url(url).await {
+                Ok(bytes) => recent.push(
+                    self.bytes_to_dataframe(bytes, station, data_type, format)
+                        .await?,
+                ),
+                // Years without observations simply have no file
+                Err(WeatherDataError::HttpStatus { status, .. })
+                    if status == StatusCode::NOT_FOUND => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if recent.is_empty() {
+            return Ok(false);
+        }
+
+        let merged = Self::merge_recent_years(cached, recent, first_year).await?;
+        self.write_cache(&key, merged).await?;
+        Ok(true)
+    }
+
+    /// Returns the year of the latest row in cached hourly data.
+    async fn last_cached_year(cached: LazyFrame) -> Result<Option<i32>, WeatherDataError> {
+        task::spawn_blocking(move || {
+            let df = cached
+                .select([col("datetime").dt().year().max()])
+                .collect()?;
+            Ok(df.column("datetime")?.i32()?.get(0))
+        })
+        .await?
+    }
+
+    /// Replaces every cached row from `first_year` onwards with the freshly downloaded
+    /// per-year frames, keeping the older history from the cache.
+    async fn merge_recent_years(
+        cached: LazyFrame,
+        recent: Vec<DataFrame>,
+        first_year: i32,
+    ) -> Result<DataFrame, WeatherDataError> {
+        task::spawn_blocking(move || {
+            let cached = cached
+                .filter(col("datetime").dt().year().lt(lit(first_year)))
+                .collect()?;
+
+            let mut frames = vec![cached];
+            frames.extend(recent);
+
+            // The optional source column may only be present in some of the files, so pad
+            // the others with nulls and put all columns in the same order before stacking.
+            let has_source = frames.iter().any(|df| df.column(SOURCE_COLUMN).is_ok());
+            let mut columns: Vec<Expr> = frames[0]
+                .get_column_names()
+                .into_iter()
+                .filter(|name| name.as_str() != SOURCE_COLUMN)
+                .map(|name| col(name.clone()))
+                .collect();
+            if has_source {
+                columns.push(col(SOURCE_COLUMN));
+            }
+            let lazy_frames = frames
+                .into_iter()
+                .map(|df| {
+                    let mut lf = df.lazy();
+                    if has_source && lf.collect_schema()?.get(SOURCE_COLUMN).is_none() {
+                        lf = lf.with_column(lit(NULL).cast(DataType::String).alias(SOURCE_COLUMN));
+                    }
+                    Ok(lf.select(columns.clone()))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            Ok(concat(lazy_frames, UnionArgs::default())?
+                .sort(["datetime"], SortMultipleOptions::default())
+                .collect()?)
+        })
+        .await?
+    }
+
+    /// The URL of the full data file for a station in `format`.
+    fn file_url(&self, data_type: Frequency, station: &str, format: DataFormat) -> String {
+        format!(
+            "{}/{}/{}.{}",
+            self.base_url,
             data_type.path_segment(),
-            station
-        );
+            station,
+            format.file_extension()
+        )
+    }
+
+    /// Converts an error from `error_for_status` into a [`WeatherDataError`].
+    fn response_error(url: String, e: reqwest::Error) -> WeatherDataError {
+        if let Some(status) = e.status() {
+            WeatherDataError::HttpStatus {
+                url,
+                status,
+                source: e,
+            }
+        } else {
+            WeatherDataError::NetworkRequest(url, e)
+        }
+    }
 
+    /// Downloads and decompresses a gzipped file.
+    ///
+    /// A download permit is held until the whole body has been read.
+    async fn download_url(&self, url: String) -> Result<Vec<u8>, WeatherDataError> {
+        let _permit = self.download_permits.acquire().await.ok();
         let response = self
             .download_client
             .get(&url)
@@ -129,17 +663,7 @@ impl WeatherDataLoader {
 
         let response = match response.error_for_status() {
             Ok(resp) => resp,
-            Err(e) => {
-                return Err(if let Some(status) = e.status() {
-                    WeatherDataError::HttpStatus {
-                        url,
-                        status,
-                        source: e,
-                    }
-                } else {
-                    WeatherDataError::NetworkRequest(url, e)
-                });
-            }
+            Err(e) => return Err(Self::response_error(url, e)),
         };
 
         let stream = response.bytes_stream().map_err(std::io::Error::other);
@@ -154,191 +678,743 @@ impl WeatherDataLoader {
         Ok(decompressed)
     }
 
+    /// Parses a downloaded data file in `format` into a `DataFrame`.
+    async fn bytes_to_dataframe(
+        &self,
+        bytes: Vec<u8>,
+        station: &str,
+        data_type: Frequency,
+        format: DataFormat,
+    ) -> Result<DataFrame, WeatherDataError> {
+        match format {
+            DataFormat::Csv => {
+                Self::csv_to_dataframe(bytes, station, data_type, &self.csv_options).await
+            }
+            DataFormat::Json => {
+                let station_owned = station.to_string();
+                task::spawn_blocking(move || Self::parse_json(&bytes, &station_owned, data_type))
+                    .await?
+            }
+        }
+    }
+
     /// Parses raw CSV bytes (without header) into a `DataFrame` using a blocking task.
-    /// Assigns correct column names and casts columns to appropriate data types based on Frequency.
     async fn csv_to_dataframe(
         bytes: Vec<u8>,
         station: &str,
         data_type: Frequency,
+        options: &CsvOptions,
     ) -> Result<DataFrame, WeatherDataError> {
         let station_owned = station.to_string();
+        let options = options.clone();
+        task::spawn_blocking(move || Self::parse_csv(&bytes, &station_owned, data_type, &options))
+            .await?
+    }
 
-        task::spawn_blocking(move || {
-            // Build the schema.
-            let schema = match data_type {
-                Frequency::Hourly => Schema::from_iter(vec![
-                    Field::new("date".into(), DataType::String),
-                    Field::new("hour".into(), DataType::Int64),
-                    Field::new("temp".into(), DataType::Float64),
-                    Field::new("dwpt".into(), DataType::Float64),
-                    Field::new("rhum".into(), DataType::Float64), // Parse as Float64, cast later
-                    Field::new("prcp".into(), DataType::Float64),
-                    Field::new("snow".into(), DataType::Float64), // Parse as Float64, cast later
-                    Field::new("wdir".into(), DataType::Float64), // Parse as Float64, cast later
-                    Field::new("wspd".into(), DataType::Float64),
-                    Field::new("wpgt".into(), DataType::Float64),
-                    Field::new("pres".into(), DataType::Float64),
-                    Field::new("tsun".into(), DataType::Float64), // Parse as Float64, cast later
-                    Field::new("coco".into(), DataType::Float64), // Parse as Float64, cast later
-                ]),
-                Frequency::Daily => Schema::from_iter(vec![
-                    Field::new("date".into(), DataType::String),
-                    Field::new("tavg".into(), DataType::Float64),
-                    Field::new("tmin".into(), DataType::Float64),
-                    Field::new("tmax".into(), DataType::Float64),
-                    Field::new("prcp".into(), DataType::Float64),
-                    Field::new("snow".into(), DataType::Float64), // Parse as Float64, cast later
-                    Field::new("wdir".into(), DataType::Float64), // Parse as Float64, cast later
-                    Field::new("wspd".into(), DataType::Float64),
-                    Field::new("wpgt".into(), DataType::Float64),
-                    Field::new("pres".into(), DataType::Float64),
-                    Field::new("tsun".into(), DataType::Float64), // Parse as Float64, cast later
-                ]),
-                Frequency::Monthly => Schema::from_iter(vec![
-                    Field::new("year".into(), DataType::Int64),
-                    Field::new("month".into(), DataType::Int64),
-                    Field::new("tavg".into(), DataType::Float64),
-                    Field::new("tmin".into(), DataType::Float64),
-                    Field::new("tmax".into(), DataType::Float64),
-                    Field::new("prcp".into(), DataType::Float64),
-                    Field::new("wspd".into(), DataType::Float64),
-                    Field::new("pres".into(), DataType::Float64),
-                    Field::new("tsun".into(), DataType::Float64), // Parse as Float64, cast later
-                ]),
-                Frequency::Climate => Schema::from_iter(vec![
-                    Field::new("start_year".into(), DataType::Int64),
-                    Field::new("end_year".into(), DataType::Int64),
-                    Field::new("month".into(), DataType::Int64),
-                    Field::new("tmin".into(), DataType::Float64),
-                    Field::new("tmax".into(), DataType::Float64),
-                    Field::new("prcp".into(), DataType::Float64),
-                    Field::new("wspd".into(), DataType::Float64),
-                    Field::new("pres".into(), DataType::Float64),
-                    Field::new("tsun".into(), DataType::Float64), // Parse as Float64, cast later
-                ]),
-            };
-            let schema_len = schema.len();
-            let schema_ref: SchemaRef = Arc::new(schema);
-
-            // Read the initial DataFrame directly from memory with schema
-            let df = CsvReadOptions::default()
-                .with_has_header(false)
-                .with_schema(Some(schema_ref))
-                .into_reader_with_file_handle(Cursor::new(bytes))
-                .finish()
-                .map_err(|e| WeatherDataError::CsvReadPolars {
-                    station: station_owned.clone(),
-                    source: e,
-                })?;
-
-            if df.width() != schema_len {
-                return Err(WeatherDataError::SchemaMismatch {
-                    station: station_owned,
-                    data_type,
-                    expected: schema_len,
-                    found: df.width(),
-                });
+    /// Parses raw CSV bytes (without header) into a `DataFrame`.
+    /// Assigns correct column names and casts columns to appropriate data types based on Frequency.
+    /// `station` is only used to label errors.
+    pub fn parse_csv(
+        bytes: &[u8],
+        station: &str,
+        data_type: Frequency,
+        options: &CsvOptions,
+    ) -> Result<DataFrame, WeatherDataError> {
+        let skip_rows = usize::from(options.skip_header);
+        let mut schema = data_type.csv_schema();
+        // Some files carry a trailing provenance/quality flag column. Only add it to the
+        // schema when it is actually present, so regular files parse exactly as before.
+        let csv_width = csv_lines(bytes, skip_rows).next().map_or(0, field_count);
+        let mut extra_columns = Vec::new();
+        if csv_width == schema.len() + 1 {
+            schema.with_column(SOURCE_COLUMN.into(), DataType::String);
+        } else if options.ignore_extra_columns && csv_width > schema.len() {
+            // Read the extra fields into placeholders, which are dropped right after
+            for index in schema.len()..csv_width {
+                let name: PlSmallStr = format!("{EXTRA_COLUMN_PREFIX}{index}").into();
+                schema.with_column(name.clone(), DataType::String);
+                extra_columns.push(name);
             }
+        }
+        let schema_len = schema.len();
+        let schema_ref: SchemaRef = Arc::new(schema);
+        let null_values = (!options.null_values.is_empty()).then(|| {
+            NullValues::AllColumns(options.null_values.iter().map(|v| v.into()).collect())
+        });
 
-            // --- Type Casting and Pre-computation ---
-            let mut lazy_df = df.lazy();
-
-            // Common strptime options
-            let date_options = StrptimeOptions {
-                format: Some("%Y-%m-%d".into()),
-                strict: false,
-                exact: true,
-                cache: true,
-            };
+        // Read the initial DataFrame directly from memory with schema
+        let df = CsvReadOptions::default()
+            .with_has_header(false)
+            .with_skip_rows(skip_rows)
+            .with_ignore_errors(options.flexible_schema)
+            .with_schema(Some(schema_ref))
+            .map_parse_options(|parse_options| {
+                parse_options
+                    .with_null_values(null_values.clone())
+                    .with_truncate_ragged_lines(options.ignore_extra_columns)
+            })
+            .into_reader_with_file_handle(Cursor::new(bytes))
+            .finish()
+            .map_err(|e| WeatherDataError::CsvReadPolars {
+                station: station.to_string(),
+                line: offending_line(bytes, skip_rows, schema_len),
+                source: e,
+            })?;
 
-            // Apply type parsing and cast float representations back to native i64
-            lazy_df = match data_type {
-                Frequency::Hourly => {
-                    lazy_df.with_columns([
-                        // Create datetime first from string date and i64 hour
-                        (col("date")
-                            .str()
-                            .strptime(DataType::Date, date_options, lit("raise"))
-                            .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
-                            + duration(DurationArgs::new().with_hours(col("hour"))))
-                        .alias("datetime"),
-                        // Safely cast numeric float formats to Int64
-                        col("rhum").cast(DataType::Int64),
-                        col("snow").cast(DataType::Int64),
-                        col("wdir").cast(DataType::Int64),
-                        col("tsun").cast(DataType::Int64),
-                        col("coco").cast(DataType::Int64),
-                    ])
-                }
-                Frequency::Daily => {
-                    lazy_df.with_columns([
-                        // Parse date string to Date type
-                        col("date")
-                            .str()
-                            .strptime(DataType::Date, date_options, lit("raise"))
-                            .alias("date"),
-                        // Safely cast numeric float formats to Int64
-                        col("snow").cast(DataType::Int64),
-                        col("wdir").cast(DataType::Int64),
-                        col("tsun").cast(DataType::Int64),
-                    ])
-                }
-                Frequency::Monthly | Frequency::Climate => {
-                    lazy_df.with_columns([
-                        // Safely cast numeric float formats to Int64
-                        col("tsun").cast(DataType::Int64),
-                    ])
-                }
-            };
+        if df.width() != schema_len {
+            return Err(WeatherDataError::SchemaMismatch {
+                station: station.to_string(),
+                data_type,
+                expected: schema_len,
+                found: df.width(),
+                line: offending_line(bytes, skip_rows, schema_len),
+            });
+        }
+        let df = df.drop_many(extra_columns);
+        Self::finish_frame(df, station, data_type)
+    }
 
-            // Collect the lazy frame to apply transformations and handle potential errors
-            let typed_df =
-                lazy_df
-                    .collect()
-                    .map_err(|e| WeatherDataError::ColumnOperationError {
-                        station: station_owned.clone(),
-                        source: e,
-                    })?;
+    /// Parses a JSON data file (see [`DataFormat::Json`]) into a `DataFrame` with the same
+    /// schema as [`Self::parse_csv`] gives.
+    ///
+    /// The columns are built from the records in the column order of `data_type`, then go
+    /// through the same date parsing, typing and sorting as CSV files. `station` is only
+    /// used to label errors.
+    pub fn parse_json(
+        bytes: &[u8],
+        station: &str,
+        data_type: Frequency,
+    ) -> Result<DataFrame, WeatherDataError> {
+        let file: JsonDataFile =
+            serde_json::from_slice(bytes).map_err(|e| WeatherDataError::JsonRead {
+                station: station.to_string(),
+                source: e,
+            })?;
+        let mut schema = data_type.csv_schema();
+        if file
+            .data
+            .iter()
+            .any(|record| record.contains_key(SOURCE_COLUMN))
+        {
+            schema.with_column(SOURCE_COLUMN.into(), DataType::String);
+        }
 
-            Ok(typed_df)
-        })
-        .await?
+        let columns = schema
+            .iter()
+            .map(|(name, dtype)| json_column(&file.data, name, dtype, station))
+            .collect::<Result<Vec<_>, _>>()?;
+        let df = DataFrame::new(file.data.len(), columns).map_err(|e| {
+            WeatherDataError::ColumnOperationError {
+                station: station.to_string(),
+                source: e,
+            }
+        })?;
+        Self::finish_frame(df, station, data_type)
     }
 
-    /// Writes a `DataFrame` to a Parquet file atomically using a temporary file.
-    async fn cache_dataframe(mut df: DataFrame, path: &Path) -> Result<(), WeatherDataError> {
-        let path_buf = path.to_path_buf();
-        task::spawn_blocking(move || {
-            let parent = path_buf.parent().ok_or_else(|| {
-                WeatherDataError::ParquetWriteIo(
-                    path_buf.clone(),
-                    std::io::Error::new(std::io::ErrorKind::NotFound, "No parent directory"),
-                )
-            })?;
-            if path_buf.exists() {
-                return Ok::<(), WeatherDataError>(());
+    /// Parses dates, casts every column to its canonical type and sorts the rows of a frame
+    /// read with the columns of [`Frequency::csv_schema`], whatever format it came from.
+    fn finish_frame(
+        df: DataFrame,
+        station: &str,
+        data_type: Frequency,
+    ) -> Result<DataFrame, WeatherDataError> {
+        // --- Type Casting and Pre-computation ---
+        let mut lazy_df = df.lazy();
+
+        // Common strptime options
+        let date_options = StrptimeOptions {
+            format: Some("%Y-%m-%d".into()),
+            strict: false,
+            exact: true,
+            cache: true,
+        };
+
+        // Apply date parsing
+        lazy_df = match data_type {
+            Frequency::Hourly => {
+                lazy_df.with_columns([
+                    // Create datetime from string date and i64 hour
+                    (col("date")
+                        .str()
+                        .strptime(DataType::Date, date_options, lit("raise"))
+                        .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+                        + duration(DurationArgs::new().with_hours(col("hour"))))
+                    .alias("datetime"),
+                ])
             }
-            let mut temp_file = NamedTempFile::new_in(parent)
-                .map_err(|e| WeatherDataError::ParquetWriteIo(path_buf.clone(), e))?;
-            ParquetWriter::new(&mut temp_file)
-                .with_compression(ParquetCompression::Snappy)
-                .finish(&mut df)
-                .map_err(|e| WeatherDataError::ParquetWritePolars(path_buf.clone(), e))?;
-            if path_buf.exists() {
-                return Ok::<(), WeatherDataError>(());
+            Frequency::Daily => {
+                lazy_df.with_columns([
+                    // Parse date string to Date type
+                    col("date")
+                        .str()
+                        .strptime(DataType::Date, date_options, lit("raise"))
+                        .alias("date"),
+                ])
             }
-            if let Err(err) = temp_file.persist(&path_buf) {
-                if path_buf.exists() {
-                    return Ok::<(), WeatherDataError>(());
-                }
-                return Err(WeatherDataError::ParquetWriteIo(
-                    path_buf.clone(),
-                    err.error,
-                ));
+            Frequency::Monthly | Frequency::Climate => lazy_df,
+        };
+
+        // Cast every column to its canonical type, e.g. integer columns parsed as floats
+        lazy_df = data_type.cast_to_schema(lazy_df).map_err(|e| {
+            WeatherDataError::ColumnOperationError {
+                station: station.to_string(),
+                source: e,
             }
-            Ok::<(), WeatherDataError>(())
+        })?;
+
+        // Keep rows in chronological order so each row group covers a narrow time span
+        lazy_df = match data_type {
+            Frequency::Hourly => lazy_df.sort(["datetime"], SortMultipleOptions::default()),
+            Frequency::Daily => lazy_df.sort(["date"], SortMultipleOptions::default()),
+            Frequency::Monthly => lazy_df.sort(["year", "month"], SortMultipleOptions::default()),
+            Frequency::Climate => lazy_df,
+        };
+
+        // Collect the lazy frame to apply transformations and handle potential errors
+        let typed_df = lazy_df
+            .collect()
+            .map_err(|e| WeatherDataError::ColumnOperationError {
+                station: station.to_string(),
+                source: e,
+            })?;
+
+        Ok(typed_df)
+    }
+
+    /// Encodes a `DataFrame` as a Parquet file in memory.
+    fn encode_parquet(mut df: DataFrame, compression: ParquetCompression) -> PolarsResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        ParquetWriter::new(&mut bytes)
+            .with_compression(compression)
+            .with_statistics(StatisticsOptions::default())
+            .with_row_group_size(Some(CACHE_ROW_GROUP_SIZE))
+            .finish(&mut df)?;
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FileSystemCache, MemoryCache};
+    use std::time::Duration;
+
+    const DAILY_CSV: &str = "2023-01-01,5.1,2.0,8.3,1.2,,240,15.0,40.0,1012.3,60\n\
+                             2023-01-02,4.0,1.1,6.9,0.0,,250,12.0,35.0,1015.0,\n";
+
+    /// A loader caching in `cache`. Port 9 (discard) refuses connections, so downloads fail
+    /// without network.
+    fn offline_loader(cache: Arc<dyn CacheBackend>) -> WeatherDataLoader {
+        WeatherDataLoader::new(
+            cache,
+            "http://127.0.0.1:9",
+            DEFAULT_CACHE_COMPRESSION,
+            Arc::new(Semaphore::new(1)),
+        )
+    }
+
+    #[test]
+    fn test_parse_http_date() {
+        let parsed = parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT");
+        assert_eq!(
+            parsed.map(|date| date.to_rfc3339()),
+            Some("1994-11-06T08:49:37+00:00".to_string())
+        );
+        assert_eq!(parse_http_date("yesterday"), None);
+    }
+
+    #[tokio::test]
+    async fn test_csv_to_dataframe_without_source_column() -> Result<(), WeatherDataError> {
+        let df = WeatherDataLoader::csv_to_dataframe(
+            DAILY_CSV.as_bytes().to_vec(),
+            "test",
+            Frequency::Daily,
+            &CsvOptions::default(),
+        )
+        .await?;
+
+        assert_eq!(df.height(), 2);
+        assert!(df.column(SOURCE_COLUMN).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_json_matches_csv() -> Result<(), WeatherDataError> {
+        let json = r#"{"data": [
+            {"date": "2023-01-02", "tavg": 4.0, "tmin": 1.1, "tmax": 6.9, "prcp": 0.0,
+             "snow": null, "wdir": 250, "wspd": 12.0, "wpgt": 35.0, "pres": 1015.0},
+            {"date": "2023-01-01", "tavg": 5.1, "tmin": 2.0, "tmax": 8.3, "prcp": 1.2,
+             "wdir": 240, "wspd": 15.0, "wpgt": 40.0, "pres": 1012.3, "tsun": 60, "note": "x"}
+        ]}"#;
+        let from_json = WeatherDataLoader::parse_json(json.as_bytes(), "test", Frequency::Daily)?;
+        let from_csv = WeatherDataLoader::parse_csv(
+            DAILY_CSV.as_bytes(),
+            "test",
+            Frequency::Daily,
+            &CsvOptions::default(),
+        )?;
+        assert!(from_json.equals_missing(&from_csv));
+
+        let hourly =
+            r#"{"data": [{"date": "2023-01-01", "hour": 5, "temp": 1.5, "source": "a,b"}]}"#;
+        let df = WeatherDataLoader::parse_json(hourly.as_bytes(), "test", Frequency::Hourly)?;
+        assert_eq!(df.column(SOURCE_COLUMN)?.str()?.get(0), Some("a,b"));
+        assert_eq!(df.column("hour")?.i64()?.get(0), Some(5));
+
+        assert!(matches!(
+            WeatherDataLoader::parse_json(b"[1, 2]", "test", Frequency::Daily),
+            Err(WeatherDataError::JsonRead { .. })
+        ));
+        let mistyped = br#"{"data": [{"date": "2023-01-01", "tavg": "warm"}]}"#;
+        assert!(matches!(
+            WeatherDataLoader::parse_json(mistyped, "test", Frequency::Daily),
+            Err(WeatherDataError::JsonValueType { column, .. }) if column == "tavg"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_merge_recent_years_replaces_recent_rows() -> Result<(), WeatherDataError> {
+        let temp_dir = tempfile::tempdir()?;
+        let loader = offline_loader(Arc::new(FileSystemCache::new(temp_dir.path())));
+        let key = "hourly-test.parquet";
+
+        let cached_csv = "2022-12-31,23,1.0,,,,,,,,,,\n2023-01-01,0,2.0,,,,,,,,,,\n";
+        let cached = WeatherDataLoader::csv_to_dataframe(
+            cached_csv.as_bytes().to_vec(),
+            "test",
+            Frequency::Hourly,
+            &CsvOptions::default(),
+        )
+        .await?;
+        loader.write_cache(key, cached).await?;
+        let cached = loader
+            .read_cache(key, Frequency::Hourly)
+            .await?
+            .expect("cache file was just written");
+        assert_eq!(
+            WeatherDataLoader::last_cached_year(cached.clone()).await?,
+            Some(2023)
+        );
+
+        // The yearly file supersedes the cached 2023 rows and carries a source column
+        let recent_csv = "2023-01-01,0,3.0,,,,,,,,,,,model\n2023-01-01,1,4.0,,,,,,,,,,,model\n";
+        let recent = WeatherDataLoader::csv_to_dataframe(
+            recent_csv.as_bytes().to_vec(),
+            "test",
+            Frequency::Hourly,
+            &CsvOptions::default(),
+        )
+        .await?;
+        let merged = WeatherDataLoader::merge_recent_years(cached, vec![recent], 2023).await?;
+        // Rewriting with a different codec must still be readable
+        let snappy = WeatherDataLoader {
+            compression: ParquetCompression::Snappy,
+            ..loader
+        };
+        snappy.write_cache(key, merged.clone()).await?;
+        let rewritten = snappy
+            .read_cache(key, Frequency::Hourly)
+            .await?
+            .expect("cache file was just written");
+        assert_eq!(
+            WeatherDataLoader::last_cached_year(rewritten).await?,
+            Some(2023)
+        );
+
+        assert_eq!(merged.height(), 3);
+        let temp = merged.column("temp")?.f64()?;
+        let temps: Vec<_> = (0..merged.height()).map(|i| temp.get(i)).collect();
+        assert_eq!(temps, vec![Some(1.0), Some(3.0), Some(4.0)]);
+        let source = merged.column(SOURCE_COLUMN)?.str()?;
+        assert_eq!(source.get(0), None);
+        assert_eq!(source.get(1), Some("model"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cache_file_has_prunable_row_groups() -> Result<(), WeatherDataError> {
+        let temp_dir = tempfile::tempdir()?;
+        let loader = offline_loader(Arc::new(FileSystemCache::new(temp_dir.path())));
+        let path = temp_dir.path().join("daily-test.parquet");
+
+        // 80 years of daily rows, written in reverse to check they get sorted
+        let start = chrono::NaiveDate::from_ymd_opt(1990, 1, 1).unwrap_or_default();
+        let csv: String = (0..80 * 365)
+            .rev()
+            .map(|i| format!("{},{i}.0,,,,,,,,,\n", start + chrono::Days::new(i)))
+            .collect();
+        let df = WeatherDataLoader::csv_to_dataframe(
+            csv.into_bytes(),
+            "test",
+            Frequency::Daily,
+            &CsvOptions::default(),
+        )
+        .await?;
+        loader.write_cache("daily-test.parquet", df).await?;
+
+        let mut reader = ParquetReader::new(std::fs::File::open(&path)?);
+        let metadata = reader.get_metadata()?;
+        assert_eq!(metadata.row_groups.len(), 80 * 365 / CACHE_ROW_GROUP_SIZE);
+
+        let pl_path = PlRefPath::try_from_path(&path)
+            .map_err(|e| WeatherDataError::ParquetScan(path.clone(), e))?;
+        let filtered = task::spawn_blocking(move || {
+            LazyFrame::scan_parquet(pl_path, ScanArgsParquet::default())?
+                .filter(
+                    col("date")
+                        .gt_eq(lit(start))
+                        .and(col("date").lt(lit(start + chrono::Days::new(3)))),
+                )
+                .collect()
         })
         .await??;
+        let temp = filtered.column("tavg")?.f64()?;
+        let temps: Vec<_> = (0..filtered.height()).map(|i| temp.get(i)).collect();
+        assert_eq!(temps, vec![Some(0.0), Some(1.0), Some(2.0)]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_cache_file_detects_truncated_files() -> Result<(), WeatherDataError> {
+        let temp_dir = tempfile::tempdir()?;
+        let loader = offline_loader(Arc::new(FileSystemCache::new(temp_dir.path())));
+        let path = temp_dir.path().join("daily-test.parquet");
+        assert!(!WeatherDataLoader::check_cache_file(&path).await?);
+
+        let df = WeatherDataLoader::csv_to_dataframe(
+            DAILY_CSV.as_bytes().to_vec(),
+            "test",
+            Frequency::Daily,
+            &CsvOptions::default(),
+        )
+        .await?;
+        loader.write_cache("daily-test.parquet", df).await?;
+        assert!(WeatherDataLoader::check_cache_file(&path).await?);
+
+        // Cut off the footer, as an interrupted write would
+        let bytes = std::fs::read(&path)?;
+        std::fs::write(&path, &bytes[..bytes.len() / 2])?;
+        assert!(matches!(
+            WeatherDataLoader::check_cache_file(&path).await,
+            Err(WeatherDataError::EmptyCacheFile(_))
+        ));
+        std::fs::write(&path, b"")?;
+        assert!(matches!(
+            WeatherDataLoader::check_cache_file(&path).await,
+            Err(WeatherDataError::EmptyCacheFile(_))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_frame_replaces_empty_cache_file() -> Result<(), WeatherDataError> {
+        let temp_dir = tempfile::tempdir()?;
+        let path = temp_dir.path().join(format!(
+            "{}10637.parquet",
+            Frequency::Daily.cache_file_prefix()
+        ));
+        std::fs::write(&path, b"")?;
+        let loader = offline_loader(Arc::new(FileSystemCache::new(temp_dir.path())));
+
+        let result = loader.get_frame(Frequency::Daily, "10637").await;
+        assert!(matches!(result, Err(WeatherDataError::NetworkRequest(..))));
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_frame_from_memory_cache() -> Result<(), WeatherDataError> {
+        let cache = Arc::new(MemoryCache::new());
+        let loader = offline_loader(cache.clone());
+        let key = WeatherDataLoader::cache_key(Frequency::Daily, "10637");
+        let df = WeatherDataLoader::csv_to_dataframe(
+            DAILY_CSV.as_bytes().to_vec(),
+            "10637",
+            Frequency::Daily,
+            &CsvOptions::default(),
+        )
+        .await?;
+        loader.write_cache(&key, df).await?;
+
+        let frame = loader.get_frame(Frequency::Daily, "10637").await?;
+        assert_eq!(frame.collect()?.height(), 2);
+        assert!(loader
+            .get_cache_modification_time("10637", Frequency::Daily)
+            .await?
+            .is_some());
+
+        // A damaged entry is dropped and re-downloaded, which fails offline
+        cache.write(&key, b"PAR1".to_vec()).await?;
+        let result = loader.get_frame(Frequency::Daily, "10637").await;
+        assert!(matches!(result, Err(WeatherDataError::NetworkRequest(..))));
+        assert_eq!(cache.read(&key).await?, None);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_frame_source_fallback() -> Result<(), WeatherDataError> {
+        let cache = Arc::new(MemoryCache::new());
+        let loader = offline_loader(cache.clone());
+
+        // Both downloads fail, and each failure is reported
+        let fallback =
+            loader
+                .clone()
+                .with_sources(&[DataSource::Cache, DataSource::Csv, DataSource::Json]);
+        let result = fallback.get_frame(Frequency::Daily, "10637").await;
+        let Err(WeatherDataError::AllSourcesFailed { attempts, .. }) = result else {
+            panic!("expected AllSourcesFailed");
+        };
+        let sources: Vec<_> = attempts.iter().map(|(source, _)| *source).collect();
+        assert_eq!(sources, [DataSource::Csv, DataSource::Json]);
+
+        let cache_only = loader.clone().with_sources(&[DataSource::Cache]);
+        let result = cache_only.get_frame(Frequency::Daily, "10637").await;
+        assert!(matches!(&result, Err(e @ WeatherDataError::NotCached(_)) if e.is_not_found()));
+
+        // With the cache last, it's only used once the download failed
+        let df = WeatherDataLoader::csv_to_dataframe(
+            DAILY_CSV.as_bytes().to_vec(),
+            "10637",
+            Frequency::Daily,
+            &CsvOptions::default(),
+        )
+        .await?;
+        let key = WeatherDataLoader::cache_key(Frequency::Daily, "10637");
+        loader.write_cache(&key, df).await?;
+        let offline_first = loader.with_sources(&[DataSource::Json, DataSource::Cache]);
+        let frame = offline_first.get_frame(Frequency::Daily, "10637").await?;
+        assert_eq!(frame.collect()?.height(), 2);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_frame_continues_after_lock_failure() -> Result<(), WeatherDataError> {
+        let temp_dir = tempfile::tempdir()?;
+        let cache = Arc::new(FileSystemCache::new(temp_dir.path()));
+        let loader =
+            offline_loader(cache.clone()).with_cache_lock_timeout(Some(Duration::from_millis(10)));
+        let key = WeatherDataLoader::cache_key(Frequency::Daily, "10637");
+        // A directory where the lock file belongs makes taking the lock fail
+        let cache_file = temp_dir.path().join(&key);
+        std::fs::create_dir(CacheFileLock::lock_path(&cache_file))?;
+
+        let result = loader.get_frame(Frequency::Daily, "10637").await;
+        assert!(matches!(result, Err(WeatherDataError::CacheLock(..))));
+
+        // The trailing cache source is still tried
+        let df = WeatherDataLoader::csv_to_dataframe(
+            DAILY_CSV.as_bytes().to_vec(),
+            "10637",
+            Frequency::Daily,
+            &CsvOptions::default(),
+        )
+        .await?;
+        loader.write_cache(&key, df).await?;
+        let offline_first = loader.with_sources(&[DataSource::Csv, DataSource::Cache]);
+        let frame = offline_first.get_frame(Frequency::Daily, "10637").await?;
+        assert_eq!(frame.collect()?.height(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_waits_for_permit() -> Result<(), WeatherDataError> {
+        let temp_dir = tempfile::tempdir()?;
+        let permits = Arc::new(Semaphore::new(1));
+        // Port 9 (discard) refuses connections, so a started download fails immediately
+        let loader = WeatherDataLoader::new(
+            Arc::new(FileSystemCache::new(temp_dir.path())),
+            "http://127.0.0.1:9",
+            DEFAULT_CACHE_COMPRESSION,
+            permits.clone(),
+        );
+        let url = "http://127.0.0.1:9/daily/10637.csv.gz".to_string();
+
+        let held = permits.acquire().await.ok();
+        let blocked =
+            tokio::time::timeout(Duration::from_millis(200), loader.download_url(url.clone()))
+                .await;
+        assert!(
+            blocked.is_err(),
+            "download should wait while no permit is free"
+        );
+
+        drop(held);
+        assert!(loader.download_url(url).await.is_err());
+        assert_eq!(permits.available_permits(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_csv_to_dataframe_matches_frequency_schema() -> Result<(), WeatherDataError> {
+        let samples = [
+            (
+                Frequency::Hourly,
+                "2023-01-01,0,1.0,,80,,,200,5.0,,1010.0,,3\n",
+            ),
+            (Frequency::Daily, DAILY_CSV),
+            (
+                Frequency::Monthly,
+                "2023,1,1.0,-2.0,5.0,40.0,10.0,1012.0,3000\n",
+            ),
+            (
+                Frequency::Climate,
+                "1991,2020,1,-2.0,5.0,40.0,10.0,1012.0,3000\n",
+            ),
+        ];
+        for (frequency, csv) in samples {
+            let df = WeatherDataLoader::csv_to_dataframe(
+                csv.as_bytes().to_vec(),
+                "test",
+                frequency,
+                &CsvOptions::default(),
+            )
+            .await?;
+            assert_eq!(**df.schema(), frequency.schema(), "{frequency}");
+            assert_eq!(
+                df.get_column_names()
+                    .iter()
+                    .map(|name| name.as_str())
+                    .collect::<Vec<_>>(),
+                frequency.columns(),
+                "{frequency}"
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_get_frame_casts_mis_typed_cache_to_schema() -> Result<(), WeatherDataError> {
+        let temp_dir = tempfile::tempdir()?;
+        let parsed = WeatherDataLoader::csv_to_dataframe(
+            DAILY_CSV.as_bytes().to_vec(),
+            "test",
+            Frequency::Daily,
+            &CsvOptions::default(),
+        )
+        .await?;
+        // As if another tool had inferred the types: whole numbers as integers and vice versa
+        let mis_typed = parsed
+            .clone()
+            .lazy()
+            .with_columns([
+                col("tmin").cast(DataType::Int64),
+                col("snow").cast(DataType::Float64),
+                col("wdir").cast(DataType::Int32),
+                col("tsun").cast(DataType::Float32),
+            ])
+            .collect()?;
+        assert_ne!(**mis_typed.schema(), Frequency::Daily.schema());
+        let loader = offline_loader(Arc::new(FileSystemCache::new(temp_dir.path())));
+        loader.write_cache("daily-test.parquet", mis_typed).await?;
+
+        let df = loader
+            .get_frame(Frequency::Daily, "test")
+            .await?
+            .collect()?;
+        assert_eq!(**df.schema(), Frequency::Daily.schema());
+        assert_eq!(df.column("wdir")?.i64()?.get(1), Some(250));
+
+        // A cache that already has the canonical types is scanned without casts
+        loader.write_cache("daily-typed.parquet", parsed).await?;
+        let plan = loader
+            .get_frame(Frequency::Daily, "typed")
+            .await?
+            .explain(false)?;
+        assert!(!plan.contains("WITH_COLUMNS"), "{plan}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_csv_rounds_decimals_in_integer_columns() -> Result<(), WeatherDataError> {
+        // Integer columns written with decimals, as by a tool that inferred them as floats
+        let csv = "2023-01-01,0,1.5,0.2,85.0,,,240.0,10.0,,1012.0,,8.0\n\
+                   2023-01-01,1,1.4,0.1,84.9999,,,250.4,9.0,,1012.5,,2.5\n";
+        let df = WeatherDataLoader::parse_csv(
+            csv.as_bytes(),
+            "test",
+            Frequency::Hourly,
+            &CsvOptions::default(),
+        )?;
+
+        assert_eq!(**df.schema(), Frequency::Hourly.schema());
+        let rhum: Vec<_> = df.column("rhum")?.i64()?.iter().collect();
+        assert_eq!(rhum, [Some(85), Some(85)]);
+        let wdir: Vec<_> = df.column("wdir")?.i64()?.iter().collect();
+        assert_eq!(wdir, [Some(240), Some(250)]);
+        let coco: Vec<_> = df.column("coco")?.i64()?.iter().collect();
+        assert_eq!(coco, [Some(8), Some(3)]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_csv_to_dataframe_with_source_column() -> Result<(), WeatherDataError> {
+        let csv = "2023-01-01,5.1,2.0,8.3,1.2,,240,15.0,40.0,1012.3,60,measured\n\
+                   2023-01-02,4.0,1.1,6.9,0.0,,250,12.0,35.0,1015.0,,model\n";
+        let df = WeatherDataLoader::csv_to_dataframe(
+            csv.as_bytes().to_vec(),
+            "test",
+            Frequency::Daily,
+            &CsvOptions::default(),
+        )
+        .await?;
+
+        let source = df.column(SOURCE_COLUMN)?.str()?;
+        assert_eq!(source.get(0), Some("measured"));
+        assert_eq!(source.get(1), Some("model"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_csv_options_for_malformed_files() -> Result<(), WeatherDataError> {
+        let strict = CsvOptions::default();
+        let parse = |csv: &str, options: &CsvOptions| {
+            WeatherDataLoader::parse_csv(csv.as_bytes(), "10637", Frequency::Daily, options)
+        };
+
+        // A stray field on the second row fails, quoting the row
+        let ragged = "2023-01-01,5.1,2.0,8.3,1.2,,240,15.0,40.0,1012.3,60\n\
+                      2023-01-02,4.0,1.1,6.9,0.0,,250,12.0,35.0,1015.0,,x,y\n";
+        let error = parse(ragged, &strict).expect_err("extra fields should fail");
+        assert!(
+            matches!(&error, WeatherDataError::CsvReadPolars { station, line, .. }
+                if station == "10637" && line.starts_with("2023-01-02")),
+            "{error:?}"
+        );
+        let lenient = CsvOptions {
+            ignore_extra_columns: true,
+            ..CsvOptions::default()
+        };
+        assert_eq!(
+            **parse(ragged, &lenient)?.schema(),
+            Frequency::Daily.schema()
+        );
+        // Also when every row has them
+        let wide = "2023-01-01,5.1,2.0,8.3,1.2,,240,15.0,40.0,1012.3,60,x,y\n";
+        assert_eq!(
+            parse(wide, &lenient)?.width(),
+            Frequency::Daily.schema().len()
+        );
+
+        // A header row, "NA" nulls and a non-numeric value
+        let odd = "date,tavg,tmin,tmax,prcp,snow,wdir,wspd,wpgt,pres,tsun\n\
+                   2023-01-01,NA,2.0,8.3,1.2,,240,15.0,40.0,1012.3,sixty\n";
+        assert!(parse(odd, &strict).is_err());
+        let df = parse(
+            odd,
+            &CsvOptions {
+                skip_header: true,
+                flexible_schema: true,
+                null_values: vec!["NA".to_string()],
+                ..CsvOptions::default()
+            },
+        )?;
+        assert_eq!(df.height(), 1);
+        assert_eq!(df.column("tavg")?.f64()?.get(0), None);
+        assert_eq!(df.column("tmin")?.f64()?.get(0), Some(2.0));
+        assert_eq!(df.column("tsun")?.i64()?.get(0), None);
         Ok(())
     }
 }
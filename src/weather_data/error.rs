@@ -1,3 +1,4 @@
+use crate::types::data_format::DataSource;
 use crate::types::frequency::Frequency;
 use chrono::NaiveDate;
 use polars::error::PolarsError;
@@ -16,6 +17,9 @@ pub enum WeatherDataError {
     #[error("Failed to read metadata for cache file '{0}'")]
     CacheMetadataRead(PathBuf, #[source] std::io::Error),
 
+    #[error("Failed to read cache file '{0}'")]
+    CacheRead(PathBuf, #[source] std::io::Error),
+
     // Errors during parquet writing (inside blocking task)
     #[error("I/O error writing parquet cache file '{0}'")]
     ParquetWriteIo(PathBuf, #[source] std::io::Error),
@@ -25,6 +29,9 @@ pub enum WeatherDataError {
     #[error("Failed to scan parquet cache file '{0}'")]
     ParquetScan(PathBuf, #[source] PolarsError),
 
+    #[error("Cache file '{0}' is empty or not a complete parquet file")]
+    EmptyCacheFile(PathBuf),
+
     #[error("Network request failed for {0}")]
     NetworkRequest(String, #[source] reqwest::Error),
 
@@ -46,13 +53,29 @@ pub enum WeatherDataError {
         #[source]
         source: std::io::Error,
     },
-    #[error("Parsing error processing CSV data for station '{station}'")]
+    #[error("Parsing error processing CSV data for station '{station}' near line '{line}'")]
     CsvReadPolars {
         station: String,
+        /// The line the error most likely stems from, cut off if long.
+        line: String,
         #[source]
         source: PolarsError,
     },
 
+    #[error("Parsing error processing JSON data for station '{station}'")]
+    JsonRead {
+        station: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("Value {value} in column '{column}' of the JSON data for station '{station}' doesn't fit the column's type")]
+    JsonValueType {
+        station: String,
+        column: String,
+        value: String,
+    },
+
     #[error("Background task failed to complete")]
     TaskJoin(#[from] tokio::task::JoinError),
 
@@ -70,12 +93,14 @@ pub enum WeatherDataError {
     #[error("Required column '{0}' not found in DataFrame")]
     ColumnNotFound(String, #[source] PolarsError),
 
-    #[error("CSV column count ({found}) does not match schema length ({expected}) for {data_type} data for station {station}")]
+    #[error("CSV column count ({found}) does not match schema length ({expected}) for {data_type} data for station {station} near line '{line}'")]
     SchemaMismatch {
         station: String,
         data_type: Frequency,
         expected: usize,
         found: usize,
+        /// The line the error most likely stems from, cut off if long.
+        line: String,
     },
 
     #[error("Failed to rename columns for station {station}: {source}")]
@@ -108,4 +133,57 @@ pub enum WeatherDataError {
 
     #[error("Failed to delete cache '{0}'")]
     CacheDeletionError(PathBuf, #[source] std::io::Error),
+
+    #[error("Failed to lock cache file with lock file '{0}'")]
+    CacheLock(PathBuf, #[source] std::io::Error),
+
+    #[error("Weather data is not cached at '{0}'")]
+    NotCached(PathBuf),
+
+    #[error(
+        "All data sources failed for station '{station}': {}",
+        describe_attempts(attempts)
+    )]
+    AllSourcesFailed {
+        station: String,
+        /// Each source that failed, with its error, in the order they were tried.
+        attempts: Vec<(DataSource, WeatherDataError)>,
+    },
+}
+
+impl WeatherDataError {
+    /// Whether the data doesn't exist at any of the places it was looked for, e.g. because
+    /// the station doesn't record this frequency, as opposed to a failed download.
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::HttpStatus { status, .. } => *status == reqwest::StatusCode::NOT_FOUND,
+            Self::NotCached(_) => true,
+            Self::AllSourcesFailed { attempts, .. } => {
+                attempts.iter().all(|(_, error)| error.is_not_found())
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether downloading the data failed, e.g. because the server is unreachable or
+    /// answered with an error status, as opposed to the data being broken or the cache
+    /// failing.
+    pub fn is_download_failure(&self) -> bool {
+        match self {
+            Self::NetworkRequest(..) | Self::HttpStatus { .. } | Self::DownloadIo(_) => true,
+            Self::AllSourcesFailed { attempts, .. } => attempts
+                .iter()
+                .all(|(_, error)| error.is_not_found() || error.is_download_failure()),
+            _ => false,
+        }
+    }
+}
+
+/// Lists the failed attempts of [`WeatherDataError::AllSourcesFailed`] on one line.
+fn describe_attempts(attempts: &[(DataSource, WeatherDataError)]) -> String {
+    attempts
+        .iter()
+        .map(|(source, error)| format!("{source}: {error}"))
+        .collect::<Vec<_>>()
+        .join("; ")
 }
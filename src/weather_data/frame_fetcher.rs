@@ -1,57 +1,86 @@
+use crate::cache_backend::{entry_path, CacheBackend};
+use crate::types::csv_options::CsvOptions;
+use crate::types::data_format::DataSource;
 use crate::types::frequency::Frequency;
 use crate::weather_data::data_loader::WeatherDataLoader;
 use crate::weather_data::error::WeatherDataError;
-use crate::RequiredData;
-use chrono::Utc;
-use polars::prelude::LazyFrame;
+use crate::{PurgeReport, RequiredData, UpdateStatus};
+use chrono::{DateTime, Utc};
+use polars::prelude::{LazyFrame, ParquetCompression};
 use std::collections::{hash_map::Entry, HashMap};
-use std::ffi::OsStr;
-use std::io;
-use std::path::{Path, PathBuf};
-use tokio::sync::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, Semaphore};
+
+/// Cache key for a station's data at a given frequency.
+type FrameKey = (String, Frequency);
 
 pub struct FrameFetcher {
     loader: WeatherDataLoader,
-    lazyframe_cache: Mutex<HashMap<(String, Frequency), LazyFrame>>,
-    cache_folder: PathBuf,
+    lazyframe_cache: Mutex<HashMap<FrameKey, LazyFrame>>,
+    /// One lock per station/frequency, held while loading it, so concurrent requests for
+    /// the same data wait for a single download instead of each starting their own. Entries
+    /// are removed once nobody holds or waits for them, so the map only grows with the
+    /// number of concurrent loads.
+    load_locks: Mutex<HashMap<FrameKey, Arc<Mutex<()>>>>,
+    cache: Arc<dyn CacheBackend>,
 }
 
 impl FrameFetcher {
-    pub fn new(cache_dir: &Path) -> Self {
+    pub fn new(
+        cache: Arc<dyn CacheBackend>,
+        bulk_data_base_url: &str,
+        cache_compression: ParquetCompression,
+        download_permits: Arc<Semaphore>,
+    ) -> Self {
         Self {
-            loader: WeatherDataLoader::new(cache_dir),
+            loader: WeatherDataLoader::new(
+                cache.clone(),
+                bulk_data_base_url,
+                cache_compression,
+                download_permits,
+            ),
             lazyframe_cache: Mutex::new(HashMap::new()),
-            cache_folder: cache_dir.to_path_buf(),
+            load_locks: Mutex::new(HashMap::new()),
+            cache,
         }
     }
 
-    pub async fn clear_cache_all(&self) -> Result<(), WeatherDataError> {
-        let mut entries = tokio::fs::read_dir(&self.cache_folder)
-            .await
-            .map_err(|e| WeatherDataError::CacheDeletionError(self.cache_folder.clone(), e))?;
+    /// Locks cache files while writing them, see [`WeatherDataLoader::with_cache_lock_timeout`].
+    #[must_use]
+    pub fn with_cache_lock_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.loader = self.loader.with_cache_lock_timeout(timeout);
+        self
+    }
 
-        while let Some(entry) = entries
-            .next_entry()
-            .await
-            .map_err(|e| WeatherDataError::CacheDeletionError(self.cache_folder.clone(), e))?
-        {
-            let file_path = entry.path();
-            if file_path.is_file() {
-                if let Some(extension) = file_path.extension() {
-                    if extension == OsStr::new("parquet") {
-                        match tokio::fs::remove_file(&file_path).await {
-                            Ok(()) => {}
-                            Err(e) if e.kind() == io::ErrorKind::NotFound => {} // Ignore if already gone
-                            Err(e) => {
-                                return Err(WeatherDataError::CacheDeletionError(
-                                    file_path.clone(),
-                                    e,
-                                ))
-                            }
-                        }
-                    }
-                }
-            }
+    /// Reads downloaded CSV files with `csv_options`, see [`WeatherDataLoader::with_csv_options`].
+    #[must_use]
+    pub fn with_csv_options(mut self, csv_options: CsvOptions) -> Self {
+        self.loader = self.loader.with_csv_options(csv_options);
+        self
+    }
+
+    /// Loads data from `sources` in order, see [`WeatherDataLoader::with_sources`].
+    #[must_use]
+    pub fn with_sources(mut self, sources: &[DataSource]) -> Self {
+        self.loader = self.loader.with_sources(sources);
+        self
+    }
+
+    /// The base URL weather data files are downloaded from.
+    pub fn base_url(&self) -> &str {
+        self.loader.base_url()
+    }
+
+    pub async fn clear_cache_all(&self) -> Result<(), WeatherDataError> {
+        let list_error = |e| WeatherDataError::CacheDeletionError(entry_path(&*self.cache, ""), e);
+        let keys = self.cache.list().await.map_err(list_error)?;
+
+        // Only weather data; the station list is cached in the same place
+        for key in keys.iter().filter(|key| key.ends_with(".parquet")) {
+            self.cache.delete(key).await.map_err(|e| {
+                WeatherDataError::CacheDeletionError(entry_path(&*self.cache, key), e)
+            })?;
         }
 
         self.lazyframe_cache.lock().await.clear();
@@ -64,16 +93,11 @@ impl FrameFetcher {
         station: &str,
         frequency: Frequency,
     ) -> Result<(), WeatherDataError> {
-        let file = self.cache_folder.join(format!(
-            "{}{}.parquet",
-            frequency.cache_file_prefix(),
-            station
-        ));
-        match tokio::fs::remove_file(&file).await {
-            Ok(()) => {}
-            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
-            Err(e) => return Err(WeatherDataError::CacheDeletionError(file.clone(), e)),
-        }
+        let key = WeatherDataLoader::cache_key(frequency, station);
+        self.cache
+            .delete(&key)
+            .await
+            .map_err(|e| WeatherDataError::CacheDeletionError(entry_path(&*self.cache, &key), e))?;
 
         self.lazyframe_cache
             .lock()
@@ -83,6 +107,44 @@ impl FrameFetcher {
         Ok(())
     }
 
+    /// Removes the weather data cache entries last written before `cutoff`, along with their
+    /// in-memory frames.
+    pub async fn purge_older_than(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> Result<PurgeReport, WeatherDataError> {
+        let list_error = |e| WeatherDataError::CacheDeletionError(entry_path(&*self.cache, ""), e);
+        let keys = self.cache.list().await.map_err(list_error)?;
+
+        let mut report = PurgeReport::default();
+        let mut removed = Vec::new();
+        // Only weather data; the station list is cached in the same place
+        for key in keys.into_iter().filter(|key| key.ends_with(".parquet")) {
+            let metadata_error =
+                |e| WeatherDataError::CacheMetadataRead(entry_path(&*self.cache, &key), e);
+            let modified = self.cache.modified(&key).await.map_err(metadata_error)?;
+            if modified.is_none_or(|modified| modified >= cutoff) {
+                continue;
+            }
+            // Backends that can't tell the size cheaply don't add to the freed bytes
+            let size = self.cache.size(&key).await.map_err(metadata_error)?;
+            self.cache.delete(&key).await.map_err(|e| {
+                WeatherDataError::CacheDeletionError(entry_path(&*self.cache, &key), e)
+            })?;
+            report.files_removed += 1;
+            report.bytes_freed += size.unwrap_or(0);
+            removed.push(key);
+        }
+
+        self.lazyframe_cache
+            .lock()
+            .await
+            .retain(|(station, frequency), _| {
+                !removed.contains(&WeatherDataLoader::cache_key(*frequency, station))
+            });
+        Ok(report)
+    }
+
     /// Checks if the cache for a station/frequency is stale based on `required_data`.
     /// Returns `true` if the cache is stale, `false` if it's recent enough.
     async fn is_cache_stale(
@@ -113,25 +175,50 @@ impl FrameFetcher {
         }
     }
 
+    /// Compares the cache file of a station with the remote file's modification time.
+    pub async fn update_status(&self, station: &str, frequency: Frequency) -> UpdateStatus {
+        let (cached_at, remote_modified) = tokio::join!(
+            self.loader.get_cache_modification_time(station, frequency),
+            self.loader.get_remote_modification_time(frequency, station),
+        );
+        UpdateStatus {
+            station: station.to_string(),
+            cached_at: cached_at.ok().flatten(),
+            remote_modified: remote_modified.ok().flatten(),
+        }
+    }
+
     /// Gets a `LazyFrame` for a given station and frequency, using the cache if possible.
-    /// Handles automatic cache refresh based on `required_data`.
+    /// Handles automatic cache refresh based on `required_data`. Stale caches are updated
+    /// incrementally when the frequency allows it, otherwise they're fully re-downloaded.
     pub async fn get_cache_lazyframe(
         &self,
         station: &str,
         frequency: Frequency,
         required_data: RequiredData,
     ) -> Result<LazyFrame, WeatherDataError> {
+        let key = (station.to_string(), frequency);
+
         if self
             .is_cache_stale(station, frequency, required_data)
             .await
             .unwrap_or(false)
         {
-            self.clear_cache(station, frequency).await?;
+            // Any failure here just means we fall back to a full re-download
+            let updated = self
+                .loader
+                .update_frame_incremental(frequency, station)
+                .await
+                .unwrap_or(false);
+            if updated {
+                self.lazyframe_cache.lock().await.remove(&key);
+            } else {
+                self.clear_cache(station, frequency).await?;
+            }
         }
 
         // --- Step 2: Try fetching from in-memory cache (fast path) ---
         // This check runs *after* potential clearing. If cleared, it will be a miss.
-        let key = (station.to_string(), frequency);
         {
             let cache = self.lazyframe_cache.lock().await;
             if let Some(cached_frame) = cache.get(&key) {
@@ -139,6 +226,41 @@ impl FrameFetcher {
             }
         } // Read lock guard is dropped here
 
+        // Only one task loads a given station/frequency at a time. Tasks that waited for
+        // the lock find the frame in the in-memory cache afterwards.
+        let load_lock = self
+            .load_locks
+            .lock()
+            .await
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        let loading = load_lock.lock().await;
+        let result = self.load_frame(&key).await;
+        drop(loading);
+        self.release_load_lock(&key, load_lock).await;
+        result
+    }
+
+    /// Removes the load lock of `key` if no other task holds or waits for it. Tasks that
+    /// wait for it hold a clone, and new ones can only clone it from the map while holding
+    /// the map's lock, so the count can't grow while it is checked.
+    async fn release_load_lock(&self, key: &FrameKey, load_lock: Arc<Mutex<()>>) {
+        let mut load_locks = self.load_locks.lock().await;
+        // One reference in the map, and ours
+        if Arc::strong_count(&load_lock) <= 2 {
+            load_locks.remove(key);
+        }
+    }
+
+    /// Loads the frame of `key` into the in-memory cache, unless a task that held the load
+    /// lock before already did. Called with the load lock of `key` held.
+    async fn load_frame(&self, key: &FrameKey) -> Result<LazyFrame, WeatherDataError> {
+        let (station, frequency) = (key.0.as_str(), key.1);
+        if let Some(cached_frame) = self.lazyframe_cache.lock().await.get(key) {
+            return Ok(cached_frame.clone());
+        }
+
         // --- Step 3: Load frame from disk or download (slow path) ---
         let loaded_frame = self.loader.get_frame(frequency, station).await?; // Load from disk/network
 
@@ -146,7 +268,7 @@ impl FrameFetcher {
         {
             let mut cache = self.lazyframe_cache.lock().await;
             // Use Entry API for race condition safety
-            match cache.entry(key) {
+            match cache.entry(key.clone()) {
                 Entry::Occupied(entry) => {
                     // Another task loaded it while we were busy. Use theirs.
                     Ok(entry.get().clone())
@@ -168,6 +290,7 @@ mod tests {
     use crate::{LatLon, Meteostat, MeteostatError, RequiredData};
     // Import Meteostat and RequiredData
     use chrono::Datelike;
+    use std::path::{Path, PathBuf};
     use std::time::{Duration, SystemTime};
     use tempfile::tempdir;
     // For year()
@@ -203,6 +326,25 @@ mod tests {
             }) // Use appropriate error
     }
 
+    #[tokio::test]
+    async fn test_load_locks_are_removed_after_loading() {
+        let fetcher = FrameFetcher::new(
+            Arc::new(crate::MemoryCache::new()),
+            "http://127.0.0.1:9",
+            crate::weather_data::data_loader::DEFAULT_CACHE_COMPRESSION,
+            Arc::new(Semaphore::new(1)),
+        );
+
+        // Several tasks waiting for the same station, and one for another station
+        let (a, b, c) = tokio::join!(
+            fetcher.get_cache_lazyframe("10637", Frequency::Daily, RequiredData::Any),
+            fetcher.get_cache_lazyframe("10637", Frequency::Daily, RequiredData::Any),
+            fetcher.get_cache_lazyframe("10641", Frequency::Hourly, RequiredData::Any),
+        );
+        assert!(a.is_err() && b.is_err() && c.is_err());
+        assert!(fetcher.load_locks.lock().await.is_empty());
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_cache_refresh_not_triggered_when_recent() -> Result<(), Box<dyn std::error::Error>>
     {
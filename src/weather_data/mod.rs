@@ -1,3 +1,4 @@
+pub mod cache_lock;
 pub mod data_loader;
 pub mod error;
 pub mod frame_fetcher;